@@ -4,10 +4,6 @@ use syn::{parse_macro_input, FnArg, Pat};
 
 #[proc_macro_attribute]
 pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let attr_str = syn::LitStr::new(
-        &format!(" {}", &attr.to_string()),
-        proc_macro2::Span::call_site(),
-    );
     let mut attr_iter = proc_macro2::TokenStream::from(attr).into_iter();
     let verb = attr_iter.next().expect("expect method, GET, PUT, POST etc");
     let verb_str = verb.to_string();
@@ -18,6 +14,44 @@ pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
         Ident::new(&verb_str.to_lowercase(), verb.span())
     };
     let url = attr_iter.next().expect("expect endpoint url");
+    let attr_str = syn::LitStr::new(
+        &format!(" {} {}", verb_str, url),
+        proc_macro2::Span::call_site(),
+    );
+    // 可选的 `dry_run`, `dry_run = "test url"` 或 `dry_run = validate`, 开启 dry run 时不发送请求,
+    // 指定测试接口时将请求发送到测试接口进行校验, 指定 validate 时按交易对规则在本地校验,
+    // `validate(field)` 使用 `self.dry_run.field` 中的规则, 默认为 `self.dry_run.rules`
+    // 可选的 `client_order_id`, 参数未指定自定义订单号时由客户端生成
+    let mut dry_run = false;
+    let mut dry_run_url = None;
+    let mut dry_run_validate = false;
+    let mut dry_run_rules = None;
+    let mut client_order_id = false;
+    for token in attr_iter {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "dry_run" => dry_run = true,
+            proc_macro2::TokenTree::Ident(ident) if ident == "client_order_id" => {
+                client_order_id = true
+            }
+            proc_macro2::TokenTree::Ident(ident) if dry_run && ident == "validate" => {
+                dry_run_validate = true
+            }
+            proc_macro2::TokenTree::Group(group)
+                if dry_run_validate
+                    && dry_run_rules.is_none()
+                    && group.delimiter() == proc_macro2::Delimiter::Parenthesis =>
+            {
+                dry_run_rules =
+                    Some(syn::parse2::<Ident>(group.stream()).expect("expect rules field"))
+            }
+            proc_macro2::TokenTree::Literal(lit) if dry_run => dry_run_url = Some(lit),
+            proc_macro2::TokenTree::Punct(_) => {}
+            other => panic!(
+                "unexpected token {}, expect dry_run or client_order_id",
+                other
+            ),
+        }
+    }
     let mut api_fn = parse_macro_input!(item as syn::ItemFn);
 
     let fn_input = api_fn.sig.inputs.clone();
@@ -35,6 +69,20 @@ pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
             panic!("expected param")
         }
     };
+    let client_order_id_block = match (&param_ident, client_order_id) {
+        (Some(p_ident), true) => {
+            let p_ident = &p_ident.ident;
+            quote::quote! {
+                let mut #p_ident = #p_ident;
+                crate::params::FillClientOrderId::fill_client_order_id(
+                    &mut #p_ident,
+                    || self.client_order_ids.next_id(),
+                );
+            }
+        }
+        (None, true) => panic!("client_order_id requires a param"),
+        _ => quote::quote! {},
+    };
     let prepare_qs_block = if param_ident.is_some() {
         quote::quote! {
             let qs = serde_qs::to_string(&param).unwrap();
@@ -45,7 +93,7 @@ pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
     let sign_block = if should_sign {
-        if let Some(p_ident) = param_ident {
+        if let Some(p_ident) = &param_ident {
             quote::quote! {
                 let qs  = format!(
                     "{}&signature={}",
@@ -64,16 +112,74 @@ pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
     } else {
         quote::quote! {}
     };
+    let dry_run_rules =
+        dry_run_rules.unwrap_or_else(|| Ident::new("rules", proc_macro2::Span::call_site()));
+    let dry_run_doc = match (&dry_run_url, dry_run_validate) {
+        (Some(test_url), _) => Some(format!(
+            " dry run 时发送到测试接口 `{}` 由交易所校验参数",
+            test_url.to_string().trim_matches('"')
+        )),
+        (None, true) if dry_run_rules == "rules" => Some(
+            " dry run 时按 `with_dry_run_rules` 设置的交易对规则在本地校验参数, 未设置时只检查必填参数和数量"
+                .to_string(),
+        ),
+        (None, true) => Some(format!(
+            " dry run 时按 `dry_run.{}` 中的交易对规则在本地校验参数, 未设置时只检查必填参数和数量",
+            dry_run_rules
+        )),
+        (None, false) if dry_run => Some(" dry run 时只记录请求, 不校验参数".to_string()),
+        _ => None,
+    };
+    let dry_run_block = if dry_run {
+        let method_name = http_method.to_string().to_uppercase();
+        let validate_block = match (dry_run_url, &param_ident) {
+            (Some(test_url), _) => quote::quote! {
+                let test_url = self.base_url.join(#test_url).unwrap();
+                let test_url = if qs.is_empty() { test_url.to_string() } else { format!("{}?{}", test_url, qs) };
+                let resp = self
+                .http_client
+                .#http_method(&test_url)
+                .header("Content-Type", "application/json")
+                .header("X-MBX-APIKEY", &self.api_key)
+                .send()
+                .await?;
+                APIError::check_resp(resp).await?;
+            },
+            (None, Some(p_ident)) if dry_run_validate => {
+                let p_ident = &p_ident.ident;
+                quote::quote! {
+                    crate::rules::ValidateOrder::validate(&#p_ident, self.dry_run.#dry_run_rules.as_deref())?;
+                }
+            }
+            (None, None) if dry_run_validate => panic!("dry_run = validate requires a param"),
+            _ => quote::quote! {},
+        };
+        quote::quote! {
+            if self.dry_run.enabled {
+                #validate_block
+                let req = format!("{} {}", #method_name, url);
+                log::info!("dry run {}", &req);
+                return Err(crate::error::APIError::DryRun(req));
+            }
+        }
+    } else {
+        quote::quote! {}
+    };
     let fn_block = syn::parse_quote! {
         use std::collections::HashMap;
 
         let url = self.base_url.join(#url).unwrap();
 
+        #client_order_id_block
+
         #prepare_qs_block
 
         #sign_block
 
         let url = if qs.is_empty() { url.to_string() } else { format!("{}?{}", url, qs) };
+
+        #dry_run_block
+
         log::debug!("req {}", &url);
         let resp = self
         .http_client
@@ -97,5 +203,14 @@ pub fn api(attr: TokenStream, item: TokenStream) -> TokenStream {
         #[doc = #attr_str]
     };
     api_fn.attrs.push(url_doc);
+    if let Some(dry_run_doc) = dry_run_doc {
+        let dry_run_doc = syn::LitStr::new(&dry_run_doc, proc_macro2::Span::call_site());
+        api_fn.attrs.push(syn::parse_quote! {
+            #[doc = r""]
+        });
+        api_fn.attrs.push(syn::parse_quote! {
+            #[doc = #dry_run_doc]
+        });
+    }
     TokenStream::from(quote::quote! { #api_fn })
 }
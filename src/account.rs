@@ -0,0 +1,432 @@
+//! 本地账户余额与持仓
+//!
+//! [`AccountState`] 通过 http 接口初始化账户快照, 之后应用用户数据推送中的余额和持仓变化,
+//! 并根据标记价格推送更新未实现盈亏。推送只包含发生变化的资产和持仓, 重连期间丢失的推送
+//! 无法补齐, 因此在用户数据流重新连接 (包括 listenKey 过期后重建) 时需要重新同步快照,
+//! [`AccountState::handle`] 会自动完成这一步。
+//!
+//! ```no_run
+//! use bian_rs::account::AccountState;
+//! use bian_rs::client::{UFuturesHttpClient, UFuturesWSClient};
+//! use bian_rs::user_stream::UFuturesUserDataStream;
+//!
+//! # async fn run(http: UFuturesHttpClient, ws: UFuturesWSClient) {
+//! let mut account = AccountState::load(&http).await.unwrap();
+//! let mut stream = UFuturesUserDataStream::start(http.clone(), ws);
+//! while let Some(Ok(event)) = stream.next().await {
+//!     account.handle(&http, &event).await.unwrap();
+//!     println!("{:?}", account.balance("USDT"));
+//! }
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::client::{DFuturesHttpClient, SpotHttpClient, UFuturesHttpClient};
+use crate::enums::{MarginType, PositionDirect};
+use crate::error::BianResult;
+use crate::params::{PSymbolWithTs, PTimestamp};
+use crate::response::{
+    self, WSAccountUpdate, WSPrice, WSSpotAccountPosition, WSSpotBalanceUpdate, WSSpotUserStream,
+    WSUserStream,
+};
+use crate::user_stream::UserDataEvent;
+
+/// 资产余额
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetBalance {
+    pub asset: String,
+    /// 钱包余额, 现货为可用与冻结余额之和
+    pub wallet_balance: f64,
+    /// 现货为可用余额, 合约快照为可用余额, 合约账户更新推送不包含可用余额, 使用全仓钱包余额
+    pub available: f64,
+}
+
+/// 持仓
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionState {
+    pub symbol: String,
+    pub position_side: PositionDirect,
+    pub margin_type: MarginType,
+    /// 持仓数量, 正数为多, 负数为空
+    pub amount: f64,
+    /// 开仓均价
+    pub entry_price: f64,
+    /// 标记价格, 由快照初始化, 之后随标记价格推送更新
+    pub mark_price: f64,
+    /// 未实现盈亏
+    pub unrealized_pnl: f64,
+    /// 杠杆倍数, 来自快照, 未知时为 0
+    pub leverage: f64,
+    /// 参考强平价格, 来自快照, 未知或全仓无强平风险时为 0
+    pub liquidation_price: f64,
+}
+
+/// 账户快照
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountSnapshot {
+    pub balances: Vec<AssetBalance>,
+    /// 持仓数量不为 0 的持仓
+    pub positions: Vec<PositionState>,
+    /// 币本位合约面值, 用于计算未实现盈亏, U本位合约和现货为空
+    pub contract_sizes: HashMap<String, f64>,
+}
+
+/// 查询账户快照的接口
+#[async_trait]
+pub trait AccountSource {
+    async fn account_snapshot(&self) -> BianResult<AccountSnapshot>;
+}
+
+/// 可应用到账户状态的用户数据推送
+pub trait AccountEvent {
+    fn apply_to(&self, state: &mut AccountState);
+}
+
+/// 账户状态
+#[derive(Debug, Default)]
+pub struct AccountState {
+    balances: HashMap<String, AssetBalance>,
+    positions: HashMap<(String, PositionDirect), PositionState>,
+    contract_sizes: HashMap<String, f64>,
+}
+
+impl AccountState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 通过 http 接口初始化账户状态
+    pub async fn load<C: AccountSource + ?Sized>(client: &C) -> BianResult<Self> {
+        let mut state = Self::new();
+        state.sync(client).await?;
+        Ok(state)
+    }
+
+    /// 通过 http 接口重新同步账户状态, 快照没有标记价格时会保留已知的标记价格并用于计算未实现盈亏
+    pub async fn sync<C: AccountSource + ?Sized>(&mut self, client: &C) -> BianResult<()> {
+        let snapshot = client.account_snapshot().await?;
+        self.reset(snapshot);
+        Ok(())
+    }
+
+    /// 使用快照替换当前状态
+    pub fn reset(&mut self, snapshot: AccountSnapshot) {
+        let marks: HashMap<String, f64> = self
+            .positions
+            .values()
+            .filter(|p| p.mark_price > 0.0)
+            .map(|p| (p.symbol.clone(), p.mark_price))
+            .collect();
+        self.balances = snapshot
+            .balances
+            .into_iter()
+            .map(|b| (b.asset.clone(), b))
+            .collect();
+        self.contract_sizes = snapshot.contract_sizes;
+        self.positions.clear();
+        for position in snapshot.positions {
+            if position.amount != 0.0 {
+                self.positions
+                    .insert((position.symbol.clone(), position.position_side), position);
+            }
+        }
+        for (symbol, mark_price) in marks {
+            let missing = self.positions_of(&symbol).any(|p| p.mark_price == 0.0);
+            if missing {
+                self.update_mark_price(&symbol, mark_price);
+            }
+        }
+    }
+
+    /// 处理用户数据流事件, 建立新连接时重新同步快照
+    pub async fn handle<C, E>(&mut self, client: &C, event: &UserDataEvent<E>) -> BianResult<()>
+    where
+        C: AccountSource + ?Sized,
+        E: AccountEvent,
+    {
+        match event {
+            UserDataEvent::Connected(_) => self.sync(client).await,
+            UserDataEvent::Update(update) => {
+                self.apply(update);
+                Ok(())
+            }
+            UserDataEvent::Reconnecting(_) => Ok(()),
+        }
+    }
+
+    /// 应用用户数据推送, 与账户无关的事件会被忽略
+    pub fn apply<E: AccountEvent + ?Sized>(&mut self, event: &E) {
+        event.apply_to(self)
+    }
+
+    /// 应用合约账户更新推送
+    pub fn apply_account_update(&mut self, update: &WSAccountUpdate) {
+        for b in update.event.balances.iter() {
+            self.balances.insert(
+                b.asset.clone(),
+                AssetBalance {
+                    asset: b.asset.clone(),
+                    wallet_balance: b.wb,
+                    available: b.cw,
+                },
+            );
+        }
+        for p in update.event.positions.iter() {
+            let key = (p.symbol.clone(), p.position_side);
+            if p.position == 0.0 {
+                self.positions.remove(&key);
+                continue;
+            }
+            // 推送不包含标记价格, 杠杆和强平价格, 沿用已知的值
+            let (mark_price, leverage, liquidation_price) =
+                self.positions.get(&key).map_or((0.0, 0.0, 0.0), |old| {
+                    (old.mark_price, old.leverage, old.liquidation_price)
+                });
+            self.positions.insert(
+                key,
+                PositionState {
+                    symbol: p.symbol.clone(),
+                    position_side: p.position_side,
+                    margin_type: p.margin_type,
+                    amount: p.position,
+                    entry_price: p.enter_price,
+                    mark_price,
+                    unrealized_pnl: p.unrealized_pnl,
+                    leverage,
+                    liquidation_price,
+                },
+            );
+        }
+    }
+
+    /// 应用现货账户更新推送
+    pub fn apply_spot_account(&mut self, update: &WSSpotAccountPosition) {
+        for b in update.balances.iter() {
+            self.balances.insert(
+                b.asset.clone(),
+                AssetBalance {
+                    asset: b.asset.clone(),
+                    wallet_balance: b.free + b.locked,
+                    available: b.free,
+                },
+            );
+        }
+    }
+
+    /// 应用现货余额变化推送
+    pub fn apply_balance_update(&mut self, update: &WSSpotBalanceUpdate) {
+        let balance = self
+            .balances
+            .entry(update.asset.clone())
+            .or_insert_with(|| AssetBalance {
+                asset: update.asset.clone(),
+                wallet_balance: 0.0,
+                available: 0.0,
+            });
+        balance.wallet_balance += update.delta;
+        balance.available += update.delta;
+    }
+
+    /// 应用标记价格推送
+    pub fn apply_mark_price(&mut self, price: &WSPrice) {
+        self.update_mark_price(&price.symbol, price.price);
+    }
+
+    /// 更新交易对的标记价格并重新计算未实现盈亏
+    pub fn update_mark_price(&mut self, symbol: &str, mark_price: f64) {
+        let contract_size = self.contract_sizes.get(symbol).copied();
+        for position in self.positions.values_mut() {
+            if position.symbol != symbol {
+                continue;
+            }
+            position.mark_price = mark_price;
+            position.unrealized_pnl = match contract_size {
+                // 币本位合约盈亏以标的资产计价
+                Some(size) if position.entry_price > 0.0 && mark_price > 0.0 => {
+                    position.amount * size * (1.0 / position.entry_price - 1.0 / mark_price)
+                }
+                Some(_) => 0.0,
+                None => position.amount * (mark_price - position.entry_price),
+            };
+        }
+    }
+
+    /// 资产余额
+    pub fn balance(&self, asset: &str) -> Option<&AssetBalance> {
+        self.balances.get(asset)
+    }
+
+    /// 所有资产余额
+    pub fn balances(&self) -> impl Iterator<Item = &AssetBalance> {
+        self.balances.values()
+    }
+
+    /// 交易对指定方向的持仓
+    pub fn position(&self, symbol: &str, side: PositionDirect) -> Option<&PositionState> {
+        self.positions.get(&(symbol.to_string(), side))
+    }
+
+    /// 交易对所有方向的持仓
+    pub fn positions_of<'a>(
+        &'a self,
+        symbol: &'a str,
+    ) -> impl Iterator<Item = &'a PositionState> + 'a {
+        self.positions.values().filter(move |p| p.symbol == symbol)
+    }
+
+    /// 所有持仓
+    pub fn positions(&self) -> impl Iterator<Item = &PositionState> {
+        self.positions.values()
+    }
+
+    /// 所有持仓的未实现盈亏之和, 币本位合约各交易对计价资产不同, 仅供参考
+    pub fn unrealized_pnl(&self) -> f64 {
+        self.positions.values().map(|p| p.unrealized_pnl).sum()
+    }
+}
+
+impl AccountEvent for WSUserStream {
+    fn apply_to(&self, state: &mut AccountState) {
+        if let WSUserStream::AccountUpdate(update) = self {
+            state.apply_account_update(update)
+        }
+    }
+}
+
+impl AccountEvent for WSSpotUserStream {
+    fn apply_to(&self, state: &mut AccountState) {
+        match self {
+            WSSpotUserStream::AccountPosition(update) => state.apply_spot_account(update),
+            WSSpotUserStream::BalanceUpdate(update) => state.apply_balance_update(update),
+            _ => {}
+        }
+    }
+}
+
+impl AccountEvent for WSPrice {
+    fn apply_to(&self, state: &mut AccountState) {
+        state.apply_mark_price(self)
+    }
+}
+
+/// 有持仓的交易对, 用于查询持仓风险
+fn open_symbols(account: &response::FuturesAccount) -> Vec<String> {
+    let mut symbols: Vec<String> = account
+        .positions
+        .iter()
+        .filter(|p| p.position_amt != 0.0)
+        .map(|p| p.symbol.clone())
+        .collect();
+    symbols.sort();
+    symbols.dedup();
+    symbols
+}
+
+/// 根据账户信息和持仓风险创建快照, 持仓风险提供标记价格和强平价格
+fn futures_snapshot(
+    account: response::FuturesAccount,
+    risks: Vec<response::PositionRisk>,
+) -> AccountSnapshot {
+    let risks: HashMap<(String, PositionDirect), response::PositionRisk> = risks
+        .into_iter()
+        .map(|r| ((r.symbol.clone(), r.position_side), r))
+        .collect();
+    let balances = account
+        .assets
+        .into_iter()
+        .map(|a| AssetBalance {
+            asset: a.asset,
+            wallet_balance: a.wallet_balance,
+            available: a.available_balance,
+        })
+        .collect();
+    let positions = account
+        .positions
+        .into_iter()
+        .filter(|p| p.position_amt != 0.0)
+        .map(|p| {
+            let risk = risks.get(&(p.symbol.clone(), p.position_side));
+            PositionState {
+                symbol: p.symbol,
+                position_side: p.position_side,
+                margin_type: if p.isolated {
+                    MarginType::Isolated
+                } else {
+                    MarginType::Crossed
+                },
+                amount: p.position_amt,
+                entry_price: p.entry_price,
+                mark_price: risk.map_or(0.0, |r| r.mark_price),
+                unrealized_pnl: p.unrealized_profit,
+                leverage: p.leverage,
+                liquidation_price: risk.map_or(0.0, |r| r.liquidation_price),
+            }
+        })
+        .collect();
+    AccountSnapshot {
+        balances,
+        positions,
+        contract_sizes: HashMap::new(),
+    }
+}
+
+#[async_trait]
+impl AccountSource for SpotHttpClient {
+    async fn account_snapshot(&self) -> BianResult<AccountSnapshot> {
+        let account = self.account(PTimestamp::now()).await?;
+        let balances = account
+            .balances
+            .into_iter()
+            .map(|b| AssetBalance {
+                asset: b.asset,
+                wallet_balance: b.free + b.locked,
+                available: b.free,
+            })
+            .collect();
+        Ok(AccountSnapshot {
+            balances,
+            ..Default::default()
+        })
+    }
+}
+
+#[async_trait]
+impl AccountSource for UFuturesHttpClient {
+    async fn account_snapshot(&self) -> BianResult<AccountSnapshot> {
+        let account = self.account_v2(PTimestamp::now()).await?;
+        let mut risks = Vec::new();
+        for symbol in open_symbols(&account) {
+            let param = PSymbolWithTs {
+                symbol,
+                ts: PTimestamp::now(),
+            };
+            risks.extend(self.position_risk(param).await?);
+        }
+        Ok(futures_snapshot(account, risks))
+    }
+}
+
+#[async_trait]
+impl AccountSource for DFuturesHttpClient {
+    async fn account_snapshot(&self) -> BianResult<AccountSnapshot> {
+        let account = self.account_v2(PTimestamp::now()).await?;
+        let mut risks = Vec::new();
+        for symbol in open_symbols(&account) {
+            let param = PSymbolWithTs {
+                symbol,
+                ts: PTimestamp::now(),
+            };
+            risks.extend(self.position_risk(param).await?);
+        }
+        let info = self.exchange_info().await?;
+        let mut snapshot = futures_snapshot(account, risks);
+        snapshot.contract_sizes = info
+            .symbols
+            .into_iter()
+            .map(|s| (s.symbol, s.contract_size as f64))
+            .collect();
+        Ok(snapshot)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::error::{APIError, BianResult};
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::DryRun;
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 合约算法单 http 客户端, 目前只支持U本位合约
+///
+/// TWAP 和 VP 算法单由交易所拆单执行, 不需要在本地运行定时任务。
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#algo)
+#[derive(Debug, Clone)]
+pub struct AlgoHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 开启后 TWAP, VP 下单和撤销算法单只签名并记录请求,
+    /// 币安没有对应的测试接口, 参数不做任何校验
+    pub dry_run: DryRun,
+}
+
+impl AlgoHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+        })
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+impl AlgoHttpClient {
+    /// TWAP 算法单, 名义价值需要大于 1 万 USDT
+    #[api(SPOST "sapi/v1/algo/futures/newOrderTwap", dry_run)]
+    pub async fn twap_order(
+        &self,
+        param: params::PTwapOrder,
+    ) -> BianResult<response::AlgoOrderResult> {
+    }
+
+    /// VP 算法单, 名义价值需要大于 1 万 USDT
+    #[api(SPOST "sapi/v1/algo/futures/newOrderVp", dry_run)]
+    pub async fn vp_order(&self, param: params::PVpOrder) -> BianResult<response::AlgoOrderResult> {
+    }
+
+    /// 撤销执行中的算法单, 已成交的子订单不会撤销
+    #[api(SDELETE "sapi/v1/algo/futures/order", dry_run)]
+    pub async fn cancel_order(
+        &self,
+        param: params::PAlgoId,
+    ) -> BianResult<response::AlgoCancelResult> {
+    }
+
+    /// 执行中的算法单
+    #[api(SGET "sapi/v1/algo/futures/openOrders")]
+    pub async fn open_orders(&self, param: params::PTimestamp) -> BianResult<response::AlgoOrders> {
+    }
+
+    /// 历史算法单
+    #[api(SGET "sapi/v1/algo/futures/historicalOrders")]
+    pub async fn historical_orders(
+        &self,
+        param: params::PAlgoHistory,
+    ) -> BianResult<response::AlgoOrders> {
+    }
+
+    /// 算法单的子订单
+    #[api(SGET "sapi/v1/algo/futures/subOrders")]
+    pub async fn sub_orders(
+        &self,
+        param: params::PAlgoSubOrders,
+    ) -> BianResult<response::AlgoSubOrders> {
+    }
+}
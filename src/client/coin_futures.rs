@@ -4,6 +4,7 @@ use crate::enums::{ContractType, Interval};
 use crate::error::{APIError, BianResult};
 use crate::params;
 use crate::response::{self, WebsocketResponse};
+use crate::trading::ClientOrderIdGenerator;
 use bian_proc::api;
 use hmac::{Hmac, Mac, NewMac};
 use sha2::Sha256;
@@ -12,6 +13,8 @@ use tungstenite::{
     WebSocket,
 };
 
+use super::DryRun;
+
 const BASE_HTTP_URL: &str = "https://dapi.binance.com";
 const BASE_WS_URL: &str = "wss://dstream.binance.com";
 
@@ -22,6 +25,11 @@ pub struct DFuturesHttpClient {
     pub api_key: String,
     pub secret_key: String,
     pub base_url: url::Url,
+    /// 下单时自动生成自定义订单号
+    pub client_order_ids: ClientOrderIdGenerator,
+    /// dry run 配置, 币本位合约没有测试下单接口, 开启后下单, 批量下单和改单按 `with_dry_run_rules`
+    /// 设置的交易对规则在本地校验参数, 撤单和倒计时撤单接口只签名并记录请求
+    pub dry_run: DryRun,
 }
 
 impl DFuturesHttpClient {
@@ -34,6 +42,8 @@ impl DFuturesHttpClient {
             api_key,
             secret_key,
             http_client,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         }
     }
 
@@ -46,9 +56,19 @@ impl DFuturesHttpClient {
             api_key: api_key.to_string(),
             secret_key: secret_key.to_string(),
             base_url,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         })
     }
 
+    /// 设置自动生成的自定义订单号前缀
+    pub fn with_client_order_prefix(mut self, prefix: &str) -> Self {
+        self.client_order_ids = ClientOrderIdGenerator::new(prefix);
+        self
+    }
+
+    dry_run_methods!(rules);
+
     fn sign<P: serde::Serialize>(&self, params: &P) -> String {
         let qs = serde_qs::to_string(&params).unwrap();
         let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
@@ -116,6 +136,22 @@ impl DFuturesHttpClient {
     ) -> BianResult<Vec<response::Kline>> {
     }
 
+    /// 标记价格K线数据
+    #[api(GET "dapi/v1/markPriceKlines")]
+    pub async fn mark_price_klines(
+        &self,
+        param: params::PKline,
+    ) -> BianResult<Vec<response::Kline>> {
+    }
+
+    /// 溢价指数K线数据
+    #[api(GET "dapi/v1/premiumIndexKlines")]
+    pub async fn premium_index_klines(
+        &self,
+        param: params::PKline,
+    ) -> BianResult<Vec<response::Kline>> {
+    }
+
     /// 最新标记价格和资金费率(单symbol)
     #[api(GET "dapi/v1/premiumIndex")]
     pub async fn premium_index(
@@ -233,6 +269,10 @@ impl DFuturesHttpClient {
         param: params::PTakerBullSell,
     ) -> BianResult<Vec<response::TakerBuySellVol>> {
     }
+
+    /// 基差, 按标的交易对和合约类型查询
+    #[api(GET "futures/data/basis")]
+    pub async fn basis(&self, param: params::PBasis) -> BianResult<Vec<response::Basis>> {}
 }
 
 /// 账户和交易接口
@@ -255,14 +295,14 @@ impl DFuturesHttpClient {
     ) -> BianResult<response::PositionSide> {
     }
 
-    /// 下单
+    /// 下单, 未指定自定义订单号时自动生成
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-2)
-    #[api(SPOST "dapi/v1/order")]
+    #[api(SPOST "dapi/v1/order", client_order_id, dry_run = validate)]
     pub async fn order(&self, param: params::PFuturesOrder) -> BianResult<response::FuturesOrder> {}
 
-    /// 批量下单
+    /// 批量下单, 未指定自定义订单号的订单自动生成
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-4)
-    #[api(SPOST "dapi/v1/batchOrders")]
+    #[api(SPOST "dapi/v1/batchOrders", client_order_id, dry_run = validate)]
     pub async fn batch_order(
         &self,
         param: params::PBatchOrder,
@@ -282,7 +322,7 @@ impl DFuturesHttpClient {
     /// 撤销订单
     ///
     /// 至少需要发送 orderId 与 origClientOrderId 中的一个
-    #[api(SDELETE "dapi/v1/order")]
+    #[api(SDELETE "dapi/v1/order", dry_run)]
     pub async fn cancel_order(
         &self,
         param: params::PQueryFuturesOrder,
@@ -290,15 +330,41 @@ impl DFuturesHttpClient {
     }
 
     /// 撤销全部订单
-    #[api(SDELETE "dapi/v1/allOpenOrders")]
+    #[api(SDELETE "dapi/v1/allOpenOrders", dry_run)]
     pub async fn cancel_all_orders(
         &self,
         param: params::PSymbolWithTs,
     ) -> BianResult<response::CodeResponse> {
     }
 
+    /// 修改订单, 仅支持限价单
+    ///
+    /// 修改后订单在同价格档位中重新排队
+    #[api(SPUT "dapi/v1/order", dry_run = validate)]
+    pub async fn modify_order(
+        &self,
+        param: params::PModifyOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 批量修改订单
+    #[api(SPUT "dapi/v1/batchOrders", dry_run = validate)]
+    pub async fn batch_modify_orders(
+        &self,
+        param: params::PBatchModifyOrder,
+    ) -> BianResult<Vec<response::BatchOrderResponse>> {
+    }
+
+    /// 查询订单修改历史
+    #[api(SGET "dapi/v1/orderAmendment")]
+    pub async fn order_amendment(
+        &self,
+        param: params::POrderAmendment,
+    ) -> BianResult<Vec<response::OrderAmendment>> {
+    }
+
     /// 批量撤销订单
-    #[api(SDELETE "dapi/v1/batchOrders")]
+    #[api(SDELETE "dapi/v1/batchOrders", dry_run)]
     pub async fn batch_cancel_orders(
         &self,
         param: params::PBatchCancelOrder,
@@ -307,7 +373,7 @@ impl DFuturesHttpClient {
 
     /// 倒计时撤销所有订单
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-8)
-    #[api(SPOST "dapi/v1/countdownCancelAll")]
+    #[api(SPOST "dapi/v1/countdownCancelAll", dry_run)]
     pub async fn countdown_cancel_all(
         &self,
         param: params::PCountdownCancel,
@@ -402,6 +468,41 @@ impl DFuturesHttpClient {
         param: params::PUserTrade,
     ) -> BianResult<Vec<response::UserFuturesTrade>> {
     }
+
+    /// 获取账户损益资金流水, 数量单位为保证金币种
+    ///
+    /// - 不传时间时返回最近 7 天的数据
+    /// - 时间范围最大 200 天, 只能查询最近 1 年的数据
+    #[api(SGET "dapi/v1/income")]
+    pub async fn income(
+        &self,
+        param: params::PDFuturesIncome,
+    ) -> BianResult<Vec<response::Income>> {
+    }
+
+    /// 杠杆分层标准, 按标的交易对返回, 不传时返回所有标的交易对
+    #[api(SGET "dapi/v1/leverageBracket")]
+    pub async fn leverage_brackets(
+        &self,
+        param: params::POptionPairQuery,
+    ) -> BianResult<Vec<response::DLeverageBracket>> {
+    }
+
+    /// 用户手续费率
+    #[api(SGET "dapi/v1/commissionRate")]
+    pub async fn commission_rate(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::CommissionRate> {
+    }
+
+    /// 持仓 ADL 队列估算, 不传交易对时返回所有持仓
+    #[api(SGET "dapi/v1/adlQuantile")]
+    pub async fn adl_quantile(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::AdlQuantile>> {
+    }
 }
 
 /// websocket 相关接口
@@ -768,15 +869,84 @@ impl DFuturesWSClient {
     }
 }
 
+/// 指数价格, 标记价格K线和合约信息
+///
+/// 指数价格按标的交易对(如 `btcusd`)订阅, 标记价格K线按交易对(如 `btcusd_perp`)订阅
+impl DFuturesWSClient {
+    /// 指数价格
+    ///
+    /// freq == 1 时更新速度为1s, 否则为3s
+    pub fn index_price(
+        &self,
+        pair: String,
+        freq: usize,
+    ) -> BianResult<impl WebsocketResponse<response::WSIndexPrice>> {
+        let channel = if freq == 1 {
+            "indexPrice@1s"
+        } else {
+            "indexPrice"
+        };
+        self.build_single(pair, channel)
+    }
+
+    /// 指数价格
+    ///
+    /// freq == 1 时更新速度为1s, 否则为3s
+    pub fn index_price_multi(
+        &self,
+        pairs: Vec<String>,
+        freq: usize,
+    ) -> BianResult<impl WebsocketResponse<response::WSIndexPrice>> {
+        let channel = if freq == 1 {
+            "indexPrice@1s"
+        } else {
+            "indexPrice"
+        };
+        self.build_multi(pairs, channel)
+    }
+
+    /// 指数价格K线
+    ///
+    /// Update Speed: 250ms
+    pub fn index_price_kline(
+        &self,
+        pair: String,
+        interval: Interval,
+    ) -> BianResult<impl WebsocketResponse<response::WSPriceKline>> {
+        let channel = format!("indexPriceKline_{}", interval.to_string());
+        self.build_single(pair, &channel)
+    }
+
+    /// 标记价格K线
+    ///
+    /// Update Speed: 250ms
+    pub fn mark_price_kline(
+        &self,
+        symbol: String,
+        interval: Interval,
+    ) -> BianResult<impl WebsocketResponse<response::WSPriceKline>> {
+        let channel = format!("markPriceKline_{}", interval.to_string());
+        self.build_single(symbol, &channel)
+    }
+
+    /// 合约信息
+    ///
+    /// 交易所只提供全市场推送, 需要按 `pair` 字段过滤标的交易对。Update Speed: 实时
+    pub fn contract_info(&self) -> BianResult<impl WebsocketResponse<response::WSContractInfo>> {
+        self.build_single(String::new(), "!contractInfo")
+    }
+}
+
 /// 用户 data stream
 impl DFuturesWSClient {
+    /// 账户信息推送
+    ///
+    /// listenKey 需要每 30 分钟续期一次, 可以使用 [`UserDataStream`](crate::user_stream::UserDataStream)
+    /// 自动续期和重连
     pub fn user_data(
         &self,
         listen_key: &str,
     ) -> BianResult<impl WebsocketResponse<response::WSUserStream>> {
-        let url = self.base_url.join(listen_key).unwrap();
-        let (socket, _) = connect_with_config(url, None, 3, self.proxy)
-            .map_err(|e| APIError::WSConnectError(e.to_string()))?;
-        Ok(socket)
+        self.build_single(String::new(), listen_key)
     }
 }
@@ -0,0 +1,133 @@
+use crate::error::{APIError, BianResult};
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::{DryRun, SpotHttpClient};
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 闪兑 http 客户端, 适合大额一次性兑换
+///
+/// 先通过 [`get_quote`](Self::get_quote) 询价, 在报价有效期内通过
+/// [`accept_quote`](Self::accept_quote) 接受报价。可以通过 [`SpotHttpClient::convert`]
+/// 复用现货客户端的密钥和连接池。
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#convert-endpoints)
+#[derive(Debug, Clone)]
+pub struct ConvertHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 开启后接受报价只签名并记录请求, 报价不会被接受; 询价接口不会成交, 不受影响
+    pub dry_run: DryRun,
+}
+
+impl ConvertHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+        })
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+impl SpotHttpClient {
+    /// 使用相同的密钥, 接口地址和连接池创建闪兑客户端
+    pub fn convert(&self) -> ConvertHttpClient {
+        ConvertHttpClient {
+            http_client: self.http_client.clone(),
+            api_key: self.api_key.clone(),
+            secret_key: self.secret_key.clone(),
+            base_url: self.base_url.clone(),
+            dry_run: self.dry_run.clone(),
+        }
+    }
+}
+
+impl ConvertHttpClient {
+    /// 支持闪兑的交易对和兑换数量限制
+    #[api(GET "sapi/v1/convert/exchangeInfo")]
+    pub async fn exchange_info(
+        &self,
+        param: params::PConvertPair,
+    ) -> BianResult<Vec<response::ConvertPair>> {
+    }
+
+    /// 询价, 报价在 `valid_timestamp` 之前有效
+    #[api(SPOST "sapi/v1/convert/getQuote")]
+    pub async fn get_quote(&self, param: params::PConvertQuote) -> BianResult<response::Quote> {}
+
+    /// 接受报价
+    #[api(SPOST "sapi/v1/convert/acceptQuote", dry_run)]
+    pub async fn accept_quote(
+        &self,
+        param: params::PAcceptQuote,
+    ) -> BianResult<response::AcceptedQuote> {
+    }
+
+    /// 查询闪兑订单状态
+    #[api(SGET "sapi/v1/convert/orderStatus")]
+    pub async fn order_status(
+        &self,
+        param: params::PConvertOrderStatus,
+    ) -> BianResult<response::ConvertOrder> {
+    }
+
+    /// 闪兑交易历史
+    #[api(SGET "sapi/v1/convert/tradeFlow")]
+    pub async fn trade_flow(
+        &self,
+        param: params::PConvertTradeFlow,
+    ) -> BianResult<response::ConvertTradeFlow> {
+    }
+
+    /// 询价并在报价有效期内接受报价
+    ///
+    /// 收到报价时已经过期则不接受, 返回 [`APIError::QuoteExpired`]
+    pub async fn convert(
+        &self,
+        param: params::PConvertQuote,
+    ) -> BianResult<(response::Quote, response::AcceptedQuote)> {
+        let quote = self.get_quote(param).await?;
+        if quote.is_expired() {
+            return Err(APIError::QuoteExpired(quote.quote_id));
+        }
+        let accepted = self
+            .accept_quote(params::PAcceptQuote {
+                quote_id: quote.quote_id.clone(),
+                ts: params::PTimestamp::now(),
+            })
+            .await?;
+        Ok((quote, accepted))
+    }
+}
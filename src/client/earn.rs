@@ -0,0 +1,197 @@
+use crate::error::{APIError, BianResult};
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::DryRun;
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 简单赚币 (活期和定期理财) http 客户端
+///
+/// 列表和历史接口使用 [`PPage`](crate::params::PPage) 分页, 可以配合
+/// [`collect_pages`](crate::pagination::collect_pages) 查询所有页。
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#simple-earn)
+#[derive(Debug, Clone)]
+pub struct EarnHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 开启后申购和赎回只签名并记录请求, 额度和持仓不做校验
+    pub dry_run: DryRun,
+}
+
+impl EarnHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+        })
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+/// 活期理财接口
+impl EarnHttpClient {
+    /// 活期产品列表
+    #[api(SGET "sapi/v1/simple-earn/flexible/list")]
+    pub async fn flexible_products(
+        &self,
+        param: params::PEarnProductList,
+    ) -> BianResult<response::Page<response::FlexibleProduct>> {
+    }
+
+    /// 申购活期产品
+    #[api(SPOST "sapi/v1/simple-earn/flexible/subscribe", dry_run)]
+    pub async fn flexible_subscribe(
+        &self,
+        param: params::PFlexibleSubscribe,
+    ) -> BianResult<response::EarnSubscribeResult> {
+    }
+
+    /// 赎回活期产品
+    #[api(SPOST "sapi/v1/simple-earn/flexible/redeem", dry_run)]
+    pub async fn flexible_redeem(
+        &self,
+        param: params::PFlexibleRedeem,
+    ) -> BianResult<response::EarnRedeemResult> {
+    }
+
+    /// 活期持仓
+    #[api(SGET "sapi/v1/simple-earn/flexible/position")]
+    pub async fn flexible_positions(
+        &self,
+        param: params::PFlexiblePosition,
+    ) -> BianResult<response::Page<response::FlexiblePosition>> {
+    }
+
+    /// 活期收益记录
+    #[api(SGET "sapi/v1/simple-earn/flexible/history/rewardsRecord")]
+    pub async fn flexible_rewards(
+        &self,
+        param: params::PFlexibleRewards,
+    ) -> BianResult<response::Page<response::FlexibleReward>> {
+    }
+
+    /// 活期产品的个人剩余申购额度
+    #[api(SGET "sapi/v1/simple-earn/flexible/personalLeftQuota")]
+    pub async fn flexible_quota(
+        &self,
+        param: params::PFlexibleQuota,
+    ) -> BianResult<response::EarnQuota> {
+    }
+
+    /// 将闲置资产申购到该资产的活期产品
+    ///
+    /// 没有可申购的活期产品或数量小于最小申购数量时不申购, 返回 `None`
+    pub async fn sweep(
+        &self,
+        asset: &str,
+        amount: f64,
+    ) -> BianResult<Option<response::EarnSubscribeResult>> {
+        let products = self
+            .flexible_products(params::PEarnProductList {
+                asset: Some(asset.to_string()),
+                page: params::PPage::default(),
+                ts: params::PTimestamp::now(),
+            })
+            .await?;
+        let product = products
+            .rows
+            .into_iter()
+            .find(|p| p.asset == asset && p.can_purchase && !p.is_sold_out);
+        match product {
+            Some(product) if amount >= product.min_purchase_amount => {
+                let param = params::PFlexibleSubscribe {
+                    product_id: product.product_id,
+                    amount,
+                    auto_subscribe: None,
+                    source_account: None,
+                    ts: params::PTimestamp::now(),
+                };
+                self.flexible_subscribe(param).await.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// 定期理财接口
+impl EarnHttpClient {
+    /// 定期产品列表
+    #[api(SGET "sapi/v1/simple-earn/locked/list")]
+    pub async fn locked_products(
+        &self,
+        param: params::PEarnProductList,
+    ) -> BianResult<response::Page<response::LockedProduct>> {
+    }
+
+    /// 申购定期产品
+    #[api(SPOST "sapi/v1/simple-earn/locked/subscribe", dry_run)]
+    pub async fn locked_subscribe(
+        &self,
+        param: params::PLockedSubscribe,
+    ) -> BianResult<response::EarnSubscribeResult> {
+    }
+
+    /// 提前赎回定期产品
+    #[api(SPOST "sapi/v1/simple-earn/locked/redeem", dry_run)]
+    pub async fn locked_redeem(
+        &self,
+        param: params::PLockedRedeem,
+    ) -> BianResult<response::EarnRedeemResult> {
+    }
+
+    /// 定期持仓
+    #[api(SGET "sapi/v1/simple-earn/locked/position")]
+    pub async fn locked_positions(
+        &self,
+        param: params::PLockedPosition,
+    ) -> BianResult<response::Page<response::LockedPosition>> {
+    }
+
+    /// 定期收益记录
+    #[api(SGET "sapi/v1/simple-earn/locked/history/rewardsRecord")]
+    pub async fn locked_rewards(
+        &self,
+        param: params::PLockedRewards,
+    ) -> BianResult<response::Page<response::LockedReward>> {
+    }
+
+    /// 定期产品的个人剩余申购额度
+    #[api(SGET "sapi/v1/simple-earn/locked/personalLeftQuota")]
+    pub async fn locked_quota(
+        &self,
+        param: params::PLockedQuota,
+    ) -> BianResult<response::EarnQuota> {
+    }
+}
@@ -0,0 +1,208 @@
+use crate::error::{APIError, BianResult};
+use crate::trading::ClientOrderIdGenerator;
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::DryRun;
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 全仓和逐仓杠杆 http 客户端
+///
+/// 杠杆账户的用户数据推送使用现货 websocket 客户端, 见
+/// [`MarginUserDataStream`](crate::user_stream::MarginUserDataStream)
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#c-margin)
+#[derive(Debug, Clone)]
+pub struct MarginHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// 下单时自动生成自定义订单号
+    pub client_order_ids: ClientOrderIdGenerator,
+    /// dry run 配置, 杠杆账户没有测试下单接口, 开启后下单按 `with_dry_run_rules` 设置的交易对规则
+    /// 在本地校验参数, 借还款和撤单只签名并记录请求
+    pub dry_run: DryRun,
+}
+
+impl MarginHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
+        })
+    }
+
+    /// 设置自动生成的自定义订单号前缀
+    pub fn with_client_order_prefix(mut self, prefix: &str) -> Self {
+        self.client_order_ids = ClientOrderIdGenerator::new(prefix);
+        self
+    }
+
+    dry_run_methods!(rules);
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+/// 借贷接口
+impl MarginHttpClient {
+    /// 杠杆账户借款或还款
+    #[api(SPOST "sapi/v1/margin/borrow-repay", dry_run)]
+    pub async fn borrow_repay(
+        &self,
+        param: params::PMarginBorrowRepay,
+    ) -> BianResult<response::MarginTransaction> {
+    }
+
+    /// 查询最大可借数量
+    #[api(SGET "sapi/v1/margin/maxBorrowable")]
+    pub async fn max_borrowable(
+        &self,
+        param: params::PMarginMaxAmount,
+    ) -> BianResult<response::MaxBorrowable> {
+    }
+
+    /// 查询最大可转出数量
+    #[api(SGET "sapi/v1/margin/maxTransferable")]
+    pub async fn max_transferable(
+        &self,
+        param: params::PMarginMaxAmount,
+    ) -> BianResult<response::MaxTransferable> {
+    }
+
+    /// 利息历史, 只能查询最近 6 个月的数据
+    #[api(SGET "sapi/v1/margin/interestHistory")]
+    pub async fn interest_history(
+        &self,
+        param: params::PMarginInterestHistory,
+    ) -> BianResult<response::MarginInterestHistory> {
+    }
+}
+
+/// 账户和交易接口
+impl MarginHttpClient {
+    /// 全仓杠杆账户信息
+    #[api(SGET "sapi/v1/margin/account")]
+    pub async fn account(&self, param: params::PTimestamp) -> BianResult<response::MarginAccount> {}
+
+    /// 逐仓杠杆账户信息
+    #[api(SGET "sapi/v1/margin/isolated/account")]
+    pub async fn isolated_account(
+        &self,
+        param: params::PIsolatedMarginAccount,
+    ) -> BianResult<response::IsolatedMarginAccount> {
+    }
+
+    /// 杠杆账户下单
+    ///
+    /// 订单参数与现货相同, 通过 `side_effect_type` 设置自动借款和还款
+    #[api(SPOST "sapi/v1/margin/order", client_order_id, dry_run = validate)]
+    pub async fn order(&self, param: params::PMarginOrder) -> BianResult<response::SpotOrder> {}
+
+    /// 杠杆账户撤销订单
+    #[api(SDELETE "sapi/v1/margin/order", dry_run)]
+    pub async fn cancel_order(
+        &self,
+        param: params::PMarginQueryOrder,
+    ) -> BianResult<response::MarginOpOrder> {
+    }
+
+    /// 查询杠杆账户订单
+    #[api(SGET "sapi/v1/margin/order")]
+    pub async fn query_order(
+        &self,
+        param: params::PMarginQueryOrder,
+    ) -> BianResult<response::QueryMarginOrder> {
+    }
+
+    /// 查询杠杆账户当前挂单
+    #[api(SGET "sapi/v1/margin/openOrders")]
+    pub async fn open_orders(
+        &self,
+        param: params::PMarginOpenOrders,
+    ) -> BianResult<Vec<response::QueryMarginOrder>> {
+    }
+
+    /// 查询杠杆账户所有订单
+    #[api(SGET "sapi/v1/margin/allOrders")]
+    pub async fn all_orders(
+        &self,
+        param: params::PMarginAllOrders,
+    ) -> BianResult<Vec<response::QueryMarginOrder>> {
+    }
+}
+
+/// 用户数据流接口
+impl MarginHttpClient {
+    /// 生成全仓杠杆 listenKey
+    #[api(POST "sapi/v1/userDataStream")]
+    pub async fn create_listen_key(&self) -> BianResult<response::ListenKey> {}
+
+    /// 延长全仓杠杆 listenKey 有效期, 建议每30分钟调用一次
+    #[api(PUT "sapi/v1/userDataStream")]
+    pub async fn update_listen_key(
+        &self,
+        param: params::PListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 关闭全仓杠杆 listenKey
+    #[api(DELETE "sapi/v1/userDataStream")]
+    pub async fn close_listen_key(
+        &self,
+        param: params::PListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 生成逐仓杠杆 listenKey, 每个逐仓交易对使用单独的 listenKey
+    #[api(POST "sapi/v1/userDataStream/isolated")]
+    pub async fn create_isolated_listen_key(
+        &self,
+        param: params::PSymbol,
+    ) -> BianResult<response::ListenKey> {
+    }
+
+    /// 延长逐仓杠杆 listenKey 有效期
+    #[api(PUT "sapi/v1/userDataStream/isolated")]
+    pub async fn update_isolated_listen_key(
+        &self,
+        param: params::PIsolatedListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 关闭逐仓杠杆 listenKey
+    #[api(DELETE "sapi/v1/userDataStream/isolated")]
+    pub async fn close_isolated_listen_key(
+        &self,
+        param: params::PIsolatedListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+}
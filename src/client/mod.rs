@@ -1,7 +1,63 @@
+use std::sync::Arc;
+
+use crate::rules::SymbolRulesCache;
+
+/// 生成 `with_dry_run`, 支持本地校验的客户端还会生成 `with_dry_run_rules`, 放在各客户端的 `sign` 旁边
+macro_rules! dry_run_methods {
+    () => {
+        /// 开启或关闭 dry run 模式, 各接口的行为见 `dry_run` 字段
+        pub fn with_dry_run(mut self, enabled: bool) -> Self {
+            self.dry_run.enabled = enabled;
+            self
+        }
+    };
+    (rules) => {
+        dry_run_methods!();
+
+        /// dry run 时按交易对规则在本地校验下单参数, 规则可以通过 `SymbolRulesCache` 从 `exchange_info` 创建
+        pub fn with_dry_run_rules(
+            mut self,
+            rules: std::sync::Arc<crate::rules::SymbolRulesCache>,
+        ) -> Self {
+            self.dry_run.rules = Some(rules);
+            self
+        }
+    };
+}
+
+mod algo;
 mod coin_futures;
+mod convert;
+mod earn;
+mod margin;
+mod options;
+mod portfolio_margin;
 mod spot;
+mod sub_account;
 mod usd_futures;
+mod wallet;
 
+pub use algo::AlgoHttpClient;
 pub use coin_futures::{DFuturesHttpClient, DFuturesWSClient};
+pub use convert::ConvertHttpClient;
+pub use earn::EarnHttpClient;
+pub use margin::MarginHttpClient;
+pub use options::{OptionsHttpClient, OptionsWSClient};
+pub use portfolio_margin::{
+    PortfolioMarginDryRun, PortfolioMarginHttpClient, PortfolioMarginWSClient,
+};
 pub use spot::{SpotHttpClient, SpotWSClient};
+pub use sub_account::{SubAccount, SubAccountHttpClient};
 pub use usd_futures::{UFuturesHttpClient, UFuturesWSClient};
+pub use wallet::WalletHttpClient;
+
+/// 客户端的 dry run 配置
+///
+/// 开启后写接口不会把请求发送到交易所, 而是返回 [`APIError::DryRun`](crate::error::APIError::DryRun),
+/// 各接口是否会先校验参数见客户端 `dry_run` 字段的说明。
+#[derive(Debug, Clone, Default)]
+pub struct DryRun {
+    pub enabled: bool,
+    /// 本地校验使用的交易对规则, 为 `None` 时只检查必填参数和数量
+    pub rules: Option<Arc<SymbolRulesCache>>,
+}
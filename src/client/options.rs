@@ -0,0 +1,321 @@
+use std::net::SocketAddr;
+
+use crate::error::{APIError, BianResult};
+use crate::params;
+use crate::response::{self, WebsocketResponse};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use tungstenite::{
+    client::{connect_with_config, AutoGenericStream},
+    WebSocket,
+};
+
+use super::DryRun;
+
+const BASE_HTTP_URL: &str = "https://eapi.binance.com";
+const BASE_WS_URL: &str = "wss://nbstream.binance.com/eoptions/";
+
+/// 欧式期权 http 客户端
+/// [doc](https://binance-docs.github.io/apidocs/voptions/cn/)
+#[derive(Debug, Clone)]
+pub struct OptionsHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 期权没有测试下单接口, 开启后下单, 批量下单和撤单只签名并记录请求
+    pub dry_run: DryRun,
+}
+
+impl OptionsHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+        })
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+/// 行情接口
+impl OptionsHttpClient {
+    /// 测试服务器连通性
+    #[api(GET "eapi/v1/ping")]
+    pub async fn ping(&self) -> BianResult<response::EmptyResponse> {}
+
+    /// 获取服务器时间
+    #[api(GET "eapi/v1/time")]
+    pub async fn server_time(&self) -> BianResult<response::ServerTime> {}
+
+    /// 获取交易规则和期权合约
+    #[api(GET "eapi/v1/exchangeInfo")]
+    pub async fn exchange_info(&self) -> BianResult<response::OptionsExchangeInfo> {}
+
+    /// 深度信息
+    ///
+    /// limit 可选 10, 20, 50, 100, 500, 1000
+    #[api(GET "eapi/v1/depth")]
+    pub async fn depth(&self, param: params::PDepth) -> BianResult<response::OptionsDepth> {}
+
+    /// K线数据
+    #[api(GET "eapi/v1/klines")]
+    pub async fn klines(&self, param: params::PKline) -> BianResult<Vec<response::OptionsKline>> {}
+
+    /// 标记价格和希腊值
+    ///
+    /// 不发送 symbol 时返回所有期权合约
+    #[api(GET "eapi/v1/mark")]
+    pub async fn mark(
+        &self,
+        param: params::POptionalSymbol,
+    ) -> BianResult<Vec<response::OptionsMark>> {
+    }
+
+    /// 标的指数价格
+    #[api(GET "eapi/v1/index")]
+    pub async fn index(&self, param: params::PUnderlying) -> BianResult<response::OptionsIndex> {}
+}
+
+/// 账户和交易接口
+impl OptionsHttpClient {
+    /// 账户信息
+    #[api(SGET "eapi/v1/account")]
+    pub async fn account(&self, param: params::PTimestamp) -> BianResult<response::OptionsAccount> {
+    }
+
+    /// 持仓信息
+    #[api(SGET "eapi/v1/position")]
+    pub async fn position(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::OptionsPosition>> {
+    }
+
+    /// 下单
+    #[api(SPOST "eapi/v1/order", dry_run)]
+    pub async fn order(
+        &self,
+        param: params::POptionsOrder,
+    ) -> BianResult<response::NewOptionsOrder> {
+    }
+
+    /// 批量下单
+    #[api(SPOST "eapi/v1/batchOrders", dry_run)]
+    pub async fn batch_order(
+        &self,
+        param: params::POptionsBatchOrder,
+    ) -> BianResult<Vec<response::OrderResponse<response::OptionsOrder>>> {
+    }
+
+    /// 查询订单
+    #[api(SGET "eapi/v1/order")]
+    pub async fn query_order(
+        &self,
+        param: params::POptionsQueryOrder,
+    ) -> BianResult<response::OptionsOrder> {
+    }
+
+    /// 撤销订单
+    #[api(SDELETE "eapi/v1/order", dry_run)]
+    pub async fn cancel_order(
+        &self,
+        param: params::POptionsQueryOrder,
+    ) -> BianResult<response::OptionsOrder> {
+    }
+
+    /// 撤销期权合约的全部挂单
+    #[api(SDELETE "eapi/v1/allOpenOrders", dry_run)]
+    pub async fn cancel_all_orders(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::CodeResponse> {
+    }
+
+    /// 查询当前挂单
+    #[api(SGET "eapi/v1/openOrders")]
+    pub async fn open_orders(
+        &self,
+        param: params::POptionsOpenOrders,
+    ) -> BianResult<Vec<response::OptionsOrder>> {
+    }
+}
+
+/// websocket 相关接口
+impl OptionsHttpClient {
+    /// 生成 listenKey
+    #[api(SPOST "eapi/v1/listenKey")]
+    pub async fn create_listen_key(&self) -> BianResult<response::ListenKey> {}
+
+    /// 更新 listenKey
+    #[api(SPUT "eapi/v1/listenKey")]
+    pub async fn update_listen_key(&self) -> BianResult<response::EmptyResponse> {}
+
+    /// 关闭 listenKey
+    #[api(SDELETE "eapi/v1/listenKey")]
+    pub async fn close_listen_key(&self) -> BianResult<response::EmptyResponse> {}
+}
+
+/// 欧式期权 websocket 客户端
+/// [doc](https://binance-docs.github.io/apidocs/voptions/cn/#websocket)
+#[derive(Debug, Clone)]
+pub struct OptionsWSClient {
+    pub proxy: Option<SocketAddr>,
+    pub base_url: url::Url,
+}
+
+impl OptionsWSClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(proxy: Option<SocketAddr>) -> Self {
+        let base_url = url::Url::parse(BASE_WS_URL).unwrap();
+        Self { base_url, proxy }
+    }
+
+    fn build_single(
+        &self,
+        symbol: &str,
+        channel: &str,
+    ) -> BianResult<WebSocket<AutoGenericStream>> {
+        let url = if symbol.is_empty() {
+            self.base_url.join(&format!("ws/{}", channel)).unwrap()
+        } else {
+            self.base_url
+                .join(&format!("ws/{}@{}", symbol, channel))
+                .unwrap()
+        };
+        let (socket, _) = connect_with_config(url, None, 3, self.proxy)
+            .map_err(|e| APIError::WSConnectError(e.to_string()))?;
+        Ok(socket)
+    }
+
+    fn build_multi(
+        &self,
+        symbols: Vec<String>,
+        channel: &str,
+    ) -> BianResult<WebSocket<AutoGenericStream>> {
+        let streams = symbols
+            .iter()
+            .map(|sym| format!("{}@{}", sym, channel))
+            .collect::<Vec<String>>()
+            .join("/");
+        let url = self
+            .base_url
+            .join(&format!("stream?streams={}", streams))
+            .unwrap();
+        let (socket, _) = connect_with_config(url, None, 3, self.proxy)
+            .map_err(|e| APIError::WSConnectError(e.to_string()))?;
+        Ok(socket)
+    }
+}
+
+fn depth_channel(level: usize, freq: usize) -> String {
+    let level = match level {
+        20 => 20,
+        50 => 50,
+        100 => 100,
+        _ => 10,
+    };
+    match freq {
+        100 => format!("depth{}@100ms", level),
+        1000 => format!("depth{}@1000ms", level),
+        _ => format!("depth{}", level),
+    }
+}
+
+/// 行情
+impl OptionsWSClient {
+    /// 24小时 ticker, 包含隐含波动率和希腊值
+    ///
+    /// Update Speed: 1000ms
+    pub fn ticker(
+        &self,
+        symbol: &str,
+    ) -> BianResult<impl WebsocketResponse<response::WSOptionsTicker>> {
+        self.build_single(symbol, "ticker")
+    }
+
+    /// 24小时 ticker, 包含隐含波动率和希腊值
+    ///
+    /// Update Speed: 1000ms
+    pub fn ticker_multi(
+        &self,
+        symbols: Vec<String>,
+    ) -> BianResult<impl WebsocketResponse<response::WSOptionsTicker>> {
+        self.build_multi(symbols, "ticker")
+    }
+
+    /// 标的资产 (如 BTC) 下所有期权合约的标记价格
+    ///
+    /// Update Speed: 1000ms
+    pub fn mark_price(
+        &self,
+        base_asset: &str,
+    ) -> BianResult<impl WebsocketResponse<Vec<response::WSOptionsMarkPrice>>> {
+        self.build_single(base_asset, "markPrice")
+    }
+
+    /// 有限档深度信息
+    ///
+    /// level 可选 10/20/50/100 档, freq 可选 100ms/500ms/1000ms, 默认 500ms
+    pub fn limit_depth(
+        &self,
+        symbol: &str,
+        level: usize,
+        freq: usize,
+    ) -> BianResult<impl WebsocketResponse<response::WSOptionsDepth>> {
+        self.build_single(symbol, &depth_channel(level, freq))
+    }
+
+    /// 有限档深度信息
+    ///
+    /// level 可选 10/20/50/100 档, freq 可选 100ms/500ms/1000ms, 默认 500ms
+    pub fn limit_depth_multi(
+        &self,
+        symbols: Vec<String>,
+        level: usize,
+        freq: usize,
+    ) -> BianResult<impl WebsocketResponse<response::WSOptionsDepth>> {
+        self.build_multi(symbols, &depth_channel(level, freq))
+    }
+}
+
+/// 用户 data stream
+impl OptionsWSClient {
+    /// 账户信息推送
+    ///
+    /// listenKey 需要每 30 分钟续期一次, 可以使用 [`UserDataStream`](crate::user_stream::UserDataStream)
+    /// 自动续期和重连
+    pub fn user_data(
+        &self,
+        listen_key: &str,
+    ) -> BianResult<impl WebsocketResponse<response::WSOptionsUserStream>> {
+        self.build_single("", listen_key)
+    }
+}
@@ -0,0 +1,352 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use crate::error::{APIError, BianResult};
+use crate::params;
+use crate::response::{self, WebsocketResponse};
+use crate::rules::SymbolRulesCache;
+use crate::trading::ClientOrderIdGenerator;
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use tungstenite::client::connect_with_config;
+
+const BASE_HTTP_URL: &str = "https://papi.binance.com";
+const BASE_WS_URL: &str = "wss://fstream.binance.com/pm/";
+
+/// 统一账户的 dry run 配置
+///
+/// 统一账户同时交易U本位合约, 币本位合约和杠杆, 同名交易对(如 `BTCUSDT`)在不同产品下的规则不同,
+/// 因此每个产品使用单独的规则缓存, 为 `None` 时只检查必填参数和数量。
+#[derive(Debug, Clone, Default)]
+pub struct PortfolioMarginDryRun {
+    pub enabled: bool,
+    /// U本位合约下单使用的交易对规则
+    pub um_rules: Option<Arc<SymbolRulesCache>>,
+    /// 币本位合约下单使用的交易对规则
+    pub cm_rules: Option<Arc<SymbolRulesCache>>,
+    /// 杠杆下单使用的交易对规则
+    pub margin_rules: Option<Arc<SymbolRulesCache>>,
+}
+
+/// 统一账户 http 客户端
+///
+/// 统一账户的U本位合约, 币本位合约和全仓杠杆下单都通过 `papi` 接口, 不能使用
+/// `fapi`/`dapi`/`sapi` 客户端。合约订单参数和返回值与合约客户端相同。
+/// [doc](https://binance-docs.github.io/apidocs/pm/cn/)
+#[derive(Debug, Clone)]
+pub struct PortfolioMarginHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// 下单时自动生成自定义订单号
+    pub client_order_ids: ClientOrderIdGenerator,
+    /// dry run 配置, 统一账户没有测试下单接口, 开启后U本位合约, 币本位合约和杠杆下单分别按
+    /// `with_um_dry_run_rules`, `with_cm_dry_run_rules` 和 `with_margin_dry_run_rules`
+    /// 设置的交易对规则在本地校验参数, 撤单和资金归集接口只签名并记录请求
+    pub dry_run: PortfolioMarginDryRun,
+}
+
+impl PortfolioMarginHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: PortfolioMarginDryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: PortfolioMarginDryRun::default(),
+        })
+    }
+
+    /// 设置自动生成的自定义订单号前缀
+    pub fn with_client_order_prefix(mut self, prefix: &str) -> Self {
+        self.client_order_ids = ClientOrderIdGenerator::new(prefix);
+        self
+    }
+
+    dry_run_methods!();
+
+    /// dry run 时按U本位合约交易对规则在本地校验U本位合约下单参数
+    pub fn with_um_dry_run_rules(mut self, rules: Arc<SymbolRulesCache>) -> Self {
+        self.dry_run.um_rules = Some(rules);
+        self
+    }
+
+    /// dry run 时按币本位合约交易对规则在本地校验币本位合约下单参数
+    pub fn with_cm_dry_run_rules(mut self, rules: Arc<SymbolRulesCache>) -> Self {
+        self.dry_run.cm_rules = Some(rules);
+        self
+    }
+
+    /// dry run 时按杠杆交易对规则在本地校验杠杆下单参数
+    pub fn with_margin_dry_run_rules(mut self, rules: Arc<SymbolRulesCache>) -> Self {
+        self.dry_run.margin_rules = Some(rules);
+        self
+    }
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+/// U本位合约交易接口
+impl PortfolioMarginHttpClient {
+    /// U本位合约下单, 仅支持 `LIMIT` 和 `MARKET` 订单
+    #[api(SPOST "papi/v1/um/order", client_order_id, dry_run = validate(um_rules))]
+    pub async fn um_order(
+        &self,
+        param: params::PFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 查询U本位合约订单
+    #[api(SGET "papi/v1/um/order")]
+    pub async fn um_query_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 撤销U本位合约订单
+    #[api(SDELETE "papi/v1/um/order", dry_run)]
+    pub async fn um_cancel_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 撤销U本位合约交易对的全部挂单
+    #[api(SDELETE "papi/v1/um/allOpenOrders", dry_run)]
+    pub async fn um_cancel_all_orders(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::CodeResponse> {
+    }
+
+    /// 查询U本位合约当前挂单, 不传交易对时返回全部挂单
+    #[api(SGET "papi/v1/um/openOrders")]
+    pub async fn um_open_orders(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+    }
+
+    /// 查询U本位合约所有订单
+    #[api(SGET "papi/v1/um/allOrders")]
+    pub async fn um_all_orders(
+        &self,
+        param: params::PHistOrder,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+    }
+
+    /// U本位合约持仓
+    #[api(SGET "papi/v1/um/positionRisk")]
+    pub async fn um_positions(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::PortfolioMarginPosition>> {
+    }
+}
+
+/// 币本位合约交易接口
+impl PortfolioMarginHttpClient {
+    /// 币本位合约下单, 仅支持 `LIMIT` 和 `MARKET` 订单
+    #[api(SPOST "papi/v1/cm/order", client_order_id, dry_run = validate(cm_rules))]
+    pub async fn cm_order(
+        &self,
+        param: params::PFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 查询币本位合约订单
+    #[api(SGET "papi/v1/cm/order")]
+    pub async fn cm_query_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 撤销币本位合约订单
+    #[api(SDELETE "papi/v1/cm/order", dry_run)]
+    pub async fn cm_cancel_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 撤销币本位合约交易对的全部挂单
+    #[api(SDELETE "papi/v1/cm/allOpenOrders", dry_run)]
+    pub async fn cm_cancel_all_orders(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::CodeResponse> {
+    }
+
+    /// 查询币本位合约当前挂单, 不传交易对时返回全部挂单
+    #[api(SGET "papi/v1/cm/openOrders")]
+    pub async fn cm_open_orders(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+    }
+
+    /// 查询币本位合约所有订单
+    #[api(SGET "papi/v1/cm/allOrders")]
+    pub async fn cm_all_orders(
+        &self,
+        param: params::PHistOrder,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+    }
+
+    /// 币本位合约持仓
+    #[api(SGET "papi/v1/cm/positionRisk")]
+    pub async fn cm_positions(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::PortfolioMarginPosition>> {
+    }
+}
+
+/// 全仓杠杆交易接口, 统一账户不支持逐仓, `is_isolated` 需要为 `None`
+impl PortfolioMarginHttpClient {
+    /// 杠杆下单
+    #[api(SPOST "papi/v1/margin/order", client_order_id, dry_run = validate(margin_rules))]
+    pub async fn margin_order(
+        &self,
+        param: params::PMarginOrder,
+    ) -> BianResult<response::SpotOrder> {
+    }
+
+    /// 查询杠杆订单
+    #[api(SGET "papi/v1/margin/order")]
+    pub async fn margin_query_order(
+        &self,
+        param: params::PMarginQueryOrder,
+    ) -> BianResult<response::QueryMarginOrder> {
+    }
+
+    /// 撤销杠杆订单
+    #[api(SDELETE "papi/v1/margin/order", dry_run)]
+    pub async fn margin_cancel_order(
+        &self,
+        param: params::PMarginQueryOrder,
+    ) -> BianResult<response::MarginOpOrder> {
+    }
+
+    /// 查询杠杆当前挂单
+    #[api(SGET "papi/v1/margin/openOrders")]
+    pub async fn margin_open_orders(
+        &self,
+        param: params::PMarginOpenOrders,
+    ) -> BianResult<Vec<response::QueryMarginOrder>> {
+    }
+
+    /// 查询杠杆所有订单
+    #[api(SGET "papi/v1/margin/allOrders")]
+    pub async fn margin_all_orders(
+        &self,
+        param: params::PMarginAllOrders,
+    ) -> BianResult<Vec<response::QueryMarginOrder>> {
+    }
+}
+
+/// 账户接口
+impl PortfolioMarginHttpClient {
+    /// 统一账户资产余额
+    #[api(SGET "papi/v1/balance")]
+    pub async fn balance(
+        &self,
+        param: params::PPortfolioMarginBalance,
+    ) -> BianResult<Vec<response::PortfolioMarginBalance>> {
+    }
+
+    /// 统一账户信息
+    #[api(SGET "papi/v1/account")]
+    pub async fn account(
+        &self,
+        param: params::PTimestamp,
+    ) -> BianResult<response::PortfolioMarginAccount> {
+    }
+
+    /// 将合约账户中所有资产归集到统一账户, 不包括 BNB
+    #[api(SPOST "papi/v1/auto-collection", dry_run)]
+    pub async fn auto_collection(
+        &self,
+        param: params::PTimestamp,
+    ) -> BianResult<response::CollectionResult> {
+    }
+
+    /// 将合约账户中指定资产归集到统一账户
+    #[api(SPOST "papi/v1/asset-collection", dry_run)]
+    pub async fn asset_collection(
+        &self,
+        param: params::PAssetCollection,
+    ) -> BianResult<response::CollectionResult> {
+    }
+}
+
+/// 用户数据流接口
+impl PortfolioMarginHttpClient {
+    /// 生成 listenKey
+    #[api(POST "papi/v1/listenKey")]
+    pub async fn create_listen_key(&self) -> BianResult<response::ListenKey> {}
+
+    /// 延长 listenKey 有效期, 建议每30分钟调用一次
+    #[api(PUT "papi/v1/listenKey")]
+    pub async fn update_listen_key(&self) -> BianResult<response::EmptyResponse> {}
+
+    /// 关闭 listenKey
+    #[api(DELETE "papi/v1/listenKey")]
+    pub async fn close_listen_key(&self) -> BianResult<response::EmptyResponse> {}
+}
+
+/// 统一账户 websocket 客户端, 只支持用户数据推送
+#[derive(Debug, Clone)]
+pub struct PortfolioMarginWSClient {
+    pub proxy: Option<SocketAddr>,
+    pub base_url: url::Url,
+}
+
+impl PortfolioMarginWSClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(proxy: Option<SocketAddr>) -> Self {
+        let base_url = url::Url::parse(BASE_WS_URL).unwrap();
+        Self { base_url, proxy }
+    }
+
+    /// 账户信息推送, 包括合约和杠杆账户
+    ///
+    /// listenKey 需要每 30 分钟续期一次, 可以使用 [`UserDataStream`](crate::user_stream::UserDataStream)
+    /// 自动续期和重连
+    pub fn user_data(
+        &self,
+        listen_key: &str,
+    ) -> BianResult<impl WebsocketResponse<response::WSPortfolioMarginUserStream>> {
+        let url = self.base_url.join(&format!("ws/{}", listen_key)).unwrap();
+        let (socket, _) = connect_with_config(url, None, 3, self.proxy)
+            .map_err(|e| APIError::WSConnectError(e.to_string()))?;
+        Ok(socket)
+    }
+}
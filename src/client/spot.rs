@@ -1,5 +1,6 @@
 use std::net::SocketAddr;
 
+use crate::trading::ClientOrderIdGenerator;
 use crate::{
     enums::Interval,
     error::{APIError, BianResult},
@@ -14,16 +15,24 @@ use tungstenite::{
     WebSocket,
 };
 
+use super::DryRun;
+
 const BASE_HTTP_URL: &str = "https://api.binance.com";
 const BASE_WS_URL: &str = "wss://stream.binance.com:9443";
 
 /// 现货账户客户端
 #[derive(Debug, Clone)]
 pub struct SpotHttpClient {
-    http_client: reqwest::Client,
+    pub(super) http_client: reqwest::Client,
     pub api_key: String,
     pub secret_key: String,
     pub base_url: url::Url,
+    /// 下单时自动生成自定义订单号
+    pub client_order_ids: ClientOrderIdGenerator,
+    /// dry run 配置, 开启后 `order` 和 `sor_order` 会先发送到对应的测试接口由交易所校验参数,
+    /// 撤单再下单, OCO 和订单列表按 `with_dry_run_rules` 设置的交易对规则在本地校验参数,
+    /// 撤单接口只签名并记录请求
+    pub dry_run: DryRun,
 }
 
 impl SpotHttpClient {
@@ -36,6 +45,8 @@ impl SpotHttpClient {
             api_key,
             secret_key,
             http_client,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         }
     }
 
@@ -48,9 +59,19 @@ impl SpotHttpClient {
             api_key,
             secret_key,
             base_url,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         })
     }
 
+    /// 设置自动生成的自定义订单号前缀
+    pub fn with_client_order_prefix(mut self, prefix: &str) -> Self {
+        self.client_order_ids = ClientOrderIdGenerator::new(prefix);
+        self
+    }
+
+    dry_run_methods!(rules);
+
     fn sign<P: serde::Serialize>(&self, params: &P) -> String {
         let qs = serde_qs::to_string(&params).unwrap();
         let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
@@ -136,12 +157,45 @@ impl SpotHttpClient {
 
 /// 现货账户和交易接口
 impl SpotHttpClient {
-    /// 下单
-    #[api(SPOST "api/v3/order")]
+    /// 下单, 未指定自定义订单号时自动生成
+    #[api(SPOST "api/v3/order", client_order_id, dry_run = "api/v3/order/test")]
     pub async fn order(&self, param: params::PSpotOrder) -> BianResult<response::SpotOrder> {}
 
+    /// 测试下单
+    ///
+    /// 校验下单参数和签名, 订单不会发送到撮合引擎
+    #[api(SPOST "api/v3/order/test")]
+    pub async fn order_test(
+        &self,
+        param: params::PSpotOrder,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 智能订单路由 (SOR) 下单, 仅支持限价单和市价单, 未指定自定义订单号时自动生成
+    #[api(SPOST "api/v3/sor/order", client_order_id, dry_run = "api/v3/sor/order/test")]
+    pub async fn sor_order(&self, param: params::PSpotOrder) -> BianResult<response::SorOrder> {}
+
+    /// 测试智能订单路由下单, 订单不会发送到撮合引擎
+    #[api(SPOST "api/v3/sor/order/test")]
+    pub async fn sor_order_test(
+        &self,
+        param: params::PSpotOrder,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 撤消挂单再下单
+    ///
+    /// 撤单和下单在同一请求中完成, 部分失败时返回 4xx 错误,
+    /// 可以通过 [`SpotCancelReplace::from_error`](response::SpotCancelReplace::from_error) 获取撤单和下单结果
+    #[api(SPOST "api/v3/order/cancelReplace", dry_run = validate)]
+    pub async fn cancel_replace(
+        &self,
+        param: params::PCancelReplace,
+    ) -> BianResult<response::SpotCancelReplace> {
+    }
+
     /// 撤销订单
-    #[api(SDELETE "api/v3/order")]
+    #[api(SDELETE "api/v3/order", dry_run)]
     pub async fn cancel_order(
         &self,
         param: params::PQuerySpotOrder,
@@ -151,7 +205,7 @@ impl SpotHttpClient {
     /// 撤销单一交易对的所有挂单
     ///
     /// 撤销单一交易对下所有挂单, 包括OCO的挂单。
-    #[api(SDELETE "api/v3/openOrders")]
+    #[api(SDELETE "api/v3/openOrders", dry_run)]
     pub async fn cancel_open_orders(
         &self,
         param: params::PSymbolWithTs,
@@ -185,12 +239,38 @@ impl SpotHttpClient {
     }
 
     /// OCO 下单
+    ///
+    /// 币安已废弃该接口, 新代码请使用 [`order_list_oco`](Self::order_list_oco)
     /// [DOC](https://binance-docs.github.io/apidocs/spot/cn/#oco-trade)
-    #[api(SPOST "api/v3/order/oco")]
+    #[api(SPOST "api/v3/order/oco", dry_run = validate)]
     pub async fn oco_order(&self, param: params::POcoOrder) -> BianResult<response::OcoOrder> {}
 
+    /// OCO 订单列表下单
+    #[api(SPOST "api/v3/orderList/oco", dry_run = validate)]
+    pub async fn order_list_oco(
+        &self,
+        param: params::POrderListOco,
+    ) -> BianResult<response::OcoOrder> {
+    }
+
+    /// OTO 订单列表下单
+    #[api(SPOST "api/v3/orderList/oto", dry_run = validate)]
+    pub async fn order_list_oto(
+        &self,
+        param: params::POrderListOto,
+    ) -> BianResult<response::OcoOrder> {
+    }
+
+    /// OTOCO 订单列表下单
+    #[api(SPOST "api/v3/orderList/otoco", dry_run = validate)]
+    pub async fn order_list_otoco(
+        &self,
+        param: params::POrderListOtoco,
+    ) -> BianResult<response::OcoOrder> {
+    }
+
     /// 取消 OCO 订单
-    #[api(SDELETE "api/v3/orderList")]
+    #[api(SDELETE "api/v3/orderList", dry_run)]
     pub async fn cancel_oco_order(
         &self,
         param: params::PCancelOcoOrder,
@@ -234,6 +314,32 @@ impl SpotHttpClient {
     }
 }
 
+/// 用户数据流接口
+impl SpotHttpClient {
+    /// 生成 listenKey
+    ///
+    /// 开始一个新的数据流, 数据流在60分钟后关闭, 除非发送 keepalive。
+    /// 如果该帐户具有有效的listenKey，则将返回该listenKey并将其有效期延长60分钟。
+    #[api(POST "api/v3/userDataStream")]
+    pub async fn create_listen_key(&self) -> BianResult<response::ListenKey> {}
+
+    /// 延长 listenKey 有效期, 建议每30分钟调用一次
+    #[api(PUT "api/v3/userDataStream")]
+    pub async fn update_listen_key(
+        &self,
+        param: params::PListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+
+    /// 关闭 listenKey
+    #[api(DELETE "api/v3/userDataStream")]
+    pub async fn close_listen_key(
+        &self,
+        param: params::PListenKey,
+    ) -> BianResult<response::EmptyResponse> {
+    }
+}
+
 /// 现货 websocket 客户端
 #[derive(Debug, Clone)]
 pub struct SpotWSClient {
@@ -503,3 +609,17 @@ impl SpotWSClient {
         self.build_multi(symbols, &channel)
     }
 }
+
+/// 用户 data stream
+impl SpotWSClient {
+    /// 账户信息推送
+    ///
+    /// listenKey 需要每 30 分钟续期一次, 可以使用 [`UserDataStream`](crate::user_stream::UserDataStream)
+    /// 自动续期和重连
+    pub fn user_data(
+        &self,
+        listen_key: &str,
+    ) -> BianResult<impl WebsocketResponse<response::WSSpotUserStream>> {
+        self.build_single(String::new(), listen_key)
+    }
+}
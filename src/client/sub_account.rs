@@ -0,0 +1,279 @@
+use crate::enums::SubAccountType;
+use crate::error::{APIError, BianResult};
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::DryRun;
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 子账户 http 客户端, 需要使用母账户的 API Key
+///
+/// 母账户不能直接使用子账户下单, 子账户交易需要使用子账户自己的 API Key 创建现货或合约客户端。
+/// 母账户可以代替子账户查询资产, 开通合约和杠杆以及划转资产, 见 [`SubAccount`]。
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#sub-account)
+#[derive(Debug, Clone)]
+pub struct SubAccountHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 开启后创建虚拟子账户, 开通合约和杠杆, 万向划转和 IP 白名单设置只签名并记录请求
+    pub dry_run: DryRun,
+}
+
+impl SubAccountHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+        })
+    }
+
+    /// 代替指定子账户调用接口
+    pub fn sub_account(&self, email: &str) -> SubAccount {
+        SubAccount {
+            client: self.clone(),
+            email: email.to_string(),
+        }
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        let qs = serde_qs::to_string(&params).unwrap();
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+/// 子账户管理接口
+impl SubAccountHttpClient {
+    /// 查询子账户列表
+    #[api(SGET "sapi/v1/sub-account/list")]
+    pub async fn list(
+        &self,
+        param: params::PSubAccountList,
+    ) -> BianResult<response::SubAccountList> {
+    }
+
+    /// 创建虚拟子账户
+    #[api(SPOST "sapi/v1/sub-account/virtualSubAccount", dry_run)]
+    pub async fn create_virtual(
+        &self,
+        param: params::PVirtualSubAccount,
+    ) -> BianResult<response::VirtualSubAccount> {
+    }
+
+    /// 为子账户开通合约
+    #[api(SPOST "sapi/v1/sub-account/futures/enable", dry_run)]
+    pub async fn enable_futures(
+        &self,
+        param: params::PSubAccountEmail,
+    ) -> BianResult<response::SubAccountEnabled> {
+    }
+
+    /// 为子账户开通杠杆
+    #[api(SPOST "sapi/v1/sub-account/margin/enable", dry_run)]
+    pub async fn enable_margin(
+        &self,
+        param: params::PSubAccountEmail,
+    ) -> BianResult<response::SubAccountEnabled> {
+    }
+}
+
+/// 资产接口
+impl SubAccountHttpClient {
+    /// 子账户现货资产
+    #[api(SGET "sapi/v4/sub-account/assets")]
+    pub async fn assets(
+        &self,
+        param: params::PSubAccountEmail,
+    ) -> BianResult<response::SubAccountAssets> {
+    }
+
+    /// 子账户现货资产汇总
+    #[api(SGET "sapi/v1/sub-account/spotSummary")]
+    pub async fn spot_summary(
+        &self,
+        param: params::PSubSpotSummary,
+    ) -> BianResult<response::SubSpotSummary> {
+    }
+
+    /// 子账户合约资产汇总
+    #[api(SGET "sapi/v2/sub-account/futures/accountSummary")]
+    pub async fn futures_summary(
+        &self,
+        param: params::PSubFuturesSummary,
+    ) -> BianResult<response::SubFuturesSummary> {
+    }
+
+    /// 母子账户万向划转
+    #[api(SPOST "sapi/v1/sub-account/universalTransfer", dry_run)]
+    pub async fn universal_transfer(
+        &self,
+        param: params::PSubUniversalTransfer,
+    ) -> BianResult<response::SubTransferResult> {
+    }
+
+    /// 母子账户万向划转历史
+    #[api(SGET "sapi/v1/sub-account/universalTransfer")]
+    pub async fn transfer_history(
+        &self,
+        param: params::PSubTransferHistory,
+    ) -> BianResult<response::SubTransferHistory> {
+    }
+}
+
+/// 子账户 API Key 接口
+impl SubAccountHttpClient {
+    /// 查询子账户 API Key 的 IP 限制
+    #[api(SGET "sapi/v1/sub-account/subAccountApi/ipRestriction")]
+    pub async fn api_ip_restriction(
+        &self,
+        param: params::PSubApiKey,
+    ) -> BianResult<response::SubApiIpRestriction> {
+    }
+
+    /// 设置子账户 API Key 的 IP 限制和白名单
+    #[api(SPOST "sapi/v2/sub-account/subAccountApi/ipRestriction", dry_run)]
+    pub async fn set_api_ip_restriction(
+        &self,
+        param: params::PSubApiIpRestriction,
+    ) -> BianResult<response::SubApiIpRestriction> {
+    }
+
+    /// 删除子账户 API Key 的白名单 IP
+    #[api(SDELETE "sapi/v1/sub-account/subAccountApi/ipRestriction/ipList", dry_run)]
+    pub async fn delete_api_ip_list(
+        &self,
+        param: params::PSubApiIpList,
+    ) -> BianResult<response::SubApiIpRestriction> {
+    }
+}
+
+/// 代替子账户调用接口, 自动填充子账户邮箱
+#[derive(Debug, Clone)]
+pub struct SubAccount {
+    pub client: SubAccountHttpClient,
+    pub email: String,
+}
+
+impl SubAccount {
+    fn email_param(&self) -> params::PSubAccountEmail {
+        params::PSubAccountEmail {
+            email: self.email.clone(),
+            ts: params::PTimestamp::now(),
+        }
+    }
+
+    /// 现货资产
+    pub async fn assets(&self) -> BianResult<response::SubAccountAssets> {
+        self.client.assets(self.email_param()).await
+    }
+
+    /// 开通合约
+    pub async fn enable_futures(&self) -> BianResult<response::SubAccountEnabled> {
+        self.client.enable_futures(self.email_param()).await
+    }
+
+    /// 开通杠杆
+    pub async fn enable_margin(&self) -> BianResult<response::SubAccountEnabled> {
+        self.client.enable_margin(self.email_param()).await
+    }
+
+    /// 从母账户的 `from` 账户划转到子账户的 `to` 账户
+    pub async fn transfer_in(
+        &self,
+        from: SubAccountType,
+        to: SubAccountType,
+        asset: &str,
+        amount: f64,
+    ) -> BianResult<response::SubTransferResult> {
+        let param = params::PSubUniversalTransfer {
+            from_email: None,
+            to_email: Some(self.email.clone()),
+            from_account_type: from,
+            to_account_type: to,
+            client_tran_id: None,
+            symbol: None,
+            asset: asset.to_string(),
+            amount,
+            ts: params::PTimestamp::now(),
+        };
+        self.client.universal_transfer(param).await
+    }
+
+    /// 从子账户的 `from` 账户划转到母账户的 `to` 账户
+    pub async fn transfer_out(
+        &self,
+        from: SubAccountType,
+        to: SubAccountType,
+        asset: &str,
+        amount: f64,
+    ) -> BianResult<response::SubTransferResult> {
+        let param = params::PSubUniversalTransfer {
+            from_email: Some(self.email.clone()),
+            to_email: None,
+            from_account_type: from,
+            to_account_type: to,
+            client_tran_id: None,
+            symbol: None,
+            asset: asset.to_string(),
+            amount,
+            ts: params::PTimestamp::now(),
+        };
+        self.client.universal_transfer(param).await
+    }
+
+    /// 查询子账户 API Key 的 IP 限制
+    pub async fn api_ip_restriction(
+        &self,
+        api_key: &str,
+    ) -> BianResult<response::SubApiIpRestriction> {
+        let param = params::PSubApiKey {
+            email: self.email.clone(),
+            sub_account_api_key: api_key.to_string(),
+            ts: params::PTimestamp::now(),
+        };
+        self.client.api_ip_restriction(param).await
+    }
+
+    /// 限制子账户 API Key 只能从 `ips` 访问
+    pub async fn restrict_api_ips(
+        &self,
+        api_key: &str,
+        ips: &[&str],
+    ) -> BianResult<response::SubApiIpRestriction> {
+        let param = params::PSubApiIpRestriction {
+            email: self.email.clone(),
+            sub_account_api_key: api_key.to_string(),
+            status: crate::enums::IpRestriction::Restricted,
+            ip_address: Some(ips.join(",")),
+            ts: params::PTimestamp::now(),
+        };
+        self.client.set_api_ip_restriction(param).await
+    }
+}
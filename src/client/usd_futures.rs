@@ -4,6 +4,7 @@ use crate::enums::{ContractType, Interval};
 use crate::error::{APIError, BianResult};
 use crate::params;
 use crate::response::{self, WebsocketResponse};
+use crate::trading::ClientOrderIdGenerator;
 use bian_proc::api;
 use hmac::{Hmac, Mac, NewMac};
 use sha2::Sha256;
@@ -12,6 +13,8 @@ use tungstenite::{
     WebSocket,
 };
 
+use super::DryRun;
+
 const BASE_HTTP_URL: &str = "https://dapi.binance.com";
 const BASE_WS_URL: &str = "wss://dstream.binance.com";
 
@@ -23,6 +26,12 @@ pub struct UFuturesHttpClient {
     pub api_key: String,
     pub secret_key: String,
     pub base_url: url::Url,
+    /// 下单时自动生成自定义订单号
+    pub client_order_ids: ClientOrderIdGenerator,
+    /// dry run 配置, 开启后 `order` 会先发送到 `fapi/v1/order/test` 由交易所校验参数,
+    /// 批量下单和改单按 `with_dry_run_rules` 设置的交易对规则在本地校验参数,
+    /// 撤单和倒计时撤单接口只签名并记录请求
+    pub dry_run: DryRun,
 }
 
 impl UFuturesHttpClient {
@@ -35,6 +44,8 @@ impl UFuturesHttpClient {
             api_key,
             secret_key,
             http_client,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         }
     }
 
@@ -47,9 +58,19 @@ impl UFuturesHttpClient {
             api_key: api_key.to_string(),
             secret_key: secret_key.to_string(),
             base_url,
+            client_order_ids: ClientOrderIdGenerator::default(),
+            dry_run: DryRun::default(),
         })
     }
 
+    /// 设置自动生成的自定义订单号前缀
+    pub fn with_client_order_prefix(mut self, prefix: &str) -> Self {
+        self.client_order_ids = ClientOrderIdGenerator::new(prefix);
+        self
+    }
+
+    dry_run_methods!(rules);
+
     fn sign<P: serde::Serialize>(&self, params: &P) -> String {
         let qs = serde_qs::to_string(&params).unwrap();
         let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
@@ -153,6 +174,14 @@ impl UFuturesHttpClient {
     #[api(GET "fapi/v1/ticker/price")]
     pub async fn prices(&self) -> BianResult<Vec<response::FuturesPrice>> {}
 
+    /// 最新价格V2(单个symbol), 与 V1 相比权重更低
+    #[api(GET "fapi/v2/ticker/price")]
+    pub async fn price_v2(&self, param: params::PSymbol) -> BianResult<response::FuturesPrice> {}
+
+    /// 最新价格V2
+    #[api(GET "fapi/v2/ticker/price")]
+    pub async fn prices_v2(&self) -> BianResult<Vec<response::FuturesPrice>> {}
+
     /// 当前最优挂单(单symbol)
     #[api(GET "fapi/v1/ticker/bookTicker")]
     pub async fn book_ticker(
@@ -260,14 +289,14 @@ impl UFuturesHttpClient {
     ) -> BianResult<response::PositionSide> {
     }
 
-    /// 下单
+    /// 下单, 未指定自定义订单号时自动生成
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-2)
-    #[api(SPOST "fapi/v1/order")]
+    #[api(SPOST "fapi/v1/order", client_order_id, dry_run = "fapi/v1/order/test")]
     pub async fn order(&self, param: params::PFuturesOrder) -> BianResult<response::FuturesOrder> {}
 
-    /// 批量下单
+    /// 批量下单, 未指定自定义订单号的订单自动生成
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-4)
-    #[api(SPOST "fapi/v1/batchOrders")]
+    #[api(SPOST "fapi/v1/batchOrders", client_order_id, dry_run = validate)]
     pub async fn batch_order(
         &self,
         param: params::PBatchOrder,
@@ -287,7 +316,7 @@ impl UFuturesHttpClient {
     /// 撤销订单
     ///
     /// 至少需要发送 orderId 与 origClientOrderId 中的一个
-    #[api(SDELETE "fapi/v1/order")]
+    #[api(SDELETE "fapi/v1/order", dry_run)]
     pub async fn cancel_order(
         &self,
         param: params::PQueryFuturesOrder,
@@ -295,15 +324,41 @@ impl UFuturesHttpClient {
     }
 
     /// 撤销全部订单
-    #[api(SDELETE "fapi/v1/allOpenOrders")]
+    #[api(SDELETE "fapi/v1/allOpenOrders", dry_run)]
     pub async fn cancel_all_orders(
         &self,
         param: params::PSymbolWithTs,
     ) -> BianResult<response::CodeResponse> {
     }
 
+    /// 修改订单, 仅支持限价单
+    ///
+    /// 修改后订单在同价格档位中重新排队
+    #[api(SPUT "fapi/v1/order", dry_run = validate)]
+    pub async fn modify_order(
+        &self,
+        param: params::PModifyOrder,
+    ) -> BianResult<response::FuturesOrder> {
+    }
+
+    /// 批量修改订单
+    #[api(SPUT "fapi/v1/batchOrders", dry_run = validate)]
+    pub async fn batch_modify_orders(
+        &self,
+        param: params::PBatchModifyOrder,
+    ) -> BianResult<Vec<response::BatchOrderResponse>> {
+    }
+
+    /// 查询订单修改历史
+    #[api(SGET "fapi/v1/orderAmendment")]
+    pub async fn order_amendment(
+        &self,
+        param: params::POrderAmendment,
+    ) -> BianResult<Vec<response::OrderAmendment>> {
+    }
+
     /// 批量撤销订单
-    #[api(SDELETE "fapi/v1/batchOrders")]
+    #[api(SDELETE "fapi/v1/batchOrders", dry_run)]
     pub async fn batch_cancel_orders(
         &self,
         param: params::PBatchCancelOrder,
@@ -312,7 +367,7 @@ impl UFuturesHttpClient {
 
     /// 倒计时撤销所有订单
     /// [DOC](https://binance-docs.github.io/apidocs/futures/cn/#trade-8)
-    #[api(SPOST "fapi/v1/countdownCancelAll")]
+    #[api(SPOST "fapi/v1/countdownCancelAll", dry_run)]
     pub async fn countdown_cancel_all(
         &self,
         param: params::PCountdownCancel,
@@ -407,6 +462,100 @@ impl UFuturesHttpClient {
         param: params::PUserTrade,
     ) -> BianResult<Vec<response::UserFuturesTrade>> {
     }
+
+    /// 获取账户损益资金流水
+    ///
+    /// - 不传时间时返回最近 7 天的数据
+    /// - 只能查询最近 3 个月的数据, 所有记录可以用 [`collect_page_numbers`](crate::pagination::collect_page_numbers) 翻页获取
+    #[api(SGET "fapi/v1/income")]
+    pub async fn income(&self, param: params::PIncome) -> BianResult<Vec<response::Income>> {}
+
+    /// 用户手续费率
+    #[api(SGET "fapi/v1/commissionRate")]
+    pub async fn commission_rate(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::CommissionRate> {
+    }
+
+    /// 持仓 ADL 队列估算, 不传交易对时返回所有持仓
+    #[api(SGET "fapi/v1/adlQuantile")]
+    pub async fn adl_quantile(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::AdlQuantile>> {
+    }
+
+    /// 杠杆分层标准(单个symbol)
+    #[api(SGET "fapi/v1/leverageBracket")]
+    pub async fn leverage_bracket(
+        &self,
+        param: params::PSymbolWithTs,
+    ) -> BianResult<response::LeverageBracket> {
+    }
+
+    /// 杠杆分层标准
+    #[api(SGET "fapi/v1/leverageBracket")]
+    pub async fn leverage_brackets(
+        &self,
+        param: params::PTimestamp,
+    ) -> BianResult<Vec<response::LeverageBracket>> {
+    }
+
+    /// 用户强平单历史
+    ///
+    /// - 不传时间时返回最近 7 天的数据
+    /// - 只能查询最近 3 个月的数据
+    #[api(SGET "fapi/v1/forceOrders")]
+    pub async fn force_orders(
+        &self,
+        param: params::PUserForceOrders,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+    }
+
+    /// 查询联合保证金模式
+    #[api(SGET "fapi/v1/multiAssetsMargin")]
+    pub async fn multi_assets_margin(
+        &self,
+        param: params::PTimestamp,
+    ) -> BianResult<response::MultiAssetsMargin> {
+    }
+
+    /// 更改联合保证金模式
+    ///
+    /// 变换用户在 所有symbol 合约上的保证金模式, 有持仓或挂单时不能更改
+    #[api(SPOST "fapi/v1/multiAssetsMargin")]
+    pub async fn set_multi_assets_margin(
+        &self,
+        param: params::PMultiAssetsMargin,
+    ) -> BianResult<response::CodeResponse> {
+    }
+
+    /// 合约 API 交易量化规则指标
+    #[api(SGET "fapi/v1/apiTradingStatus")]
+    pub async fn api_trading_status(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<response::ApiTradingStatus> {
+    }
+
+    /// 获取合约订单历史下载ID
+    ///
+    /// 每月最多请求 5 次, 生成下载链接后通过 [`order_download_link`](Self::order_download_link) 查询
+    #[api(SGET "fapi/v1/order/asyn")]
+    pub async fn order_download_id(
+        &self,
+        param: params::PAsyncDownload,
+    ) -> BianResult<response::AsyncDownloadId> {
+    }
+
+    /// 通过下载ID获取合约订单历史下载链接, 链接 24 小时内有效
+    #[api(SGET "fapi/v1/order/asyn/id")]
+    pub async fn order_download_link(
+        &self,
+        param: params::PDownloadId,
+    ) -> BianResult<response::AsyncDownloadLink> {
+    }
 }
 
 /// websocket 相关接口
@@ -776,13 +925,14 @@ impl UFuturesWSClient {
 
 /// 用户 data stream
 impl UFuturesWSClient {
+    /// 账户信息推送
+    ///
+    /// listenKey 需要每 30 分钟续期一次, 可以使用 [`UserDataStream`](crate::user_stream::UserDataStream)
+    /// 自动续期和重连
     pub fn user_data(
         &self,
         listen_key: &str,
     ) -> BianResult<impl WebsocketResponse<response::WSUserStream>> {
-        let url = self.base_url.join(listen_key).unwrap();
-        let (socket, _) = connect_with_config(url, None, 3, self.proxy)
-            .map_err(|e| APIError::WSConnectError(e.to_string()))?;
-        Ok(socket)
+        self.build_single(String::new(), listen_key)
     }
 }
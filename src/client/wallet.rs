@@ -0,0 +1,196 @@
+use crate::error::{APIError, BianResult};
+use crate::{params, response};
+use bian_proc::api;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+
+use super::{DryRun, SpotHttpClient};
+
+const BASE_HTTP_URL: &str = "https://api.binance.com";
+
+/// 钱包 http 客户端, 包括充值, 提币, 万向划转和小额资产转换
+///
+/// 可以通过 [`SpotHttpClient::wallet`] 复用现货客户端的密钥和连接池。
+/// 提币接口默认关闭, 需要调用 [`with_withdraw`](Self::with_withdraw) 开启。
+/// [doc](https://binance-docs.github.io/apidocs/spot/cn/#wallet-endpoints)
+#[derive(Debug, Clone)]
+pub struct WalletHttpClient {
+    http_client: reqwest::Client,
+    pub api_key: String,
+    pub secret_key: String,
+    pub base_url: url::Url,
+    /// dry run 配置, 开启后提币, 划转和小额资产转换只签名并记录请求, 余额和提币地址不做校验
+    pub dry_run: DryRun,
+    /// 是否允许提币, 默认关闭
+    pub withdraw_enabled: bool,
+}
+
+impl WalletHttpClient {
+    /// create client from default endpoint url
+    pub fn default_endpoint(api_key: String, secret_key: String) -> Self {
+        let base_url = url::Url::parse(BASE_HTTP_URL).unwrap();
+        let http_client = reqwest::Client::new();
+        Self {
+            base_url,
+            api_key,
+            secret_key,
+            http_client,
+            dry_run: DryRun::default(),
+            withdraw_enabled: false,
+        }
+    }
+
+    pub fn new(api_key: String, secret_key: String, base_url: &str) -> BianResult<Self> {
+        let http_client = reqwest::Client::new();
+        let base_url = url::Url::parse(base_url)
+            .map_err(|_| crate::error::APIError::InvalidUrl(base_url.to_string()))?;
+        Ok(Self {
+            http_client,
+            api_key,
+            secret_key,
+            base_url,
+            dry_run: DryRun::default(),
+            withdraw_enabled: false,
+        })
+    }
+
+    /// 开启或关闭提币接口
+    pub fn with_withdraw(mut self, enabled: bool) -> Self {
+        self.withdraw_enabled = enabled;
+        self
+    }
+
+    dry_run_methods!();
+
+    fn sign<P: serde::Serialize>(&self, params: &P) -> String {
+        self.sign_query(&serde_qs::to_string(&params).unwrap())
+    }
+
+    fn sign_query(&self, qs: &str) -> String {
+        let mut signed_key = Hmac::<Sha256>::new_varkey(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(qs.as_bytes());
+        hex::encode(signed_key.finalize().into_bytes())
+    }
+}
+
+impl SpotHttpClient {
+    /// 使用相同的密钥, 接口地址和连接池创建钱包客户端, 提币接口默认关闭
+    pub fn wallet(&self) -> WalletHttpClient {
+        WalletHttpClient {
+            http_client: self.http_client.clone(),
+            api_key: self.api_key.clone(),
+            secret_key: self.secret_key.clone(),
+            base_url: self.base_url.clone(),
+            dry_run: self.dry_run.clone(),
+            withdraw_enabled: false,
+        }
+    }
+}
+
+/// 充值和提币接口
+impl WalletHttpClient {
+    /// 所有币种信息, 包括支持的网络和提币手续费
+    #[api(SGET "sapi/v1/capital/config/getall")]
+    pub async fn coins(&self, param: params::PTimestamp) -> BianResult<Vec<response::CoinInfo>> {}
+
+    /// 获取充值地址
+    #[api(SGET "sapi/v1/capital/deposit/address")]
+    pub async fn deposit_address(
+        &self,
+        param: params::PDepositAddress,
+    ) -> BianResult<response::DepositAddress> {
+    }
+
+    /// 充值历史
+    #[api(SGET "sapi/v1/capital/deposit/hisrec")]
+    pub async fn deposit_history(
+        &self,
+        param: params::PDepositHistory,
+    ) -> BianResult<Vec<response::DepositRecord>> {
+    }
+
+    /// 提币
+    ///
+    /// 需要先通过 [`with_withdraw`](Self::with_withdraw) 开启, 否则返回 [`APIError::WithdrawDisabled`]
+    pub async fn withdraw(&self, param: params::PWithdraw) -> BianResult<response::WithdrawId> {
+        if !self.withdraw_enabled {
+            return Err(APIError::WithdrawDisabled);
+        }
+        self.withdraw_apply(param).await
+    }
+
+    #[api(SPOST "sapi/v1/capital/withdraw/apply", dry_run)]
+    async fn withdraw_apply(&self, param: params::PWithdraw) -> BianResult<response::WithdrawId> {}
+
+    /// 提币历史
+    #[api(SGET "sapi/v1/capital/withdraw/history")]
+    pub async fn withdraw_history(
+        &self,
+        param: params::PWithdrawHistory,
+    ) -> BianResult<Vec<response::WithdrawRecord>> {
+    }
+}
+
+/// 资产接口
+impl WalletHttpClient {
+    /// 万向划转, 在现货, 合约, 杠杆和资金账户之间划转
+    #[api(SPOST "sapi/v1/asset/transfer", dry_run)]
+    pub async fn transfer(
+        &self,
+        param: params::PUniversalTransfer,
+    ) -> BianResult<response::TransferResult> {
+    }
+
+    /// 万向划转历史
+    #[api(SGET "sapi/v1/asset/transfer")]
+    pub async fn transfer_history(
+        &self,
+        param: params::PTransferHistory,
+    ) -> BianResult<response::TransferHistory> {
+    }
+
+    /// 资产充提详情, 以资产名为键
+    #[api(SGET "sapi/v1/asset/assetDetail")]
+    pub async fn asset_detail(
+        &self,
+        param: params::PAssetDetail,
+    ) -> BianResult<std::collections::HashMap<String, response::AssetDetail>> {
+    }
+
+    /// 交易手续费率
+    #[api(SGET "sapi/v1/asset/tradeFee")]
+    pub async fn trade_fee(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::TradeFee>> {
+    }
+
+    /// 可以转换为 BNB 的小额资产
+    #[api(SPOST "sapi/v1/asset/dust-btc")]
+    pub async fn dust_assets(&self, param: params::PTimestamp) -> BianResult<response::DustAssets> {
+    }
+
+    /// 小额资产转换 BNB, 每 6 小时只能转换一次
+    pub async fn dust(&self, param: params::PDust) -> BianResult<response::DustResult> {
+        let qs = param.to_query();
+        let url = self.base_url.join("sapi/v1/asset/dust").unwrap();
+        let url = format!("{}?{}&signature={}", url, qs, self.sign_query(&qs));
+        if self.dry_run.enabled {
+            let req = format!("POST {}", url);
+            log::info!("dry run {}", &req);
+            return Err(APIError::DryRun(req));
+        }
+        log::debug!("req {}", &url);
+        let resp = self
+            .http_client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .header("X-MBX-APIKEY", &self.api_key)
+            .send()
+            .await?;
+        let resp = APIError::check_resp(resp).await?;
+        let resp_text = resp.text().await.unwrap();
+        log::debug!("resp {}", &resp_text);
+        serde_json::from_str(&resp_text).map_err(|e| APIError::DecodeError(e.to_string()))
+    }
+}
@@ -0,0 +1,247 @@
+//! 基于倒计时撤单的 dead-man switch
+//!
+//! 合约的 `countdownCancelAll` 接口在倒计时结束时撤销交易对的所有挂单。[`DeadManSwitch`] 在后台
+//! 定时重置倒计时, 只要策略进程正常运行, 挂单就不会被撤销; 一旦进程卡死, 退出或 drop 了
+//! [`DeadManSwitch`], 倒计时不再被重置, 交易所会在倒计时结束后自动撤销所有挂单。
+//!
+//! 策略可以通过 [`HealthHandle::trip`] 主动停止重置, 或者调用 [`HealthHandle::require_heartbeat`]
+//! 要求策略定时调用 [`HealthHandle::beat`], 超时未调用时视为策略卡死。
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use bian_rs::client::UFuturesHttpClient;
+//! use bian_rs::dead_man::DeadManSwitch;
+//!
+//! # async fn run(client: UFuturesHttpClient) {
+//! let switch = DeadManSwitch::start(client, vec!["BTCUSDT".to_string()], Duration::from_secs(60));
+//! let health = switch.health();
+//! health.require_heartbeat(Duration::from_secs(10));
+//! loop {
+//!     // 策略主循环
+//!     health.beat();
+//! #   break;
+//! }
+//! // 正常退出时取消倒计时, 保留挂单
+//! switch.disarm().await.unwrap();
+//! # }
+//! ```
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+
+use crate::client::{DFuturesHttpClient, UFuturesHttpClient};
+use crate::error::BianResult;
+use crate::paper::PaperExchange;
+use crate::params::{PCountdownCancel, PTimestamp};
+
+/// 倒计时撤单接口
+#[async_trait]
+pub trait CountdownCancelClient: Clone + Send + Sync + 'static {
+    /// 设置交易对的倒计时, `countdown` 为 0 时取消倒计时
+    async fn set_countdown(&self, symbol: &str, countdown: Duration) -> BianResult<()>;
+}
+
+/// 停止重置倒计时的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TripReason {
+    /// 策略主动调用 [`HealthHandle::trip`]
+    Manual,
+    /// 超时未收到策略心跳
+    Stalled,
+}
+
+#[derive(Debug)]
+struct Health {
+    tripped: Option<TripReason>,
+    heartbeat_timeout: Option<Duration>,
+    last_beat: Instant,
+}
+
+/// 策略健康状态, clone 后共享同一份状态
+#[derive(Debug, Clone)]
+pub struct HealthHandle {
+    inner: Arc<Mutex<Health>>,
+}
+
+impl HealthHandle {
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Health {
+                tripped: None,
+                heartbeat_timeout: None,
+                last_beat: Instant::now(),
+            })),
+        }
+    }
+
+    /// 策略心跳
+    pub fn beat(&self) {
+        self.inner.lock().unwrap().last_beat = Instant::now();
+    }
+
+    /// 要求策略在 `timeout` 内调用 [`beat`](Self::beat), 否则停止重置倒计时
+    pub fn require_heartbeat(&self, timeout: Duration) {
+        let mut health = self.inner.lock().unwrap();
+        health.heartbeat_timeout = Some(timeout);
+        health.last_beat = Instant::now();
+    }
+
+    /// 停止重置倒计时, 倒计时结束后交易所撤销所有挂单
+    pub fn trip(&self) {
+        self.inner
+            .lock()
+            .unwrap()
+            .tripped
+            .get_or_insert(TripReason::Manual);
+    }
+
+    /// 停止重置倒计时的原因, 仍在运行时返回 `None`
+    pub fn tripped(&self) -> Option<TripReason> {
+        self.inner.lock().unwrap().tripped
+    }
+
+    /// 检查是否可以继续重置倒计时
+    fn check(&self) -> bool {
+        let mut health = self.inner.lock().unwrap();
+        if health.tripped.is_none()
+            && matches!(
+                health.heartbeat_timeout,
+                Some(timeout) if health.last_beat.elapsed() > timeout
+            )
+        {
+            health.tripped = Some(TripReason::Stalled);
+        }
+        health.tripped.is_none()
+    }
+}
+
+/// 定时重置倒计时撤单, 需要在 tokio runtime 中使用
+///
+/// drop 时停止重置, 倒计时结束后交易所撤销所有挂单。
+pub struct DeadManSwitch<C: CountdownCancelClient> {
+    client: C,
+    symbols: Vec<String>,
+    health: HealthHandle,
+    task: JoinHandle<()>,
+}
+
+impl<C: CountdownCancelClient> DeadManSwitch<C> {
+    /// 启动, 每隔 `countdown` 的三分之一重置一次倒计时
+    pub fn start(client: C, symbols: Vec<String>, countdown: Duration) -> Self {
+        Self::with_interval(client, symbols, countdown, countdown / 3)
+    }
+
+    /// 使用自定义重置间隔启动, `interval` 应小于 `countdown`
+    pub fn with_interval(
+        client: C,
+        symbols: Vec<String>,
+        countdown: Duration,
+        interval: Duration,
+    ) -> Self {
+        let health = HealthHandle::new();
+        let task = tokio::spawn(drive(
+            client.clone(),
+            symbols.clone(),
+            countdown,
+            interval,
+            health.clone(),
+        ));
+        Self {
+            client,
+            symbols,
+            health,
+            task,
+        }
+    }
+
+    /// 健康状态, 可以交给策略的其他任务使用
+    pub fn health(&self) -> HealthHandle {
+        self.health.clone()
+    }
+
+    /// 停止重置倒计时, 见 [`HealthHandle::trip`]
+    pub fn trip(&self) {
+        self.health.trip()
+    }
+
+    /// 停止重置倒计时的原因, 仍在运行时返回 `None`
+    pub fn tripped(&self) -> Option<TripReason> {
+        self.health.tripped()
+    }
+
+    /// 停止重置并取消所有交易对的倒计时, 挂单不会被撤销, 用于策略正常退出
+    pub async fn disarm(self) -> BianResult<()> {
+        self.task.abort();
+        for symbol in self.symbols.iter() {
+            self.client
+                .set_countdown(symbol, Duration::from_secs(0))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: CountdownCancelClient> Drop for DeadManSwitch<C> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn drive<C: CountdownCancelClient>(
+    client: C,
+    symbols: Vec<String>,
+    countdown: Duration,
+    interval: Duration,
+    health: HealthHandle,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if !health.check() {
+            log::warn!("dead-man switch tripped: {:?}", health.tripped());
+            return;
+        }
+        for symbol in symbols.iter() {
+            if let Err(e) = client.set_countdown(symbol, countdown).await {
+                log::warn!("reset countdown of {} failed: {}", symbol, e);
+            }
+        }
+    }
+}
+
+fn countdown_param(symbol: &str, countdown: Duration) -> PCountdownCancel {
+    PCountdownCancel {
+        symbol: symbol.to_string(),
+        countdown_time: countdown.as_millis() as i64,
+        ts: PTimestamp::now(),
+    }
+}
+
+#[async_trait]
+impl CountdownCancelClient for UFuturesHttpClient {
+    async fn set_countdown(&self, symbol: &str, countdown: Duration) -> BianResult<()> {
+        self.countdown_cancel_all(countdown_param(symbol, countdown))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CountdownCancelClient for DFuturesHttpClient {
+    async fn set_countdown(&self, symbol: &str, countdown: Duration) -> BianResult<()> {
+        self.countdown_cancel_all(countdown_param(symbol, countdown))
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CountdownCancelClient for PaperExchange {
+    async fn set_countdown(&self, symbol: &str, countdown: Duration) -> BianResult<()> {
+        self.countdown_cancel_all(countdown_param(symbol, countdown))
+            .await?;
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
+use std::convert::TryFrom;
+
 use serde::{Deserialize, Serialize};
 
 /// 合约类型
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     /// 永续合约
@@ -10,6 +12,14 @@ pub enum ContractType {
     CurrentMonth,
     /// 次月交割合约
     NextMonth,
+    /// 当季交割合约, 币本位合约
+    CurrentQuarter,
+    /// 次季交割合约, 币本位合约
+    NextQuarter,
+    /// 交割中的无效类型
+    PerpetualDelivering,
+    /// 交割中的无效类型
+    CurrentQuarterDelivering,
     /// 交割中的无效类型
     CurrentMonthDelivering,
     /// 交割中的无效类型
@@ -26,7 +36,7 @@ impl ToString for ContractType {
 }
 
 /// 合约状态
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractStatus {
     ///  待上市
@@ -48,7 +58,7 @@ pub enum ContractStatus {
 }
 
 /// 订单状态
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     /// 新建订单
@@ -63,10 +73,20 @@ pub enum OrderStatus {
     Rejected,
     /// 订单过期(根据timeInForce参数规则)
     Expired,
+    /// 风险保障基金(强平), 仅合约用户数据推送
+    NewInsurance,
+    /// 自动减仓序列(强平), 仅合约用户数据推送
+    NewAdl,
+    /// 撤销中, 现货目前不会出现此状态
+    PendingCancel,
+    /// 因防止自成交被撤销, 仅现货
+    ExpiredInMatch,
+    /// 等待订单列表中的生效订单成交, 仅现货 OTO 和 OTOCO 订单
+    PendingNew,
 }
 
 /// 期货订单种类
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum FuturesOrderType {
     Limit,
@@ -79,7 +99,7 @@ pub enum FuturesOrderType {
 }
 
 /// 现货订单种类
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SpotOrderType {
     ///限价单
@@ -98,14 +118,14 @@ pub enum SpotOrderType {
     LimitMaker,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderSide {
     Buy,
     Sell,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PositionDirect {
     Both,
@@ -113,36 +133,78 @@ pub enum PositionDirect {
     Short,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TimeInForce {
     GTC,
     IOC,
     FOK,
     GTX,
+    /// 指定时间前有效, 仅合约支持
+    GTD,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkingType {
     MarkPrice,
     ContractPrice,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl WorkingType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkingType::MarkPrice => "MARK_PRICE",
+            WorkingType::ContractPrice => "CONTRACT_PRICE",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MarginType {
+    #[serde(alias = "isolated")]
     Isolated,
+    #[serde(alias = "cross", alias = "CROSS")]
     Crossed,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum NewOrderType {
     Ack,
     Result,
+    /// 仅现货支持
+    Full,
+}
+
+impl NewOrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewOrderType::Ack => "ACK",
+            NewOrderType::Result => "RESULT",
+            NewOrderType::Full => "FULL",
+        }
+    }
+}
+
+/// 合约下单返回类型, 合约不支持 `FULL`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FuturesNewOrderType {
+    Ack,
+    Result,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl FuturesNewOrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FuturesNewOrderType::Ack => "ACK",
+            FuturesNewOrderType::Result => "RESULT",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Interval {
     #[serde(rename = "1m")]
     Min1,
@@ -191,7 +253,7 @@ pub enum RateLimitType {
 }
 
 /// OCO 状态 (状态类型集)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OcoStatus {
     /// 当ListStatus响应失败的操作时使用。 (订单完成或取消订单)
@@ -203,7 +265,7 @@ pub enum OcoStatus {
 }
 
 /// OCO 订单状态 (订单状态集)
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OcoOrderStatus {
     /// 当已经下单或者订单有更新时
@@ -211,9 +273,462 @@ pub enum OcoOrderStatus {
     /// 当订单执行结束或者不在激活状态
     AllDone,
     /// 当订单状态响应失败(订单完成或取消订单)
+    #[serde(rename = "REJECT")]
     REJECT,
 }
 
+/// 撤消挂单再下单模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceMode {
+    /// 撤单失败时不再下单
+    StopOnFailure,
+    /// 撤单失败时仍然下单
+    AllowFailure,
+}
+
+/// 撤消挂单再下单中撤单或下单的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    Success,
+    Failure,
+    /// 撤单失败且模式为 `STOP_ON_FAILURE` 时不会尝试下单
+    NotAttempted,
+}
+
+/// 期权类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionSide {
+    /// 看涨期权
+    Call,
+    /// 看跌期权
+    Put,
+}
+
+/// 期权订单状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionsOrderStatus {
+    /// 已接受, 未成交
+    Accepted,
+    Rejected,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+}
+
+/// 杠杆借贷类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginLoanType {
+    /// 借款
+    Borrow,
+    /// 还款
+    Repay,
+}
+
+/// 杠杆下单时的自动借还款方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SideEffectType {
+    /// 普通订单, 默认值
+    NoSideEffect,
+    /// 自动借款
+    MarginBuy,
+    /// 成交后自动还款
+    AutoRepay,
+    /// 自动借款, 成交后自动还款
+    AutoBorrowRepay,
+}
+
+/// 万向划转类型, 格式为 `转出账户_转入账户`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UniversalTransferType {
+    /// 现货钱包转向U本位合约钱包
+    #[serde(rename = "MAIN_UMFUTURE")]
+    MainUmFuture,
+    /// 现货钱包转向币本位合约钱包
+    #[serde(rename = "MAIN_CMFUTURE")]
+    MainCmFuture,
+    /// 现货钱包转向杠杆全仓钱包
+    #[serde(rename = "MAIN_MARGIN")]
+    MainMargin,
+    /// 现货钱包转向资金钱包
+    #[serde(rename = "MAIN_FUNDING")]
+    MainFunding,
+    /// 现货钱包转向期权钱包
+    #[serde(rename = "MAIN_OPTION")]
+    MainOption,
+    /// U本位合约钱包转向现货钱包
+    #[serde(rename = "UMFUTURE_MAIN")]
+    UmFutureMain,
+    /// U本位合约钱包转向杠杆全仓钱包
+    #[serde(rename = "UMFUTURE_MARGIN")]
+    UmFutureMargin,
+    /// U本位合约钱包转向资金钱包
+    #[serde(rename = "UMFUTURE_FUNDING")]
+    UmFutureFunding,
+    /// 币本位合约钱包转向现货钱包
+    #[serde(rename = "CMFUTURE_MAIN")]
+    CmFutureMain,
+    /// 币本位合约钱包转向杠杆全仓钱包
+    #[serde(rename = "CMFUTURE_MARGIN")]
+    CmFutureMargin,
+    /// 币本位合约钱包转向资金钱包
+    #[serde(rename = "CMFUTURE_FUNDING")]
+    CmFutureFunding,
+    /// 杠杆全仓钱包转向现货钱包
+    #[serde(rename = "MARGIN_MAIN")]
+    MarginMain,
+    /// 杠杆全仓钱包转向U本位合约钱包
+    #[serde(rename = "MARGIN_UMFUTURE")]
+    MarginUmFuture,
+    /// 杠杆全仓钱包转向币本位合约钱包
+    #[serde(rename = "MARGIN_CMFUTURE")]
+    MarginCmFuture,
+    /// 杠杆全仓钱包转向杠杆逐仓钱包
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginIsolatedMargin,
+    /// 杠杆全仓钱包转向资金钱包
+    #[serde(rename = "MARGIN_FUNDING")]
+    MarginFunding,
+    /// 杠杆逐仓钱包转向杠杆全仓钱包
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginMargin,
+    /// 杠杆逐仓钱包转向杠杆逐仓钱包
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
+    IsolatedMarginIsolatedMargin,
+    /// 资金钱包转向现货钱包
+    #[serde(rename = "FUNDING_MAIN")]
+    FundingMain,
+    /// 资金钱包转向U本位合约钱包
+    #[serde(rename = "FUNDING_UMFUTURE")]
+    FundingUmFuture,
+    /// 资金钱包转向币本位合约钱包
+    #[serde(rename = "FUNDING_CMFUTURE")]
+    FundingCmFuture,
+    /// 资金钱包转向杠杆全仓钱包
+    #[serde(rename = "FUNDING_MARGIN")]
+    FundingMargin,
+    /// 期权钱包转向现货钱包
+    #[serde(rename = "OPTION_MAIN")]
+    OptionMain,
+}
+
+/// 划转状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransferStatus {
+    Pending,
+    Confirmed,
+    Failed,
+}
+
+/// 充值状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum DepositStatus {
+    /// 0: 处理中
+    Pending,
+    /// 1: 成功
+    Success,
+    /// 2: 已驳回
+    Rejected,
+    /// 6: 已上账但暂不可提现
+    Credited,
+    /// 7: 错误充值
+    WrongDeposit,
+    /// 8: 待用户确认
+    WaitingConfirm,
+}
+
+impl TryFrom<u8> for DepositStatus {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(DepositStatus::Pending),
+            1 => Ok(DepositStatus::Success),
+            2 => Ok(DepositStatus::Rejected),
+            6 => Ok(DepositStatus::Credited),
+            7 => Ok(DepositStatus::WrongDeposit),
+            8 => Ok(DepositStatus::WaitingConfirm),
+            other => Err(format!("unknown deposit status {}", other)),
+        }
+    }
+}
+
+impl From<DepositStatus> for u8 {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Pending => 0,
+            DepositStatus::Success => 1,
+            DepositStatus::Rejected => 2,
+            DepositStatus::Credited => 6,
+            DepositStatus::WrongDeposit => 7,
+            DepositStatus::WaitingConfirm => 8,
+        }
+    }
+}
+
+/// 提现状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum WithdrawStatus {
+    /// 0: 已发送确认邮件
+    EmailSent,
+    /// 1: 已取消
+    Cancelled,
+    /// 2: 等待确认
+    AwaitingApproval,
+    /// 3: 被拒绝
+    Rejected,
+    /// 4: 处理中
+    Processing,
+    /// 5: 提现交易失败
+    Failure,
+    /// 6: 提现完成
+    Completed,
+}
+
+impl TryFrom<u8> for WithdrawStatus {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WithdrawStatus::EmailSent),
+            1 => Ok(WithdrawStatus::Cancelled),
+            2 => Ok(WithdrawStatus::AwaitingApproval),
+            3 => Ok(WithdrawStatus::Rejected),
+            4 => Ok(WithdrawStatus::Processing),
+            5 => Ok(WithdrawStatus::Failure),
+            6 => Ok(WithdrawStatus::Completed),
+            other => Err(format!("unknown withdraw status {}", other)),
+        }
+    }
+}
+
+impl From<WithdrawStatus> for u8 {
+    fn from(status: WithdrawStatus) -> Self {
+        status as u8
+    }
+}
+
+/// 子账户万向划转的账户类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubAccountType {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+/// 子账户合约类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum SubFuturesType {
+    /// 1: U本位合约
+    UsdM,
+    /// 2: 币本位合约
+    CoinM,
+}
+
+impl TryFrom<u8> for SubFuturesType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(SubFuturesType::UsdM),
+            2 => Ok(SubFuturesType::CoinM),
+            other => Err(format!("unknown futures type {}", other)),
+        }
+    }
+}
+
+impl From<SubFuturesType> for u8 {
+    fn from(futures_type: SubFuturesType) -> Self {
+        match futures_type {
+            SubFuturesType::UsdM => 1,
+            SubFuturesType::CoinM => 2,
+        }
+    }
+}
+
+/// 子账户 API Key 的 IP 限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IpRestriction {
+    /// 不限制 IP, 有安全风险
+    #[serde(rename = "1")]
+    Unrestricted,
+    /// 只允许白名单 IP 访问
+    #[serde(rename = "2")]
+    Restricted,
+}
+
+/// 理财申购或赎回使用的账户
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnAccount {
+    /// 现货账户
+    Spot,
+    /// 资金账户
+    Fund,
+    /// 优先使用现货账户, 不足时使用资金账户, 仅申购时有效
+    All,
+}
+
+/// 活期理财收益类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnRewardType {
+    /// 额外奖励
+    Bonus,
+    /// 实时 APR 收益
+    Realtime,
+    /// 历史收益
+    Rewards,
+}
+
+/// 闪兑扣款账户
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertWallet {
+    /// 现货账户
+    Spot,
+    /// 资金账户
+    Funding,
+    /// 优先使用现货账户, 不足时使用资金账户
+    SpotFunding,
+}
+
+/// 闪兑报价有效时间
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QuoteValidTime {
+    #[serde(rename = "10s")]
+    TenSeconds,
+    #[serde(rename = "30s")]
+    ThirtySeconds,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "2m")]
+    TwoMinutes,
+}
+
+/// 闪兑订单状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertStatus {
+    /// 处理中
+    Process,
+    /// 已接受报价, 等待成交
+    AcceptSuccess,
+    Success,
+    Fail,
+}
+
+/// 统一账户状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioMarginStatus {
+    Normal,
+    /// 追加保证金提醒
+    MarginCall,
+    /// 需要补充保证金
+    SupplyMargin,
+    /// 只能减仓
+    ReduceOnly,
+    /// 主动强平中
+    ActiveLiquidation,
+    /// 强制平仓中
+    ForceLiquidation,
+    /// 已穿仓
+    Bankrupted,
+}
+
+/// VP 算法单执行紧急程度, 越紧急执行越快, 对市场冲击越大
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlgoUrgency {
+    Low,
+    Medium,
+    High,
+}
+
+/// 算法单类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlgoType {
+    /// 成交量参与 (Volume Participation)
+    Vp,
+    /// 时间加权平均价格
+    Twap,
+}
+
+/// 算法单状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlgoStatus {
+    Working,
+    Finished,
+    Cancelled,
+}
+
+/// 合约资金流水类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    /// 划转
+    Transfer,
+    /// 赠金
+    WelcomeBonus,
+    /// 已实现盈亏
+    RealizedPnl,
+    /// 资金费用
+    FundingFee,
+    /// 手续费
+    Commission,
+    /// 强平保险基金
+    InsuranceClear,
+    /// 推荐人返佣
+    ReferralKickback,
+    /// 被推荐人返佣
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    /// 交割结算, 接口拼写如此
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    StrategyUmfuturesTransfer,
+    FeeReturn,
+    BfusdReward,
+    /// 尚未支持的类型, 不能用于查询
+    #[serde(other)]
+    Other,
+}
+
+/// 用户强平订单类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoCloseType {
+    /// 强平
+    Liquidation,
+    /// 自动减仓
+    Adl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Method {
     GET,
     POST,
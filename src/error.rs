@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::rules::RuleViolation;
+
 #[derive(Debug, Error)]
 pub enum APIError {
     #[error("4xx client side error {0}")]
@@ -20,8 +22,18 @@ pub enum APIError {
     WSClientError(String),
     #[error("ws connect error {0}")]
     WSConnectError(String),
+    #[error("order rejected by symbol filter: {0}")]
+    FilterFailure(RuleViolation),
     #[error("invalid url {0}")]
     InvalidUrl(String),
+    #[error("dry run, request not sent: {0}")]
+    DryRun(String),
+    #[error("withdraw is disabled, enable it with `with_withdraw(true)`")]
+    WithdrawDisabled,
+    #[error("quote {0} expired before accepted")]
+    QuoteExpired(String),
+    #[error("no market data for {0}")]
+    NoMarketData(String),
     #[error("unknown {0}")]
     Unknown(String),
 }
@@ -32,6 +44,12 @@ impl From<reqwest::Error> for APIError {
     }
 }
 
+impl From<RuleViolation> for APIError {
+    fn from(err: RuleViolation) -> Self {
+        APIError::FilterFailure(err)
+    }
+}
+
 impl APIError {
     pub async fn check_resp(resp: reqwest::Response) -> Result<reqwest::Response, Self> {
         let status_code = u16::from(resp.status());
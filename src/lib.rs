@@ -1,5 +1,15 @@
+pub mod account;
 pub mod client;
+pub mod dead_man;
 pub mod enums;
 pub mod error;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod pagination;
+pub mod paper;
 pub mod params;
 pub mod response;
+pub mod rules;
+pub mod tracker;
+pub mod trading;
+pub mod user_stream;
@@ -0,0 +1,682 @@
+//! 默认 fixture, 数据录制自币安接口文档示例
+
+use crate::enums::Method::{self, *};
+
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!(concat!("fixtures/", $path))
+    };
+}
+
+/// (方法, 路径, 返回数据)
+pub(super) const HTTP_FIXTURES: &[(Method, &str, &str)] = &[
+    // U本位合约
+    (GET, "fapi/v1/ping", fixture!("ufutures/empty.json")),
+    (GET, "fapi/v1/time", fixture!("ufutures/time.json")),
+    (GET, "fapi/v1/exchangeInfo", fixture!("ufutures/exchange_info.json")),
+    (GET, "fapi/v1/depth", fixture!("ufutures/depth.json")),
+    (GET, "fapi/v1/trades", fixture!("ufutures/trades.json")),
+    (GET, "fapi/v1/klines", fixture!("ufutures/klines.json")),
+    (GET, "fapi/v1/premiumIndex", fixture!("ufutures/premium_index.json")),
+    (
+        GET,
+        "fapi/v1/premiumIndex?symbol=BTCUSDT",
+        fixture!("ufutures/premium_index_btcusdt.json"),
+    ),
+    (GET, "fapi/v1/ticker/price", fixture!("ufutures/price.json")),
+    (
+        GET,
+        "fapi/v1/ticker/price?symbol=BTCUSDT",
+        fixture!("ufutures/price_btcusdt.json"),
+    ),
+    (GET, "fapi/v1/ticker/bookTicker", fixture!("ufutures/book_ticker.json")),
+    (
+        GET,
+        "fapi/v1/ticker/bookTicker?symbol=BTCUSDT",
+        fixture!("ufutures/book_ticker_btcusdt.json"),
+    ),
+    (SPOST, "fapi/v1/order", fixture!("ufutures/order.json")),
+    (SPOST, "fapi/v1/order/test", fixture!("ufutures/empty.json")),
+    (SGET, "fapi/v1/order", fixture!("ufutures/query_order.json")),
+    (SDELETE, "fapi/v1/order", fixture!("ufutures/cancel_order.json")),
+    (SPUT, "fapi/v1/order", fixture!("ufutures/modify_order.json")),
+    (
+        SPUT,
+        "fapi/v1/batchOrders",
+        fixture!("ufutures/batch_modify_orders.json"),
+    ),
+    (
+        SGET,
+        "fapi/v1/orderAmendment",
+        fixture!("ufutures/order_amendment.json"),
+    ),
+    (SGET, "fapi/v1/openOrders", fixture!("ufutures/open_orders.json")),
+    (
+        SPOST,
+        "fapi/v1/countdownCancelAll",
+        fixture!("ufutures/countdown_cancel_all.json"),
+    ),
+    (SGET, "fapi/v2/balance", fixture!("ufutures/balance.json")),
+    (SGET, "fapi/v2/account", fixture!("ufutures/account.json")),
+    (SGET, "fapi/v2/positionRisk", fixture!("ufutures/position_risk.json")),
+    (SGET, "fapi/v1/userTrades", fixture!("ufutures/user_trades.json")),
+    (GET, "fapi/v2/ticker/price", fixture!("ufutures/price_v2.json")),
+    (
+        GET,
+        "fapi/v2/ticker/price?symbol=BTCUSDT",
+        fixture!("ufutures/price_v2_btcusdt.json"),
+    ),
+    (SGET, "fapi/v1/income", fixture!("ufutures/income.json")),
+    (
+        SGET,
+        "fapi/v1/commissionRate",
+        fixture!("ufutures/commission_rate.json"),
+    ),
+    (SGET, "fapi/v1/adlQuantile", fixture!("ufutures/adl_quantile.json")),
+    (
+        SGET,
+        "fapi/v1/leverageBracket",
+        fixture!("ufutures/leverage_bracket.json"),
+    ),
+    (
+        SGET,
+        "fapi/v1/leverageBracket?symbol=BTCUSDT",
+        fixture!("ufutures/leverage_bracket_btcusdt.json"),
+    ),
+    (SGET, "fapi/v1/forceOrders", fixture!("ufutures/force_orders.json")),
+    (
+        SGET,
+        "fapi/v1/multiAssetsMargin",
+        fixture!("ufutures/multi_assets_margin.json"),
+    ),
+    (SPOST, "fapi/v1/multiAssetsMargin", fixture!("ufutures/code.json")),
+    (
+        SGET,
+        "fapi/v1/apiTradingStatus",
+        fixture!("ufutures/api_trading_status.json"),
+    ),
+    (
+        SGET,
+        "fapi/v1/order/asyn",
+        fixture!("ufutures/order_download_id.json"),
+    ),
+    (
+        SGET,
+        "fapi/v1/order/asyn/id",
+        fixture!("ufutures/order_download_link.json"),
+    ),
+    (SPOST, "fapi/v1/listenKey", fixture!("ufutures/listen_key.json")),
+    (SPUT, "fapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    (SDELETE, "fapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    // 币本位合约
+    (GET, "dapi/v1/ping", fixture!("ufutures/empty.json")),
+    (GET, "dapi/v1/time", fixture!("dfutures/time.json")),
+    (GET, "dapi/v1/exchangeInfo", fixture!("dfutures/exchange_info.json")),
+    (GET, "dapi/v1/depth", fixture!("dfutures/depth.json")),
+    (GET, "dapi/v1/premiumIndex", fixture!("dfutures/premium_index.json")),
+    (GET, "dapi/v1/ticker/price", fixture!("dfutures/price.json")),
+    (GET, "dapi/v1/ticker/bookTicker", fixture!("dfutures/book_ticker.json")),
+    (SPOST, "dapi/v1/order", fixture!("dfutures/order.json")),
+    (SGET, "dapi/v1/order", fixture!("dfutures/order.json")),
+    (SDELETE, "dapi/v1/order", fixture!("dfutures/cancel_order.json")),
+    (SPUT, "dapi/v1/order", fixture!("dfutures/order.json")),
+    (SGET, "dapi/v1/openOrders", fixture!("dfutures/open_orders.json")),
+    (SGET, "dapi/v1/balance", fixture!("dfutures/balance.json")),
+    (SGET, "dapi/v2/positionRisk", fixture!("dfutures/position_risk.json")),
+    (SGET, "dapi/v1/userTrades", fixture!("dfutures/user_trades.json")),
+    (
+        GET,
+        "dapi/v1/markPriceKlines",
+        fixture!("dfutures/mark_price_klines.json"),
+    ),
+    (
+        GET,
+        "dapi/v1/premiumIndexKlines",
+        fixture!("dfutures/premium_index_klines.json"),
+    ),
+    (GET, "futures/data/basis", fixture!("dfutures/basis.json")),
+    (SGET, "dapi/v1/income", fixture!("dfutures/income.json")),
+    (
+        SGET,
+        "dapi/v1/leverageBracket",
+        fixture!("dfutures/leverage_bracket.json"),
+    ),
+    (
+        SGET,
+        "dapi/v1/commissionRate",
+        fixture!("dfutures/commission_rate.json"),
+    ),
+    (SGET, "dapi/v1/adlQuantile", fixture!("dfutures/adl_quantile.json")),
+    (SPOST, "dapi/v1/listenKey", fixture!("ufutures/listen_key.json")),
+    (SPUT, "dapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    (SDELETE, "dapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    // 现货
+    (GET, "api/v3/ping", fixture!("ufutures/empty.json")),
+    (GET, "api/v3/time", fixture!("spot/time.json")),
+    (GET, "api/v3/exchangeInfo", fixture!("spot/exchange_info.json")),
+    (GET, "api/v3/depth", fixture!("spot/depth.json")),
+    (GET, "api/v3/trades", fixture!("spot/trades.json")),
+    (GET, "api/v3/klines", fixture!("spot/klines.json")),
+    (GET, "api/v3/avgPrice", fixture!("spot/avg_price.json")),
+    (GET, "api/v3/ticker/price", fixture!("spot/price.json")),
+    (
+        GET,
+        "api/v3/ticker/price?symbol=ADAUSDT",
+        fixture!("spot/price_adausdt.json"),
+    ),
+    (GET, "api/v3/ticker/bookTicker", fixture!("spot/book_ticker.json")),
+    (
+        GET,
+        "api/v3/ticker/bookTicker?symbol=ADAUSDT",
+        fixture!("spot/book_ticker_adausdt.json"),
+    ),
+    (SPOST, "api/v3/order", fixture!("spot/order.json")),
+    (SPOST, "api/v3/order/test", fixture!("ufutures/empty.json")),
+    (SPOST, "api/v3/sor/order", fixture!("spot/sor_order.json")),
+    (SPOST, "api/v3/sor/order/test", fixture!("ufutures/empty.json")),
+    (SPOST, "api/v3/orderList/oco", fixture!("spot/order_list_oco.json")),
+    (SPOST, "api/v3/orderList/oto", fixture!("spot/order_list_oto.json")),
+    (SPOST, "api/v3/orderList/otoco", fixture!("spot/order_list_otoco.json")),
+    (SGET, "api/v3/order", fixture!("spot/query_order.json")),
+    (SDELETE, "api/v3/order", fixture!("spot/cancel_order.json")),
+    (
+        SPOST,
+        "api/v3/order/cancelReplace",
+        fixture!("spot/cancel_replace.json"),
+    ),
+    (SGET, "api/v3/openOrders", fixture!("spot/open_orders.json")),
+    (SGET, "api/v3/account", fixture!("spot/account.json")),
+    (SGET, "api/v3/myTrades", fixture!("spot/my_trades.json")),
+    (POST, "api/v3/userDataStream", fixture!("spot/listen_key.json")),
+    (PUT, "api/v3/userDataStream", fixture!("ufutures/empty.json")),
+    (DELETE, "api/v3/userDataStream", fixture!("ufutures/empty.json")),
+    // 欧式期权
+    (GET, "eapi/v1/ping", fixture!("ufutures/empty.json")),
+    (GET, "eapi/v1/time", fixture!("options/time.json")),
+    (GET, "eapi/v1/exchangeInfo", fixture!("options/exchange_info.json")),
+    (GET, "eapi/v1/depth", fixture!("options/depth.json")),
+    (GET, "eapi/v1/klines", fixture!("options/klines.json")),
+    (GET, "eapi/v1/mark", fixture!("options/mark.json")),
+    (GET, "eapi/v1/index", fixture!("options/index.json")),
+    (SGET, "eapi/v1/account", fixture!("options/account.json")),
+    (SGET, "eapi/v1/position", fixture!("options/position.json")),
+    (SPOST, "eapi/v1/order", fixture!("options/order.json")),
+    (SGET, "eapi/v1/order", fixture!("options/query_order.json")),
+    (SDELETE, "eapi/v1/order", fixture!("options/cancel_order.json")),
+    (SPOST, "eapi/v1/batchOrders", fixture!("options/batch_orders.json")),
+    (
+        SDELETE,
+        "eapi/v1/allOpenOrders",
+        fixture!("options/cancel_all_orders.json"),
+    ),
+    (SGET, "eapi/v1/openOrders", fixture!("options/open_orders.json")),
+    (SPOST, "eapi/v1/listenKey", fixture!("options/listen_key.json")),
+    (SPUT, "eapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    (SDELETE, "eapi/v1/listenKey", fixture!("ufutures/empty.json")),
+    (
+        SPOST,
+        "sapi/v1/margin/borrow-repay",
+        fixture!("margin/borrow_repay.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/margin/maxBorrowable",
+        fixture!("margin/max_borrowable.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/margin/maxTransferable",
+        fixture!("margin/max_transferable.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/margin/interestHistory",
+        fixture!("margin/interest_history.json"),
+    ),
+    (SGET, "sapi/v1/margin/account", fixture!("margin/account.json")),
+    (
+        SGET,
+        "sapi/v1/margin/isolated/account",
+        fixture!("margin/isolated_account.json"),
+    ),
+    (SPOST, "sapi/v1/margin/order", fixture!("margin/order.json")),
+    (SGET, "sapi/v1/margin/order", fixture!("margin/query_order.json")),
+    (
+        SDELETE,
+        "sapi/v1/margin/order",
+        fixture!("margin/cancel_order.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/margin/openOrders",
+        fixture!("margin/open_orders.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/margin/allOrders",
+        fixture!("margin/all_orders.json"),
+    ),
+    (POST, "sapi/v1/userDataStream", fixture!("margin/listen_key.json")),
+    (PUT, "sapi/v1/userDataStream", fixture!("ufutures/empty.json")),
+    (DELETE, "sapi/v1/userDataStream", fixture!("ufutures/empty.json")),
+    (
+        POST,
+        "sapi/v1/userDataStream/isolated",
+        fixture!("margin/isolated_listen_key.json"),
+    ),
+    (
+        PUT,
+        "sapi/v1/userDataStream/isolated",
+        fixture!("ufutures/empty.json"),
+    ),
+    (
+        DELETE,
+        "sapi/v1/userDataStream/isolated",
+        fixture!("ufutures/empty.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/capital/config/getall",
+        fixture!("wallet/coins.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/capital/deposit/address",
+        fixture!("wallet/deposit_address.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/capital/deposit/hisrec",
+        fixture!("wallet/deposit_history.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/capital/withdraw/apply",
+        fixture!("wallet/withdraw.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/capital/withdraw/history",
+        fixture!("wallet/withdraw_history.json"),
+    ),
+    (SPOST, "sapi/v1/asset/transfer", fixture!("wallet/transfer.json")),
+    (
+        SGET,
+        "sapi/v1/asset/transfer",
+        fixture!("wallet/transfer_history.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/asset/assetDetail",
+        fixture!("wallet/asset_detail.json"),
+    ),
+    (SGET, "sapi/v1/asset/tradeFee", fixture!("wallet/trade_fee.json")),
+    (SPOST, "sapi/v1/asset/dust-btc", fixture!("wallet/dust_assets.json")),
+    (SPOST, "sapi/v1/asset/dust", fixture!("wallet/dust.json")),
+    (SGET, "sapi/v1/sub-account/list", fixture!("sub_account/list.json")),
+    (
+        SPOST,
+        "sapi/v1/sub-account/virtualSubAccount",
+        fixture!("sub_account/virtual.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/sub-account/futures/enable",
+        fixture!("sub_account/futures_enable.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/sub-account/margin/enable",
+        fixture!("sub_account/margin_enable.json"),
+    ),
+    (
+        SGET,
+        "sapi/v4/sub-account/assets",
+        fixture!("sub_account/assets.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/sub-account/spotSummary",
+        fixture!("sub_account/spot_summary.json"),
+    ),
+    (
+        SGET,
+        "sapi/v2/sub-account/futures/accountSummary",
+        fixture!("sub_account/futures_summary.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/sub-account/universalTransfer",
+        fixture!("sub_account/universal_transfer.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/sub-account/universalTransfer",
+        fixture!("sub_account/transfer_history.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/sub-account/subAccountApi/ipRestriction",
+        fixture!("sub_account/ip_restriction.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v2/sub-account/subAccountApi/ipRestriction",
+        fixture!("sub_account/ip_restriction.json"),
+    ),
+    (
+        SDELETE,
+        "sapi/v1/sub-account/subAccountApi/ipRestriction/ipList",
+        fixture!("sub_account/ip_restriction.json"),
+    ),    (
+        SGET,
+        "sapi/v1/simple-earn/flexible/list",
+        fixture!("earn/flexible_list.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/simple-earn/flexible/subscribe",
+        fixture!("earn/flexible_subscribe.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/simple-earn/flexible/redeem",
+        fixture!("earn/redeem.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/flexible/position",
+        fixture!("earn/flexible_position.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/flexible/history/rewardsRecord",
+        fixture!("earn/flexible_rewards.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/flexible/personalLeftQuota",
+        fixture!("earn/quota.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/locked/list",
+        fixture!("earn/locked_list.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/simple-earn/locked/subscribe",
+        fixture!("earn/locked_subscribe.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/simple-earn/locked/redeem",
+        fixture!("earn/redeem.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/locked/position",
+        fixture!("earn/locked_position.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/locked/history/rewardsRecord",
+        fixture!("earn/locked_rewards.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/simple-earn/locked/personalLeftQuota",
+        fixture!("earn/quota.json"),
+    ),    (
+        GET,
+        "sapi/v1/convert/exchangeInfo",
+        fixture!("convert/exchange_info.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/convert/getQuote",
+        fixture!("convert/get_quote.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/convert/acceptQuote",
+        fixture!("convert/accept_quote.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/convert/orderStatus",
+        fixture!("convert/order_status.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/convert/tradeFlow",
+        fixture!("convert/trade_flow.json"),
+    ),    // 统一账户
+    (
+        SPOST,
+        "papi/v1/um/order",
+        fixture!("portfolio_margin/um_order.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/um/order",
+        fixture!("portfolio_margin/um_order.json"),
+    ),
+    (
+        SDELETE,
+        "papi/v1/um/order",
+        fixture!("portfolio_margin/um_order.json"),
+    ),
+    (
+        SDELETE,
+        "papi/v1/um/allOpenOrders",
+        fixture!("options/cancel_all_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/um/openOrders",
+        fixture!("portfolio_margin/um_open_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/um/allOrders",
+        fixture!("portfolio_margin/um_open_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/um/positionRisk",
+        fixture!("portfolio_margin/um_positions.json"),
+    ),
+    (
+        SPOST,
+        "papi/v1/cm/order",
+        fixture!("portfolio_margin/cm_order.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/cm/order",
+        fixture!("portfolio_margin/cm_order.json"),
+    ),
+    (
+        SDELETE,
+        "papi/v1/cm/order",
+        fixture!("portfolio_margin/cm_order.json"),
+    ),
+    (
+        SDELETE,
+        "papi/v1/cm/allOpenOrders",
+        fixture!("options/cancel_all_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/cm/openOrders",
+        fixture!("portfolio_margin/cm_open_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/cm/allOrders",
+        fixture!("portfolio_margin/cm_open_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/cm/positionRisk",
+        fixture!("portfolio_margin/cm_positions.json"),
+    ),
+    (
+        SPOST,
+        "papi/v1/margin/order",
+        fixture!("margin/order.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/margin/order",
+        fixture!("margin/query_order.json"),
+    ),
+    (
+        SDELETE,
+        "papi/v1/margin/order",
+        fixture!("margin/cancel_order.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/margin/openOrders",
+        fixture!("margin/open_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/margin/allOrders",
+        fixture!("margin/all_orders.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/balance",
+        fixture!("portfolio_margin/balance.json"),
+    ),
+    (
+        SGET,
+        "papi/v1/account",
+        fixture!("portfolio_margin/account.json"),
+    ),
+    (
+        SPOST,
+        "papi/v1/auto-collection",
+        fixture!("portfolio_margin/collection.json"),
+    ),
+    (
+        SPOST,
+        "papi/v1/asset-collection",
+        fixture!("portfolio_margin/collection.json"),
+    ),
+    (
+        POST,
+        "papi/v1/listenKey",
+        fixture!("portfolio_margin/listen_key.json"),
+    ),
+    (
+        PUT,
+        "papi/v1/listenKey",
+        fixture!("ufutures/empty.json"),
+    ),
+    (
+        DELETE,
+        "papi/v1/listenKey",
+        fixture!("ufutures/empty.json"),
+    ),    (
+        SPOST,
+        "sapi/v1/algo/futures/newOrderTwap",
+        fixture!("algo/new_order.json"),
+    ),
+    (
+        SPOST,
+        "sapi/v1/algo/futures/newOrderVp",
+        fixture!("algo/new_order.json"),
+    ),
+    (
+        SDELETE,
+        "sapi/v1/algo/futures/order",
+        fixture!("algo/cancel_order.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/algo/futures/openOrders",
+        fixture!("algo/open_orders.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/algo/futures/historicalOrders",
+        fixture!("algo/historical_orders.json"),
+    ),
+    (
+        SGET,
+        "sapi/v1/algo/futures/subOrders",
+        fixture!("algo/sub_orders.json"),
+    ),
+];
+
+/// (连接路径, 每行一条推送消息)
+pub(super) const WS_FIXTURES: &[(&str, &str)] = &[
+    (
+        "ws/btcusdt@aggTrade",
+        fixture!("ufutures/ws_btcusdt_aggTrade.jsonl"),
+    ),
+    (
+        "ws/btcusdt@markPrice@1s",
+        fixture!("ufutures/ws_btcusdt_markPrice_1s.jsonl"),
+    ),
+    (
+        "ws/btcusdt@kline_1m",
+        fixture!("ufutures/ws_btcusdt_kline_1m.jsonl"),
+    ),
+    (
+        "ws/btcusdt@bookTicker",
+        fixture!("ufutures/ws_btcusdt_bookTicker.jsonl"),
+    ),
+    (
+        "ws/btcusd@indexPrice@1s",
+        fixture!("dfutures/ws_btcusd_indexPrice_1s.jsonl"),
+    ),
+    (
+        "ws/btcusd@indexPriceKline_1m",
+        fixture!("dfutures/ws_btcusd_indexPriceKline_1m.jsonl"),
+    ),
+    (
+        "ws/btcusd_perp@markPriceKline_1m",
+        fixture!("dfutures/ws_btcusd_perp_markPriceKline_1m.jsonl"),
+    ),
+    ("ws/!contractInfo", fixture!("dfutures/ws_contract_info.jsonl")),
+    // 用户数据推送, 路径为 listen_key.json 中的 listenKey
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1",
+        fixture!("ufutures/ws_user_data.jsonl"),
+    ),
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a2",
+        fixture!("spot/ws_user_data.jsonl"),
+    ),
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a3",
+        fixture!("options/ws_user_data.jsonl"),
+    ),
+    // 杠杆账户推送格式与现货相同
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a4",
+        fixture!("spot/ws_user_data.jsonl"),
+    ),
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a5",
+        fixture!("spot/ws_user_data.jsonl"),
+    ),
+    (
+        "ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a6",
+        fixture!("portfolio_margin/ws_user_data.jsonl"),
+    ),
+    (
+        "ws/BTC-240628-60000-C@ticker",
+        fixture!("options/ws_ticker.jsonl"),
+    ),
+    ("ws/BTC@markPrice", fixture!("options/ws_mark_price.jsonl")),
+    (
+        "ws/BTC-240628-60000-C@depth10@100ms",
+        fixture!("options/ws_depth.jsonl"),
+    ),
+];
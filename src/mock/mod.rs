@@ -0,0 +1,425 @@
+//! 本地 mock 币安服务器
+//!
+//! 用于离线集成测试, 需开启 `mock` feature。
+//! http 接口按 `(方法, 路径)` 返回预先录制的 fixture, 签名接口会使用
+//! [`MOCK_SECRET_KEY`] 校验 HMAC 签名; websocket 接口按路径推送预设的消息。
+//!
+//! ```no_run
+//! use bian_rs::client::UFuturesHttpClient;
+//! use bian_rs::mock::{MockServer, MOCK_API_KEY, MOCK_SECRET_KEY};
+//!
+//! # async fn run() {
+//! let server = MockServer::start().unwrap();
+//! let client = UFuturesHttpClient::new(
+//!     MOCK_API_KEY.to_string(),
+//!     MOCK_SECRET_KEY.to_string(),
+//!     &server.http_url(),
+//! )
+//! .unwrap();
+//! client.ping().await.unwrap();
+//! # }
+//! ```
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use tungstenite::handshake::server::{Request, Response};
+
+use crate::enums::Method;
+use crate::error::{APIError, BianResult};
+
+mod fixtures;
+
+/// mock 服务器接受的 api key
+pub const MOCK_API_KEY: &str = "mock-api-key";
+/// mock 服务器用于校验签名的 secret key
+pub const MOCK_SECRET_KEY: &str = "mock-secret-key";
+
+/// mock 服务器收到的 http 请求
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// http 方法, 如 `GET`
+    pub method: String,
+    /// 请求路径, 如 `/fapi/v1/order`
+    pub path: String,
+    /// 原始 query string, 包含 signature
+    pub query: String,
+    /// X-MBX-APIKEY 请求头
+    pub api_key: Option<String>,
+}
+
+impl MockRequest {
+    /// 获取 query string 中的参数
+    pub fn param(&self, key: &str) -> Option<String> {
+        url::form_urlencoded::parse(self.query.as_bytes())
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.into_owned())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Route {
+    method: String,
+    path: String,
+    params: Vec<(String, String)>,
+    signed: bool,
+    status: u16,
+    body: String,
+}
+
+impl Route {
+    fn matches(&self, req: &MockRequest) -> bool {
+        self.method == req.method
+            && self.path == req.path
+            && self
+                .params
+                .iter()
+                .all(|(k, v)| req.param(k).as_deref() == Some(v.as_str()))
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<Route>,
+    frames: HashMap<String, Vec<String>>,
+    requests: Vec<MockRequest>,
+    ws_connections: usize,
+}
+
+/// 本地 mock 服务器, drop 时自动关闭
+pub struct MockServer {
+    http_addr: SocketAddr,
+    ws_addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockServer {
+    /// 启动 mock 服务器, 并加载现货, U本位合约和币本位合约的默认 fixture
+    pub fn start() -> BianResult<Self> {
+        let server = Self::empty()?;
+        for (method, path, body) in fixtures::HTTP_FIXTURES {
+            server.mock(*method, path, body);
+        }
+        for (path, frames) in fixtures::WS_FIXTURES {
+            server.push_frames(path, frames.lines().map(String::from).collect());
+        }
+        Ok(server)
+    }
+
+    /// 启动不带任何 fixture 的 mock 服务器
+    pub fn empty() -> BianResult<Self> {
+        let http_listener =
+            TcpListener::bind("127.0.0.1:0").map_err(|e| APIError::Unknown(e.to_string()))?;
+        let ws_listener =
+            TcpListener::bind("127.0.0.1:0").map_err(|e| APIError::Unknown(e.to_string()))?;
+        let http_addr = http_listener.local_addr().unwrap();
+        let ws_addr = ws_listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let (s, flag) = (state.clone(), shutdown.clone());
+        thread::spawn(move || serve(http_listener, flag, s, handle_http));
+        let (s, flag) = (state.clone(), shutdown.clone());
+        thread::spawn(move || serve(ws_listener, flag, s, handle_ws));
+
+        Ok(Self {
+            http_addr,
+            ws_addr,
+            state,
+            shutdown,
+        })
+    }
+
+    /// http 接口地址, 用于构造 http 客户端
+    pub fn http_url(&self) -> String {
+        format!("http://{}", self.http_addr)
+    }
+
+    /// websocket 接口地址, 用于构造 websocket 客户端
+    pub fn ws_url(&self) -> String {
+        format!("ws://{}", self.ws_addr)
+    }
+
+    /// 设置接口返回的数据, 已存在的 fixture 会被覆盖
+    ///
+    /// `SGET`, `SPOST` 等签名方法会校验 api key 和签名。
+    /// 路径可以带 query string, 如 `/fapi/v1/ticker/price?symbol=BTCUSDT`,
+    /// 此时仅匹配包含这些参数的请求, 且优先于不带参数的 fixture
+    pub fn mock(&self, method: Method, path: &str, body: &str) {
+        self.mock_status(method, path, 200, body);
+    }
+
+    /// 设置接口返回的状态码和数据, 用于模拟错误响应
+    pub fn mock_status(&self, method: Method, path: &str, status: u16, body: &str) {
+        let path = normalize_path(path);
+        let (path, params) = match path.split_once('?') {
+            Some((p, q)) => (
+                p.to_string(),
+                url::form_urlencoded::parse(q.as_bytes())
+                    .into_owned()
+                    .collect::<Vec<_>>(),
+            ),
+            None => (path, vec![]),
+        };
+        let route = Route {
+            method: method.verb().to_string(),
+            path,
+            params,
+            signed: method.is_signed(),
+            status,
+            body: body.to_string(),
+        };
+        let mut state = self.state.lock().unwrap();
+        state.routes.retain(|r| {
+            !(r.method == route.method && r.path == route.path && r.params == route.params)
+        });
+        state.routes.push(route);
+    }
+
+    /// 设置 websocket 连接建立后依次推送的消息
+    ///
+    /// `path` 为连接路径, 如 `/ws/btcusdt@aggTrade` 或
+    /// `/stream?streams=btcusdt@aggTrade/ethusdt@aggTrade`
+    pub fn push_frames(&self, path: &str, frames: Vec<String>) {
+        self.state
+            .lock()
+            .unwrap()
+            .frames
+            .insert(normalize_path(path), frames);
+    }
+
+    /// 已收到的 http 请求
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// 当前未断开的 websocket 连接数
+    pub fn ws_connections(&self) -> usize {
+        self.state.lock().unwrap().ws_connections
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // 唤醒阻塞在 accept 上的线程
+        TcpStream::connect(self.http_addr).ok();
+        TcpStream::connect(self.ws_addr).ok();
+    }
+}
+
+impl Method {
+    fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            Method::SGET | Method::SPOST | Method::SPUT | Method::SDELETE
+        )
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            Method::GET | Method::SGET => "GET",
+            Method::POST | Method::SPOST => "POST",
+            Method::PUT | Method::SPUT => "PUT",
+            Method::DELETE | Method::SDELETE => "DELETE",
+        }
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    if path.starts_with('/') {
+        path.to_string()
+    } else {
+        format!("/{}", path)
+    }
+}
+
+fn serve(
+    listener: TcpListener,
+    shutdown: Arc<AtomicBool>,
+    state: Arc<Mutex<MockState>>,
+    handler: fn(TcpStream, Arc<Mutex<MockState>>),
+) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        if let Ok(stream) = stream {
+            let state = state.clone();
+            thread::spawn(move || handler(stream, state));
+        }
+    }
+}
+
+fn handle_http(stream: TcpStream, state: Arc<Mutex<MockState>>) {
+    let mut reader = BufReader::new(match stream.try_clone() {
+        Ok(s) => s,
+        Err(_) => return,
+    });
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() || request_line.is_empty() {
+        return;
+    }
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default().to_string();
+
+    let mut api_key = None;
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() {
+            return;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            match name.to_lowercase().as_str() {
+                "x-mbx-apikey" => api_key = Some(value.to_string()),
+                "content-length" => content_length = value.parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok();
+
+    let (path, query) = match target.split_once('?') {
+        Some((p, q)) => (p.to_string(), q.to_string()),
+        None => (target.clone(), String::new()),
+    };
+    let request = MockRequest {
+        method,
+        path,
+        query,
+        api_key,
+    };
+    let (status, body) = respond(&request, &state);
+    state.lock().unwrap().requests.push(request);
+
+    let resp = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        body.len(),
+        body
+    );
+    let mut stream = stream;
+    stream.write_all(resp.as_bytes()).ok();
+    stream.flush().ok();
+}
+
+fn respond(req: &MockRequest, state: &Arc<Mutex<MockState>>) -> (u16, String) {
+    let route = state
+        .lock()
+        .unwrap()
+        .routes
+        .iter()
+        .filter(|r| r.matches(req))
+        .max_by_key(|r| r.params.len())
+        .cloned();
+    let route = match route {
+        Some(route) => route,
+        None => {
+            return (
+                404,
+                error_body(-1, &format!("no fixture for {} {}", req.method, req.path)),
+            )
+        }
+    };
+    if route.signed {
+        if req.api_key.as_deref() != Some(MOCK_API_KEY) {
+            return (
+                401,
+                error_body(-2015, "Invalid API-key, IP, or permissions for action."),
+            );
+        }
+        if !verify_signature(&req.query) {
+            return (
+                400,
+                error_body(-1022, "Signature for this request is not valid."),
+            );
+        }
+    }
+    (route.status, route.body)
+}
+
+/// 校验 query string 末尾的签名, 签名内容为 `&signature=` 之前的部分
+fn verify_signature(query: &str) -> bool {
+    let (payload, signature) = match query.rfind("signature=") {
+        Some(idx) => {
+            let payload = query[..idx].trim_end_matches('&');
+            (payload, &query[idx + "signature=".len()..])
+        }
+        None => return false,
+    };
+    let signature = match hex::decode(signature) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let mut mac = Hmac::<Sha256>::new_varkey(MOCK_SECRET_KEY.as_bytes()).unwrap();
+    mac.update(payload.as_bytes());
+    mac.verify(&signature).is_ok()
+}
+
+fn error_body(code: i64, msg: &str) -> String {
+    serde_json::json!({ "code": code, "msg": msg }).to_string()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        418 => "I'm a teapot",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
+
+fn handle_ws(stream: TcpStream, state: Arc<Mutex<MockState>>) {
+    let mut path = String::new();
+    // 签名由 tungstenite 的 Callback 决定
+    #[allow(clippy::result_large_err)]
+    let callback = |req: &Request, resp: Response| {
+        path = req.uri().to_string();
+        Ok(resp)
+    };
+    let mut socket = match tungstenite::accept_hdr(stream, callback) {
+        Ok(socket) => socket,
+        Err(_) => return,
+    };
+    let frames = {
+        let mut state = state.lock().unwrap();
+        state.ws_connections += 1;
+        state.frames.get(&path).cloned().unwrap_or_default()
+    };
+    for frame in frames {
+        if socket
+            .write_message(tungstenite::Message::Text(frame))
+            .is_err()
+        {
+            break;
+        }
+    }
+    // 推送完毕后保持连接, 直到客户端关闭
+    while let Ok(msg) = socket.read_message() {
+        if msg.is_close() {
+            break;
+        }
+    }
+    state.lock().unwrap().ws_connections -= 1;
+}
@@ -0,0 +1,89 @@
+//! 分页查询
+//!
+//! 理财, 划转历史等接口使用 `current` 和 `size` 分页, 返回 [`Page`]。[`collect_pages`] 从第一页开始
+//! 依次查询, 直到取完所有记录。
+//!
+//! ```no_run
+//! use bian_rs::client::EarnHttpClient;
+//! use bian_rs::pagination::collect_pages;
+//! use bian_rs::params::{PEarnProductList, PTimestamp};
+//!
+//! # async fn run(client: EarnHttpClient) {
+//! let products = collect_pages(100, |page| {
+//!     client.flexible_products(PEarnProductList {
+//!         asset: None,
+//!         page,
+//!         ts: PTimestamp::now(),
+//!     })
+//! })
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+//!
+//! 合约资金流水等接口使用 `page` 页码分页, 直接返回列表, 可以使用 [`collect_page_numbers`]。
+//!
+//! ```no_run
+//! use bian_rs::client::UFuturesHttpClient;
+//! use bian_rs::pagination::collect_page_numbers;
+//! use bian_rs::params::{PIncome, PTimestamp};
+//!
+//! # async fn run(client: UFuturesHttpClient) {
+//! let income = collect_page_numbers(1000, |page| {
+//!     client.income(PIncome {
+//!         symbol: None,
+//!         income_type: None,
+//!         start_time: None,
+//!         end_time: None,
+//!         page: Some(page),
+//!         limit: Some(1000),
+//!         ts: PTimestamp::now(),
+//!     })
+//! })
+//! .await
+//! .unwrap();
+//! # }
+//! ```
+use std::future::Future;
+
+use crate::error::BianResult;
+use crate::params::PPage;
+use crate::response::Page;
+
+/// 查询所有页, `size` 为每页数量
+pub async fn collect_pages<T, F, Fut>(size: usize, mut fetch: F) -> BianResult<Vec<T>>
+where
+    F: FnMut(PPage) -> Fut,
+    Fut: Future<Output = BianResult<Page<T>>>,
+{
+    let mut page = PPage::first(size);
+    let mut rows = Vec::new();
+    loop {
+        let resp = fetch(page).await?;
+        let count = resp.rows.len();
+        rows.extend(resp.rows);
+        if count == 0 || count < size || rows.len() >= resp.total {
+            return Ok(rows);
+        }
+        page = page.next();
+    }
+}
+
+/// 按页码查询所有页, 页码从 1 开始, `limit` 为每页数量, 返回数量少于 `limit` 时结束
+pub async fn collect_page_numbers<T, F, Fut>(limit: usize, mut fetch: F) -> BianResult<Vec<T>>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = BianResult<Vec<T>>>,
+{
+    let mut page = 1;
+    let mut rows = Vec::new();
+    loop {
+        let resp = fetch(page).await?;
+        let count = resp.len();
+        rows.extend(resp);
+        if count == 0 || count < limit {
+            return Ok(rows);
+        }
+        page += 1;
+    }
+}
@@ -0,0 +1,965 @@
+//! 模拟交易所
+//!
+//! [`PaperExchange`] 提供与 [`UFuturesHttpClient`](crate::client::UFuturesHttpClient)
+//! 相同的下单接口, 根据实时或回放的行情撮合订单, 并按 `exchange_info` 中的
+//! `LOT_SIZE`/`PRICE_FILTER`/`MIN_NOTIONAL` 规则校验订单。
+//! 订单和账户变化会以 [`WSUserStream`] 事件的形式推送, 策略代码无需修改即可进行模拟盘测试。
+//!
+//! 撮合规则做了简化:
+//! - 不考虑盘口深度, 成交时一次性全部成交
+//! - 挂单在对手价或成交价穿过委托价时以委托价成交(maker), 立即成交的订单以对手价成交(taker)
+//! - 不校验保证金, 仅记录钱包余额, 持仓和手续费
+//! - `CONTRACT_PRICE` 条件单按最新成交价触发, 没有成交推送时使用最优挂单的中间价
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::enums::{
+    FuturesOrderType, MarginType, OrderSide, OrderStatus, PositionDirect, TimeInForce,
+};
+use crate::error::{APIError, BianResult};
+use crate::params::{self, PFuturesOrderSpec};
+use crate::response::{
+    self, BatchOrderResponse, CodeResponse, WSAccountEvent, WSAccountUpdate, WSBalance,
+    WSOrderEvent, WSOrderUpdate, WSPosition, WSUserStream,
+};
+use crate::rules::{MarketContext, SymbolRules};
+use crate::trading::{self, MarketData, OrderExecution, OrderRequest};
+
+/// 默认 maker 手续费率
+pub const DEFAULT_MAKER_FEE: f64 = 0.0002;
+/// 默认 taker 手续费率
+pub const DEFAULT_TAKER_FEE: f64 = 0.0004;
+
+/// 交易对规则
+#[derive(Debug, Clone)]
+struct SymbolInfo {
+    margin_asset: String,
+    rules: SymbolRules,
+}
+
+/// 交易对的最新行情
+#[derive(Debug, Clone, Default)]
+struct Market {
+    bid: Option<f64>,
+    ask: Option<f64>,
+    last: Option<f64>,
+    mark: Option<f64>,
+}
+
+impl Market {
+    /// 最新成交价, 没有成交推送时使用最优挂单的中间价
+    fn last_or_mid(&self) -> Option<f64> {
+        match (self.last, self.bid, self.ask) {
+            (Some(last), _, _) => Some(last),
+            (None, Some(bid), Some(ask)) => Some((bid + ask) / 2.0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PaperOrder {
+    order_id: usize,
+    client_order_id: String,
+    symbol: String,
+    side: OrderSide,
+    position_side: PositionDirect,
+    order_type: FuturesOrderType,
+    time_in_force: TimeInForce,
+    quantity: f64,
+    price: f64,
+    stop_price: f64,
+    reduce_only: bool,
+    close_position: bool,
+    working_type: String,
+    price_protect: bool,
+    triggered: bool,
+    status: OrderStatus,
+    executed_qty: f64,
+    cum_quote: f64,
+    update_time: i64,
+}
+
+impl PaperOrder {
+    fn avg_price(&self) -> f64 {
+        if self.executed_qty > 0.0 {
+            self.cum_quote / self.executed_qty
+        } else {
+            0.0
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        matches!(self.status, OrderStatus::New | OrderStatus::PartiallyFilled)
+    }
+
+    fn to_response(&self) -> response::FuturesOrder {
+        response::FuturesOrder {
+            client_order_id: self.client_order_id.clone(),
+            cum_qty: self.executed_qty,
+            executed_qty: self.executed_qty,
+            cum_quote: self.cum_quote,
+            cum_base: 0.0,
+            order_id: self.order_id,
+            avg_price: self.avg_price(),
+            orig_qty: self.quantity,
+            price: self.price,
+            reduce_only: self.reduce_only,
+            side: self.side,
+            position_side: self.position_side,
+            status: self.status,
+            stop_price: self.stop_price,
+            close_position: self.close_position,
+            symbol: self.symbol.clone(),
+            time_in_force: self.time_in_force,
+            order_type: self.order_type,
+            activate_price: 0.0,
+            price_rate: 0.0,
+            update_time: self.update_time,
+            working_type: self.working_type.clone(),
+            price_protect: self.price_protect,
+        }
+    }
+}
+
+/// 持仓, 数量为正表示多仓, 为负表示空仓
+#[derive(Debug, Clone, Default)]
+struct Position {
+    amount: f64,
+    entry_price: f64,
+    realized: f64,
+}
+
+/// 一次成交
+struct Fill {
+    price: f64,
+    qty: f64,
+    maker: bool,
+}
+
+#[derive(Debug, Default)]
+struct PaperState {
+    symbols: HashMap<String, SymbolInfo>,
+    markets: HashMap<String, Market>,
+    balances: HashMap<String, f64>,
+    positions: HashMap<(String, PositionDirect), Position>,
+    orders: Vec<PaperOrder>,
+    fills: Vec<trading::Fill>,
+    countdowns: HashMap<String, i64>,
+    events: Vec<WSUserStream>,
+    next_order_id: usize,
+    next_trade_id: usize,
+    clock: i64,
+    maker_fee: f64,
+    taker_fee: f64,
+}
+
+/// 模拟交易所, clone 后共享同一份状态
+#[derive(Debug, Clone)]
+pub struct PaperExchange {
+    state: Arc<Mutex<PaperState>>,
+}
+
+impl PaperExchange {
+    /// 根据交易规则和初始余额创建模拟交易所
+    ///
+    /// `balances` 为 (资产, 数量), 如 `("USDT", 10000.0)`
+    pub fn new(info: &response::UFuturesExchangeInfo, balances: Vec<(String, f64)>) -> Self {
+        let symbols = info
+            .symbols
+            .iter()
+            .map(|s| {
+                let symbol_info = SymbolInfo {
+                    margin_asset: s.margin_asset.clone(),
+                    rules: SymbolRules::from_futures(&s.symbol, &s.filters),
+                };
+                (s.symbol.clone(), symbol_info)
+            })
+            .collect();
+        let state = PaperState {
+            symbols,
+            balances: balances.into_iter().collect(),
+            next_order_id: 1,
+            next_trade_id: 1,
+            maker_fee: DEFAULT_MAKER_FEE,
+            taker_fee: DEFAULT_TAKER_FEE,
+            ..Default::default()
+        };
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// 设置 maker 和 taker 手续费率
+    pub fn with_fee(self, maker_fee: f64, taker_fee: f64) -> Self {
+        {
+            let mut state = self.state.lock().unwrap();
+            state.maker_fee = maker_fee;
+            state.taker_fee = taker_fee;
+        }
+        self
+    }
+
+    /// 取出尚未处理的订单和账户推送
+    pub fn drain_events(&self) -> Vec<WSUserStream> {
+        std::mem::take(&mut self.state.lock().unwrap().events)
+    }
+
+    /// 钱包余额
+    pub fn balance(&self, asset: &str) -> f64 {
+        let state = self.state.lock().unwrap();
+        state.balances.get(asset).cloned().unwrap_or_default()
+    }
+
+    /// 持仓数量和开仓均价, 数量为正表示多仓, 为负表示空仓
+    pub fn position(&self, symbol: &str, position_side: PositionDirect) -> (f64, f64) {
+        let state = self.state.lock().unwrap();
+        state
+            .positions
+            .get(&(symbol.to_string(), position_side))
+            .map(|p| (p.amount, p.entry_price))
+            .unwrap_or_default()
+    }
+}
+
+/// 行情输入
+impl PaperExchange {
+    /// 最优挂单更新
+    pub fn on_book_ticker(&self, ticker: &response::WSFuturesBookTicker) {
+        let mut state = self.state.lock().unwrap();
+        state.advance_clock(ticker.event_time);
+        let market = state.markets.entry(ticker.symbol.clone()).or_default();
+        market.bid = Some(ticker.buy_price);
+        market.ask = Some(ticker.sell_price);
+        state.match_symbol(&ticker.symbol);
+    }
+
+    /// 归集成交
+    pub fn on_agg_trade(&self, trade: &response::WSAggTrade) {
+        let mut state = self.state.lock().unwrap();
+        state.advance_clock(trade.event_time);
+        let market = state.markets.entry(trade.symbol.clone()).or_default();
+        market.last = Some(trade.price);
+        state.match_symbol(&trade.symbol);
+    }
+
+    /// 标记价格更新
+    pub fn on_mark_price(&self, price: &response::WSPrice) {
+        let mut state = self.state.lock().unwrap();
+        state.advance_clock(price.event_time);
+        let market = state.markets.entry(price.symbol.clone()).or_default();
+        market.mark = Some(price.price);
+        state.match_symbol(&price.symbol);
+    }
+
+    /// 推进时钟, 用于回放行情时触发倒计时撤单
+    pub fn advance_clock(&self, time: i64) {
+        self.state.lock().unwrap().advance_clock(time);
+    }
+}
+
+/// 交易接口, 与 U本位合约 http 客户端一致
+impl PaperExchange {
+    /// 下单
+    pub async fn order(&self, param: params::PFuturesOrder) -> BianResult<response::FuturesOrder> {
+        self.state.lock().unwrap().place(param.spec)
+    }
+
+    /// 批量下单
+    pub async fn batch_order(
+        &self,
+        param: params::PBatchOrder,
+    ) -> BianResult<Vec<BatchOrderResponse>> {
+        if param.batch_orders.len() > 5 {
+            return Err(reject(-4002, "Batch order list should be less than 5."));
+        }
+        let mut state = self.state.lock().unwrap();
+        let resp = param
+            .batch_orders
+            .into_iter()
+            .map(|spec| match state.place(spec) {
+                Ok(order) => BatchOrderResponse::Order(order),
+                Err(e) => BatchOrderResponse::Code(to_code(e)),
+            })
+            .collect();
+        Ok(resp)
+    }
+
+    /// 查询订单
+    pub async fn query_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+        let state = self.state.lock().unwrap();
+        state
+            .find(&param)
+            .map(|idx| state.orders[idx].to_response())
+            .ok_or_else(|| reject(-2013, "Order does not exist."))
+    }
+
+    /// 撤销订单
+    pub async fn cancel_order(
+        &self,
+        param: params::PQueryFuturesOrder,
+    ) -> BianResult<response::FuturesOrder> {
+        let mut state = self.state.lock().unwrap();
+        let idx = state
+            .find(&param)
+            .filter(|idx| state.orders[*idx].is_open())
+            .ok_or_else(|| reject(-2011, "Unknown order sent."))?;
+        state.finish(idx, OrderStatus::Canceled, "CANCELED");
+        Ok(state.orders[idx].to_response())
+    }
+
+    /// 查询当前全部挂单
+    pub async fn open_orders(
+        &self,
+        param: params::POptionSymbolQuery,
+    ) -> BianResult<Vec<response::FuturesOrder>> {
+        let state = self.state.lock().unwrap();
+        let orders = state
+            .orders
+            .iter()
+            .filter(|o| o.is_open())
+            .filter(|o| match &param.symbol {
+                Some(s) => *s == o.symbol,
+                None => true,
+            })
+            .map(PaperOrder::to_response)
+            .collect();
+        Ok(orders)
+    }
+
+    /// 倒计时撤销所有订单
+    ///
+    /// `countdown_time` 为 0 时取消倒计时
+    pub async fn countdown_cancel_all(
+        &self,
+        param: params::PCountdownCancel,
+    ) -> BianResult<response::CountdownCancel> {
+        let mut state = self.state.lock().unwrap();
+        if param.countdown_time == 0 {
+            state.countdowns.remove(&param.symbol);
+        } else {
+            let deadline = state.now() + param.countdown_time;
+            state.countdowns.insert(param.symbol.clone(), deadline);
+        }
+        Ok(response::CountdownCancel {
+            symbol: param.symbol,
+            countdown_time: param.countdown_time as usize,
+        })
+    }
+}
+
+#[async_trait]
+impl MarketData for PaperExchange {
+    async fn last_price(&self, symbol: &str) -> BianResult<f64> {
+        let state = self.state.lock().unwrap();
+        let market = state.markets.get(symbol).cloned().unwrap_or_default();
+        market
+            .last
+            .or(market.mark)
+            .ok_or_else(|| reject(-1000, "No market data for symbol."))
+    }
+
+    async fn best_quote(&self, symbol: &str) -> BianResult<trading::Quote> {
+        let state = self.state.lock().unwrap();
+        let market = state.markets.get(symbol).cloned().unwrap_or_default();
+        match (market.bid, market.ask) {
+            (Some(bid_price), Some(ask_price)) => Ok(trading::Quote {
+                symbol: symbol.to_string(),
+                bid_price,
+                bid_qty: 0.0,
+                ask_price,
+                ask_qty: 0.0,
+            }),
+            _ => Err(reject(-1000, "No market data for symbol.")),
+        }
+    }
+
+    /// 模拟盘不维护盘口深度, 仅返回最优挂单
+    async fn order_book(&self, symbol: &str, _limit: usize) -> BianResult<trading::OrderBook> {
+        let quote = self.best_quote(symbol).await?;
+        Ok(trading::OrderBook {
+            bids: vec![(quote.bid_price, quote.bid_qty)],
+            asks: vec![(quote.ask_price, quote.ask_qty)],
+        })
+    }
+}
+
+#[async_trait]
+impl OrderExecution for PaperExchange {
+    async fn submit_order(&self, req: OrderRequest) -> BianResult<trading::Order> {
+        let resp = self.state.lock().unwrap().place(req.to_futures_spec())?;
+        Ok(resp.into())
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: usize) -> BianResult<trading::Order> {
+        let resp = self.cancel_order(query(symbol, order_id)).await?;
+        Ok(resp.into())
+    }
+
+    async fn fetch_order(&self, symbol: &str, order_id: usize) -> BianResult<trading::Order> {
+        let resp = self.query_order(query(symbol, order_id)).await?;
+        Ok(resp.into())
+    }
+
+    async fn fetch_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> BianResult<trading::Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: None,
+            orig_client_order_id: Some(client_order_id.to_string()),
+            ts: params::PTimestamp::now(),
+        };
+        Ok(self.query_order(param).await?.into())
+    }
+
+    async fn fetch_open_orders(&self, symbol: Option<&str>) -> BianResult<Vec<trading::Order>> {
+        let param = params::POptionSymbolQuery {
+            symbol: symbol.map(String::from),
+            ts: params::PTimestamp::now(),
+        };
+        let orders = self.open_orders(param).await?;
+        Ok(orders.into_iter().map(trading::Order::from).collect())
+    }
+
+    async fn fetch_fills(&self, symbol: &str) -> BianResult<Vec<trading::Fill>> {
+        let state = self.state.lock().unwrap();
+        let fills = state
+            .fills
+            .iter()
+            .filter(|f| f.symbol == symbol)
+            .cloned()
+            .collect();
+        Ok(fills)
+    }
+
+    async fn fetch_balances(&self) -> BianResult<Vec<trading::Balance>> {
+        let state = self.state.lock().unwrap();
+        let balances = state
+            .balances
+            .iter()
+            .map(|(asset, balance)| trading::Balance {
+                asset: asset.clone(),
+                total: *balance,
+                available: *balance,
+            })
+            .collect();
+        Ok(balances)
+    }
+
+    async fn fetch_positions(&self, symbol: &str) -> BianResult<Vec<trading::Position>> {
+        let state = self.state.lock().unwrap();
+        let market = state.markets.get(symbol).cloned().unwrap_or_default();
+        let positions = state
+            .positions
+            .iter()
+            .filter(|((s, _), _)| s == symbol)
+            .map(|((symbol, position_side), p)| {
+                let mark = market.mark.or(market.last).unwrap_or(p.entry_price);
+                trading::Position {
+                    symbol: symbol.clone(),
+                    position_side: *position_side,
+                    amount: p.amount,
+                    entry_price: p.entry_price,
+                    unrealized_pnl: p.amount * (mark - p.entry_price),
+                }
+            })
+            .collect();
+        Ok(positions)
+    }
+}
+
+fn query(symbol: &str, order_id: usize) -> params::PQueryFuturesOrder {
+    params::PQueryFuturesOrder {
+        symbol: symbol.to_string(),
+        order_id: Some(order_id),
+        orig_client_order_id: None,
+        ts: params::PTimestamp::now(),
+    }
+}
+
+impl PaperState {
+    fn now(&self) -> i64 {
+        if self.clock > 0 {
+            self.clock
+        } else {
+            chrono::Utc::now().timestamp_millis()
+        }
+    }
+
+    fn advance_clock(&mut self, time: i64) {
+        if time > self.clock {
+            self.clock = time;
+        }
+        let now = self.now();
+        let expired: Vec<String> = self
+            .countdowns
+            .iter()
+            .filter(|(_, deadline)| **deadline <= now)
+            .map(|(symbol, _)| symbol.clone())
+            .collect();
+        for symbol in expired {
+            self.countdowns.remove(&symbol);
+            let open: Vec<usize> = (0..self.orders.len())
+                .filter(|idx| self.orders[*idx].is_open() && self.orders[*idx].symbol == symbol)
+                .collect();
+            for idx in open {
+                self.finish(idx, OrderStatus::Canceled, "CANCELED");
+            }
+        }
+    }
+
+    fn find(&self, param: &params::PQueryFuturesOrder) -> Option<usize> {
+        self.orders.iter().position(|o| {
+            o.symbol == param.symbol
+                && (param.order_id == Some(o.order_id)
+                    || param.orig_client_order_id.as_ref() == Some(&o.client_order_id))
+        })
+    }
+
+    fn place(&mut self, spec: PFuturesOrderSpec) -> BianResult<response::FuturesOrder> {
+        let info = self
+            .symbols
+            .get(&spec.symbol)
+            .cloned()
+            .ok_or_else(|| reject(-1121, "Invalid symbol."))?;
+        if let Some(id) = &spec.new_client_order_id {
+            if self
+                .orders
+                .iter()
+                .any(|o| o.is_open() && &o.client_order_id == id)
+            {
+                return Err(reject(-4015, "Client order id is not valid."));
+            }
+        }
+        if spec.order_type == FuturesOrderType::TrailingStopMarket {
+            return Err(reject(
+                -4046,
+                "TRAILING_STOP_MARKET is not supported by paper exchange.",
+            ));
+        }
+        let position_side = spec.position_side.unwrap_or(PositionDirect::Both);
+        let close_position = spec.close_position.unwrap_or(false);
+        let quantity = if close_position {
+            self.positions
+                .get(&(spec.symbol.clone(), position_side))
+                .map(|p| p.amount.abs())
+                .unwrap_or_default()
+        } else {
+            spec.quantity.unwrap_or_default()
+        };
+        let needs_price = matches!(
+            spec.order_type,
+            FuturesOrderType::Limit | FuturesOrderType::Stop | FuturesOrderType::TakeProfit
+        );
+        let needs_stop = !matches!(
+            spec.order_type,
+            FuturesOrderType::Limit | FuturesOrderType::Market
+        );
+        if needs_price && spec.price.is_none() {
+            return Err(reject(-1102, "Mandatory parameter 'price' was not sent."));
+        }
+        if needs_stop && spec.stop_price.is_none() {
+            return Err(reject(
+                -1102,
+                "Mandatory parameter 'stopPrice' was not sent.",
+            ));
+        }
+        let price = spec.price.unwrap_or_default();
+        let reduce_only = spec.reduce_only.unwrap_or(false) || close_position;
+        if quantity <= 0.0 && !close_position {
+            return Err(reject(-4003, "Quantity less than or equal to zero."));
+        }
+        let market = self.markets.get(&spec.symbol).cloned().unwrap_or_default();
+        let reference_price = if needs_price {
+            Some(price)
+        } else {
+            match spec.side {
+                OrderSide::Buy => market.ask.or(market.last),
+                OrderSide::Sell => market.bid.or(market.last),
+            }
+        };
+        let ctx = MarketContext {
+            reference_price: market.mark,
+            open_orders: self
+                .orders
+                .iter()
+                .filter(|o| o.is_open() && o.symbol == spec.symbol)
+                .count(),
+        };
+        info.rules
+            .check(
+                if close_position { None } else { Some(quantity) },
+                if needs_price { Some(price) } else { None },
+                reference_price.map(|p| p * quantity),
+                reduce_only,
+                &ctx,
+            )
+            .map_err(|e| reject(e.code(info.rules.product), &e.to_string()))?;
+        if reduce_only {
+            let amount = self
+                .positions
+                .get(&(spec.symbol.clone(), position_side))
+                .map(|p| p.amount)
+                .unwrap_or_default();
+            let reduces = match spec.side {
+                OrderSide::Buy => amount < 0.0,
+                OrderSide::Sell => amount > 0.0,
+            };
+            if !reduces {
+                return Err(reject(-2022, "ReduceOnly Order is rejected."));
+            }
+        }
+
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+        let order = PaperOrder {
+            order_id,
+            client_order_id: spec
+                .new_client_order_id
+                .unwrap_or_else(|| format!("paper_{}", order_id)),
+            symbol: spec.symbol,
+            side: spec.side,
+            position_side,
+            order_type: spec.order_type,
+            time_in_force: spec.time_in_force.unwrap_or(TimeInForce::GTC),
+            quantity,
+            price,
+            stop_price: spec.stop_price.unwrap_or_default(),
+            reduce_only: spec.reduce_only.unwrap_or(false),
+            close_position,
+            working_type: spec
+                .working_type
+                .unwrap_or_else(|| "CONTRACT_PRICE".to_string()),
+            price_protect: spec.price_protect.as_deref() == Some("TRUE"),
+            triggered: !needs_stop,
+            status: OrderStatus::New,
+            executed_qty: 0.0,
+            cum_quote: 0.0,
+            update_time: self.now(),
+        };
+        if order.order_type == FuturesOrderType::Market && reference_price.is_none() {
+            return Err(reject(-1000, "No market data for symbol."));
+        }
+        self.orders.push(order);
+        let idx = self.orders.len() - 1;
+        self.emit_order(idx, "NEW", None, 0.0, 0.0);
+        self.try_fill(idx, true);
+        Ok(self.orders[idx].to_response())
+    }
+
+    /// 撮合交易对下所有挂单
+    fn match_symbol(&mut self, symbol: &str) {
+        let open: Vec<usize> = (0..self.orders.len())
+            .filter(|idx| self.orders[*idx].is_open() && self.orders[*idx].symbol == symbol)
+            .collect();
+        for idx in open {
+            self.try_fill(idx, false);
+        }
+    }
+
+    /// 尝试撮合订单, `incoming` 表示订单刚刚提交
+    fn try_fill(&mut self, idx: usize, incoming: bool) {
+        let order = self.orders[idx].clone();
+        let market = self.markets.get(&order.symbol).cloned().unwrap_or_default();
+        let mut taker = incoming;
+        if !order.triggered {
+            let trigger_price = if order.working_type == "MARK_PRICE" {
+                market.mark
+            } else {
+                market.last_or_mid()
+            };
+            let trigger_price = match trigger_price {
+                Some(p) => p,
+                None => return,
+            };
+            let stop_like = matches!(
+                order.order_type,
+                FuturesOrderType::Stop | FuturesOrderType::StopMarket
+            );
+            let triggered = match (order.side, stop_like) {
+                (OrderSide::Buy, true) | (OrderSide::Sell, false) => {
+                    trigger_price >= order.stop_price
+                }
+                (OrderSide::Buy, false) | (OrderSide::Sell, true) => {
+                    trigger_price <= order.stop_price
+                }
+            };
+            if !triggered {
+                return;
+            }
+            self.orders[idx].triggered = true;
+            taker = true;
+        }
+
+        let opposite = match order.side {
+            OrderSide::Buy => market.ask.or(market.last),
+            OrderSide::Sell => market.bid.or(market.last),
+        };
+        let fill = match order.order_type {
+            FuturesOrderType::Market
+            | FuturesOrderType::StopMarket
+            | FuturesOrderType::TakeProfitMarket => opposite.map(|price| Fill {
+                price,
+                qty: order.quantity,
+                maker: false,
+            }),
+            _ => {
+                let crossed = |p: f64| match order.side {
+                    OrderSide::Buy => p <= order.price,
+                    OrderSide::Sell => p >= order.price,
+                };
+                let book_price = match order.side {
+                    OrderSide::Buy => market.ask,
+                    OrderSide::Sell => market.bid,
+                };
+                let book_cross = matches!(book_price, Some(p) if crossed(p));
+                if taker && book_cross {
+                    if order.time_in_force == TimeInForce::GTX {
+                        self.finish(idx, OrderStatus::Expired, "EXPIRED");
+                        return;
+                    }
+                    opposite.map(|price| Fill {
+                        price,
+                        qty: order.quantity,
+                        maker: false,
+                    })
+                } else if !taker && (book_cross || matches!(market.last, Some(p) if crossed(p))) {
+                    Some(Fill {
+                        price: order.price,
+                        qty: order.quantity,
+                        maker: true,
+                    })
+                } else {
+                    None
+                }
+            }
+        };
+        match fill {
+            Some(mut fill) if order.reduce_only || order.close_position => {
+                // 只减仓订单最多成交到持仓为 0, 剩余部分过期, 不会反向开仓
+                let amount = self
+                    .positions
+                    .get(&(order.symbol.clone(), order.position_side))
+                    .map(|p| p.amount)
+                    .unwrap_or_default();
+                let reducible = match order.side {
+                    OrderSide::Buy => (-amount).max(0.0),
+                    OrderSide::Sell => amount.max(0.0),
+                };
+                fill.qty = fill.qty.min(reducible);
+                if fill.qty > 0.0 {
+                    self.apply_fill(idx, fill);
+                }
+                if self.orders[idx].is_open() {
+                    self.finish(idx, OrderStatus::Expired, "EXPIRED");
+                }
+            }
+            Some(fill) => self.apply_fill(idx, fill),
+            None => {
+                let immediate = matches!(order.time_in_force, TimeInForce::IOC | TimeInForce::FOK);
+                if incoming && order.triggered && immediate {
+                    self.finish(idx, OrderStatus::Expired, "EXPIRED");
+                }
+            }
+        }
+    }
+
+    fn apply_fill(&mut self, idx: usize, fill: Fill) {
+        let order = self.orders[idx].clone();
+        let margin_asset = self.symbols[&order.symbol].margin_asset.clone();
+        let fee_rate = if fill.maker {
+            self.maker_fee
+        } else {
+            self.taker_fee
+        };
+        let fee = fill.price * fill.qty * fee_rate;
+        let signed_qty = match order.side {
+            OrderSide::Buy => fill.qty,
+            OrderSide::Sell => -fill.qty,
+        };
+        let position = self
+            .positions
+            .entry((order.symbol.clone(), order.position_side))
+            .or_default();
+        let realized = position.apply(signed_qty, fill.price);
+        *self.balances.entry(margin_asset).or_default() += realized - fee;
+
+        let now = self.now();
+        let o = &mut self.orders[idx];
+        o.executed_qty += fill.qty;
+        o.cum_quote += fill.price * fill.qty;
+        o.update_time = now;
+        o.status = if o.executed_qty + 1e-12 >= o.quantity {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        self.emit_order(idx, "TRADE", Some(&fill), fee, realized);
+        self.emit_account(&order.symbol, order.position_side);
+        self.fills.push(trading::Fill {
+            symbol: order.symbol.clone(),
+            order_id: order.order_id,
+            trade_id: self.next_trade_id - 1,
+            side: order.side,
+            price: fill.price,
+            qty: fill.qty,
+            fee,
+            fee_asset: self.symbols[&order.symbol].margin_asset.clone(),
+            maker: fill.maker,
+            realized_pnl: realized,
+            time: now,
+        });
+    }
+
+    fn finish(&mut self, idx: usize, status: OrderStatus, execution_type: &str) {
+        self.orders[idx].status = status;
+        self.orders[idx].update_time = self.now();
+        self.emit_order(idx, execution_type, None, 0.0, 0.0);
+    }
+
+    fn emit_order(
+        &mut self,
+        idx: usize,
+        execution_type: &str,
+        fill: Option<&Fill>,
+        fee: f64,
+        realized: f64,
+    ) {
+        let order = &self.orders[idx];
+        let now = self.now();
+        let trade_id = if fill.is_some() {
+            self.next_trade_id += 1;
+            self.next_trade_id - 1
+        } else {
+            0
+        };
+        let event = WSOrderEvent {
+            s: order.symbol.clone(),
+            c: order.client_order_id.clone(),
+            S: order.side,
+            o: order.order_type,
+            f: order.time_in_force,
+            q: order.quantity,
+            p: order.price,
+            ap: order.avg_price(),
+            sp: order.stop_price,
+            x: execution_type.to_string(),
+            X: order.status,
+            i: order.order_id,
+            l: fill.map_or(0.0, |f| f.qty),
+            z: order.executed_qty,
+            L: fill.map_or(0.0, |f| f.price),
+            N: self.symbols[&order.symbol].margin_asset.clone(),
+            n: fee,
+            T: now,
+            t: trade_id,
+            b: 0.0,
+            a: 0.0,
+            m: matches!(fill, Some(f) if f.maker),
+            R: order.reduce_only,
+            wt: order.working_type.clone(),
+            ot: Some(order.order_type),
+            ps: order.position_side,
+            cp: order.close_position,
+            AP: 0.0,
+            cr: 0.0,
+            rp: realized,
+        };
+        self.events.push(WSUserStream::OrderUpdate(WSOrderUpdate {
+            event_type: "ORDER_TRADE_UPDATE".to_string(),
+            event_time: now,
+            trade_matching_time: now,
+            event,
+        }));
+    }
+
+    fn emit_account(&mut self, symbol: &str, position_side: PositionDirect) {
+        let now = self.now();
+        let asset = self.symbols[symbol].margin_asset.clone();
+        let wallet = self.balances.get(&asset).cloned().unwrap_or_default();
+        let position = self
+            .positions
+            .get(&(symbol.to_string(), position_side))
+            .cloned()
+            .unwrap_or_default();
+        let market = self.markets.get(symbol).cloned().unwrap_or_default();
+        let mark = market.mark.or(market.last).unwrap_or(position.entry_price);
+        let event = WSAccountEvent {
+            update_type: "ORDER".to_string(),
+            balances: vec![WSBalance {
+                asset,
+                wb: wallet,
+                cw: wallet,
+                bc: 0.0,
+            }],
+            positions: vec![WSPosition {
+                symbol: symbol.to_string(),
+                position: position.amount,
+                enter_price: position.entry_price,
+                cumulative_realized: position.realized,
+                unrealized_pnl: position.amount * (mark - position.entry_price),
+                margin_type: MarginType::Crossed,
+                iw: 0.0,
+                position_side,
+            }],
+        };
+        self.events
+            .push(WSUserStream::AccountUpdate(WSAccountUpdate {
+                event_type: "ACCOUNT_UPDATE".to_string(),
+                event_time: now,
+                trade_matching_time: now,
+                event,
+            }));
+    }
+}
+
+impl Position {
+    /// 更新持仓, 返回本次成交的已实现盈亏
+    fn apply(&mut self, signed_qty: f64, price: f64) -> f64 {
+        let mut realized = 0.0;
+        if self.amount == 0.0 || self.amount.signum() == signed_qty.signum() {
+            let total = self.amount.abs() + signed_qty.abs();
+            self.entry_price =
+                (self.entry_price * self.amount.abs() + price * signed_qty.abs()) / total;
+            self.amount += signed_qty;
+        } else {
+            let closed = signed_qty.abs().min(self.amount.abs());
+            realized = closed * (price - self.entry_price) * self.amount.signum();
+            self.amount += signed_qty;
+            if self.amount.abs() < 1e-12 {
+                self.amount = 0.0;
+                self.entry_price = 0.0;
+            } else if self.amount.signum() == signed_qty.signum() {
+                // 反向开仓
+                self.entry_price = price;
+            }
+        }
+        self.realized += realized;
+        realized
+    }
+}
+
+fn reject(code: i64, msg: &str) -> APIError {
+    APIError::ClientSideError(serde_json::json!({ "code": code, "msg": msg }).to_string())
+}
+
+fn to_code(err: APIError) -> CodeResponse {
+    match err {
+        APIError::ClientSideError(body) => serde_json::from_str(&body).unwrap_or(CodeResponse {
+            code: -1000,
+            msg: body,
+        }),
+        e => CodeResponse {
+            code: -1000,
+            msg: e.to_string(),
+        },
+    }
+}
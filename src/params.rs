@@ -1,10 +1,51 @@
+use std::marker::PhantomData;
+use std::time::Duration;
 use std::usize;
 
 use crate::enums::{
-    ContractType, FuturesOrderType, Interval, MarginType, OrderSide, PositionDirect, SpotOrderType,
-    TimeInForce,
+    AlgoUrgency, AutoCloseType, CancelReplaceMode, ContractType, ConvertWallet, DepositStatus,
+    EarnAccount, EarnRewardType, FuturesNewOrderType, FuturesOrderType, IncomeType, Interval,
+    IpRestriction, MarginLoanType, MarginType, NewOrderType, OrderSide, PositionDirect,
+    QuoteValidTime, SideEffectType, SpotOrderType, SubAccountType, SubFuturesType, TimeInForce,
+    UniversalTransferType, WithdrawStatus, WorkingType,
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
+
+/// 将参数序列化为 JSON 字符串, 用于批量接口
+fn json_string<T: Serialize, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    let json = serde_json::to_string(value).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_str(&json)
+}
+
+fn f64_as_string<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
+
+/// 杠杆接口的布尔参数为 `TRUE` 或 `FALSE`
+fn option_bool_as_upper<S: Serializer>(
+    value: &Option<bool>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(true) => serializer.serialize_str("TRUE"),
+        Some(false) => serializer.serialize_str("FALSE"),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn option_f64_as_string<S: Serializer>(
+    value: &Option<f64>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(v) => serializer.serialize_str(&v.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn duration_as_secs<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(value.as_secs())
+}
 
 #[derive(Debug, Serialize)]
 pub struct PDepth {
@@ -78,6 +119,12 @@ pub struct PSymbol {
     pub symbol: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PListenKey {
+    pub listen_key: String,
+}
+
 /// - symbol 和 pair 不接受同时发送
 /// - 发送 pair的,返回pair对应所有正在交易的symbol数据
 /// - symbol,pair 都没有发送的,返回所有symbol数据
@@ -205,7 +252,7 @@ pub struct PFuturesOrderSpec {
     /// 触发价, 仅 `STOP`, `STOP_MARKET`, `TAKE_PROFIT`, `TAKE_PROFIT_MARKET` 需要此参数
     pub stop_price: Option<f64>,
     /// `true`, `false`；触发后全部平仓，仅支持`STOP_MARKET`和`TAKE_PROFIT_MARKET`；不与`quantity`合用；自带只平仓效果，不与`reduceOnly` 合用
-    pub close_position: Option<f64>,
+    pub close_position: Option<bool>,
     /// 追踪止损激活价格，仅`TRAILING_STOP_MARKET` 需要此参数, 默认为下单当前市场价格(支持不同`workingType`)
     pub activation_price: Option<f64>,
     /// 追踪止损回调比例，可取值范围[0.1, 5],其中 1代表1% ,仅`TRAILING_STOP_MARKET` 需要此参数
@@ -231,6 +278,43 @@ pub struct PFuturesOrder {
     pub ts: PTimestamp,
 }
 
+/// 带自定义订单号的下单参数, 下单接口在未指定订单号时通过客户端的生成器补全
+pub trait FillClientOrderId {
+    fn fill_client_order_id(&mut self, next_id: impl FnMut() -> String);
+}
+
+impl FillClientOrderId for PFuturesOrder {
+    fn fill_client_order_id(&mut self, mut next_id: impl FnMut() -> String) {
+        self.spec
+            .new_client_order_id
+            .get_or_insert_with(&mut next_id);
+    }
+}
+
+impl FillClientOrderId for PSpotOrder {
+    fn fill_client_order_id(&mut self, mut next_id: impl FnMut() -> String) {
+        self.spec
+            .new_client_order_id
+            .get_or_insert_with(&mut next_id);
+    }
+}
+
+impl FillClientOrderId for PBatchOrder {
+    fn fill_client_order_id(&mut self, mut next_id: impl FnMut() -> String) {
+        for order in self.batch_orders.iter_mut() {
+            order.new_client_order_id.get_or_insert_with(&mut next_id);
+        }
+    }
+}
+
+impl FillClientOrderId for PMarginOrder {
+    fn fill_client_order_id(&mut self, mut next_id: impl FnMut() -> String) {
+        self.spec
+            .new_client_order_id
+            .get_or_insert_with(&mut next_id);
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PSpotOrder {
@@ -258,9 +342,506 @@ pub struct PSpotOrderSpec {
     pub new_order_resp_type: Option<String>,
 }
 
+/// 下单构造器的订单类别
+///
+/// 构造器按订单类别区分类型, 只对有效的订单类别提供对应的链式方法,
+/// 如只有跟踪止损单可以设置激活价格, 无效的组合在编译期报错。
+pub mod order_kind {
+    /// 合约限价单和市价单
+    #[derive(Debug)]
+    pub struct Basic;
+
+    /// 合约止损止盈单
+    #[derive(Debug)]
+    pub struct Conditional;
+
+    /// 合约触发后全部平仓的条件单
+    #[derive(Debug)]
+    pub struct ClosePosition;
+
+    /// 合约跟踪止损单
+    #[derive(Debug)]
+    pub struct TrailingStop;
+
+    /// 现货市价单和止损止盈单, 没有有效方式
+    #[derive(Debug)]
+    pub struct Market;
+
+    /// 有效方式为 `GTC` 的限价类订单, 可以设置冰山单数量或修改有效方式
+    #[derive(Debug)]
+    pub struct Gtc;
+
+    /// 已确定有效方式或冰山单数量的限价类订单
+    #[derive(Debug)]
+    pub struct Limit;
+
+    /// 限价只挂单
+    #[derive(Debug)]
+    pub struct LimitMaker;
+
+    /// 订单列表中的止损止盈单
+    #[derive(Debug)]
+    pub struct Stop;
+
+    /// 订单列表中有效方式为 `GTC` 的限价止损止盈单
+    #[derive(Debug)]
+    pub struct StopGtc;
+
+    /// 订单列表中已确定有效方式或冰山单数量的限价止损止盈单
+    #[derive(Debug)]
+    pub struct StopLimit;
+
+    /// 可以设置只减仓的合约订单
+    pub trait Reducible {}
+
+    impl Reducible for Basic {}
+    impl Reducible for Conditional {}
+    impl Reducible for TrailingStop {}
+
+    /// 可以设置触发价格类型和触发保护的合约条件单
+    pub trait Triggered {}
+
+    impl Triggered for Conditional {}
+    impl Triggered for ClosePosition {}
+
+    /// 可以设置冰山单数量的订单, `Fixed` 为设置后的类别
+    pub trait Iceberg {
+        type Fixed;
+    }
+
+    impl Iceberg for Gtc {
+        type Fixed = Limit;
+    }
+
+    impl Iceberg for LimitMaker {
+        type Fixed = Limit;
+    }
+
+    impl Iceberg for StopGtc {
+        type Fixed = StopLimit;
+    }
+
+    /// 可以设置追踪止损的订单列表止损止盈单
+    pub trait Trailing {}
+
+    impl Trailing for Stop {}
+    impl Trailing for StopGtc {}
+    impl Trailing for StopLimit {}
+}
+
+/// 合约下单参数构造器
+///
+/// 每种订单类型的构造函数只接受币安要求的必填参数, 可选参数通过链式方法设置,
+/// 最后调用 `build` 生成 [`PFuturesOrderSpec`]。
+/// 构造器的类型参数为订单类别, 对订单类型无效的可选参数不能设置。
+///
+/// ```
+/// use bian_rs::enums::{OrderSide, TimeInForce, WorkingType};
+/// use bian_rs::params::FuturesOrderBuilder;
+///
+/// let spec = FuturesOrderBuilder::limit("BTCUSDT", OrderSide::Buy, 0.01, 57000.0, TimeInForce::GTC)
+///     .client_order_id("my_order")
+///     .build();
+/// let stop = FuturesOrderBuilder::close_position_stop_market("BTCUSDT", OrderSide::Sell, 55000.0)
+///     .working_type(WorkingType::MarkPrice)
+///     .build();
+/// assert_eq!(stop.close_position, Some(true));
+/// ```
+///
+/// 市价单不能设置触发价格类型:
+///
+/// ```compile_fail
+/// use bian_rs::enums::{OrderSide, WorkingType};
+/// use bian_rs::params::FuturesOrderBuilder;
+///
+/// FuturesOrderBuilder::market("BTCUSDT", OrderSide::Sell, 0.01)
+///     .working_type(WorkingType::MarkPrice);
+/// ```
+#[derive(Debug)]
+pub struct FuturesOrderBuilder<K> {
+    spec: PFuturesOrderSpec,
+    kind: PhantomData<K>,
+}
+
+impl FuturesOrderBuilder<order_kind::Basic> {
+    /// 限价单
+    pub fn limit(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        price: f64,
+        time_in_force: TimeInForce,
+    ) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::Limit);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.price = Some(price);
+        builder.spec.time_in_force = Some(time_in_force);
+        builder
+    }
+
+    /// 只做 maker 的限价单, 即 `GTX` 限价单
+    pub fn post_only(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self::limit(symbol, side, quantity, price, TimeInForce::GTX)
+    }
+
+    /// 市价单
+    pub fn market(symbol: &str, side: OrderSide, quantity: f64) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::Market);
+        builder.spec.quantity = Some(quantity);
+        builder
+    }
+}
+
+impl FuturesOrderBuilder<order_kind::Conditional> {
+    /// 止损限价单
+    pub fn stop(symbol: &str, side: OrderSide, quantity: f64, price: f64, stop_price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::Stop);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.price = Some(price);
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 止盈限价单
+    pub fn take_profit(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::TakeProfit);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.price = Some(price);
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 止损市价单
+    pub fn stop_market(symbol: &str, side: OrderSide, quantity: f64, stop_price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::StopMarket);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 止盈市价单
+    pub fn take_profit_market(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        stop_price: f64,
+    ) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::TakeProfitMarket);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+}
+
+impl FuturesOrderBuilder<order_kind::ClosePosition> {
+    /// 触发后全部平仓的止损市价单, 自带只减仓效果
+    pub fn close_position_stop_market(symbol: &str, side: OrderSide, stop_price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::StopMarket);
+        builder.spec.stop_price = Some(stop_price);
+        builder.spec.close_position = Some(true);
+        builder
+    }
+
+    /// 触发后全部平仓的止盈市价单, 自带只减仓效果
+    pub fn close_position_take_profit_market(
+        symbol: &str,
+        side: OrderSide,
+        stop_price: f64,
+    ) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::TakeProfitMarket);
+        builder.spec.stop_price = Some(stop_price);
+        builder.spec.close_position = Some(true);
+        builder
+    }
+}
+
+impl FuturesOrderBuilder<order_kind::TrailingStop> {
+    /// 跟踪止损单, `callback_rate` 取值范围 [0.1, 5], 1 代表 1%
+    pub fn trailing_stop_market(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        callback_rate: f64,
+    ) -> Self {
+        let mut builder = Self::new(symbol, side, FuturesOrderType::TrailingStopMarket);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.callback_rate = Some(callback_rate);
+        builder
+    }
+
+    /// 跟踪止损激活价格
+    pub fn activation_price(mut self, activation_price: f64) -> Self {
+        self.spec.activation_price = Some(activation_price);
+        self
+    }
+}
+
+impl<K: order_kind::Reducible> FuturesOrderBuilder<K> {
+    /// 只减仓
+    pub fn reduce_only(mut self) -> Self {
+        self.spec.reduce_only = Some(true);
+        self
+    }
+}
+
+impl<K: order_kind::Triggered> FuturesOrderBuilder<K> {
+    /// 条件单触发价格类型
+    pub fn working_type(mut self, working_type: WorkingType) -> Self {
+        self.spec.working_type = Some(working_type.as_str().to_string());
+        self
+    }
+
+    /// 开启条件单触发保护
+    pub fn price_protect(mut self) -> Self {
+        self.spec.price_protect = Some("TRUE".to_string());
+        self
+    }
+}
+
+impl<K> FuturesOrderBuilder<K> {
+    fn new(symbol: &str, side: OrderSide, order_type: FuturesOrderType) -> Self {
+        Self {
+            spec: PFuturesOrderSpec {
+                symbol: symbol.to_string(),
+                side,
+                position_side: None,
+                order_type,
+                reduce_only: None,
+                quantity: None,
+                price: None,
+                new_client_order_id: None,
+                stop_price: None,
+                close_position: None,
+                activation_price: None,
+                callback_rate: None,
+                time_in_force: None,
+                working_type: None,
+                price_protect: None,
+                new_order_resp_type: None,
+            },
+            kind: PhantomData,
+        }
+    }
+
+    /// 持仓方向, 双向持仓模式下必填
+    pub fn position_side(mut self, position_side: PositionDirect) -> Self {
+        self.spec.position_side = Some(position_side);
+        self
+    }
+
+    /// 自定义订单号
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.spec.new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// 返回类型
+    pub fn resp_type(mut self, resp_type: FuturesNewOrderType) -> Self {
+        self.spec.new_order_resp_type = Some(resp_type.as_str().to_string());
+        self
+    }
+
+    pub fn build(self) -> PFuturesOrderSpec {
+        self.spec
+    }
+}
+
+impl<K> From<FuturesOrderBuilder<K>> for PFuturesOrderSpec {
+    fn from(builder: FuturesOrderBuilder<K>) -> Self {
+        builder.build()
+    }
+}
+
+/// 现货下单参数构造器
+///
+/// 限价类订单的有效方式默认为 `GTC`, 只有 `GTC` 订单和限价只挂单可以设置冰山单数量,
+/// 设置冰山单数量后不能再修改有效方式。
+///
+/// ```
+/// use bian_rs::enums::{OrderSide, TimeInForce};
+/// use bian_rs::params::SpotOrderBuilder;
+///
+/// let spec = SpotOrderBuilder::market_quote("BNBUSDT", OrderSide::Buy, 100.0).build();
+/// assert_eq!(spec.quantity, None);
+/// let spec = SpotOrderBuilder::limit("BNBUSDT", OrderSide::Sell, 1.0, 400.0)
+///     .iceberg_qty(0.2)
+///     .build();
+/// assert_eq!(spec.time_in_force, Some(TimeInForce::GTC));
+/// let spec = SpotOrderBuilder::limit("BNBUSDT", OrderSide::Sell, 1.0, 400.0)
+///     .time_in_force(TimeInForce::IOC)
+///     .build();
+/// ```
+///
+/// `IOC` 订单不能设置冰山单数量:
+///
+/// ```compile_fail
+/// use bian_rs::enums::{OrderSide, TimeInForce};
+/// use bian_rs::params::SpotOrderBuilder;
+///
+/// SpotOrderBuilder::limit("BNBUSDT", OrderSide::Sell, 1.0, 400.0)
+///     .time_in_force(TimeInForce::IOC)
+///     .iceberg_qty(0.2);
+/// ```
+#[derive(Debug)]
+pub struct SpotOrderBuilder<K> {
+    spec: PSpotOrderSpec,
+    kind: PhantomData<K>,
+}
+
+impl SpotOrderBuilder<order_kind::Market> {
+    /// 按数量下市价单
+    pub fn market(symbol: &str, side: OrderSide, quantity: f64) -> Self {
+        let mut builder = Self::new(symbol, side, SpotOrderType::Market);
+        builder.spec.quantity = Some(quantity);
+        builder
+    }
+
+    /// 按成交额下市价单, 如买入 100 USDT 的 BNB
+    pub fn market_quote(symbol: &str, side: OrderSide, quote_order_qty: f64) -> Self {
+        let mut builder = Self::new(symbol, side, SpotOrderType::Market);
+        builder.spec.quote_order_qty = Some(quote_order_qty);
+        builder
+    }
+
+    /// 止损单
+    pub fn stop_loss(symbol: &str, side: OrderSide, quantity: f64, stop_price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, SpotOrderType::StopLoss);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 止盈单
+    pub fn take_profit(symbol: &str, side: OrderSide, quantity: f64, stop_price: f64) -> Self {
+        let mut builder = Self::stop_loss(symbol, side, quantity, stop_price);
+        builder.spec.order_type = SpotOrderType::TakeProfit;
+        builder
+    }
+}
+
+impl SpotOrderBuilder<order_kind::Gtc> {
+    /// 限价单
+    pub fn limit(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, SpotOrderType::Limit);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.price = Some(price);
+        builder.spec.time_in_force = Some(TimeInForce::GTC);
+        builder
+    }
+
+    /// 限价止损单
+    pub fn stop_loss_limit(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        let mut builder = Self::limit(symbol, side, quantity, price);
+        builder.spec.order_type = SpotOrderType::StopLossLimit;
+        builder.spec.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 限价止盈单
+    pub fn take_profit_limit(
+        symbol: &str,
+        side: OrderSide,
+        quantity: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        let mut builder = Self::stop_loss_limit(symbol, side, quantity, price, stop_price);
+        builder.spec.order_type = SpotOrderType::TakeProfitLimit;
+        builder
+    }
+
+    /// 有效方式, 非 `GTC` 的订单不能设置冰山单数量
+    pub fn time_in_force(self, time_in_force: TimeInForce) -> SpotOrderBuilder<order_kind::Limit> {
+        let mut spec = self.spec;
+        spec.time_in_force = Some(time_in_force);
+        SpotOrderBuilder {
+            spec,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl SpotOrderBuilder<order_kind::LimitMaker> {
+    /// 限价只挂单
+    pub fn limit_maker(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        let mut builder = Self::new(symbol, side, SpotOrderType::LimitMaker);
+        builder.spec.quantity = Some(quantity);
+        builder.spec.price = Some(price);
+        builder
+    }
+}
+
+impl<K: order_kind::Iceberg> SpotOrderBuilder<K> {
+    /// 冰山单数量
+    pub fn iceberg_qty(self, iceberg_qty: f64) -> SpotOrderBuilder<K::Fixed> {
+        let mut spec = self.spec;
+        spec.iceberg_qty = Some(iceberg_qty);
+        SpotOrderBuilder {
+            spec,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> SpotOrderBuilder<K> {
+    fn new(symbol: &str, side: OrderSide, order_type: SpotOrderType) -> Self {
+        Self {
+            spec: PSpotOrderSpec {
+                symbol: symbol.to_string(),
+                side,
+                order_type,
+                time_in_force: None,
+                quantity: None,
+                quote_order_qty: None,
+                price: None,
+                new_client_order_id: None,
+                stop_price: None,
+                iceberg_qty: None,
+                new_order_resp_type: None,
+            },
+            kind: PhantomData,
+        }
+    }
+
+    /// 自定义订单号
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.spec.new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// 返回类型
+    pub fn resp_type(mut self, resp_type: NewOrderType) -> Self {
+        self.spec.new_order_resp_type = Some(resp_type.as_str().to_string());
+        self
+    }
+
+    pub fn build(self) -> PSpotOrderSpec {
+        self.spec
+    }
+}
+
+impl<K> From<SpotOrderBuilder<K>> for PSpotOrderSpec {
+    fn from(builder: SpotOrderBuilder<K>) -> Self {
+        builder.build()
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct POcoOrder {
+    #[serde(flatten)]
     pub spec: POcoOrderSpec,
     #[serde(flatten)]
     pub ts: PTimestamp,
@@ -290,42 +871,508 @@ pub struct POcoOrderSpec {
     pub new_order_resp_type: Option<String>,
 }
 
+/// 订单列表中的单个订单
+///
+/// 序列化时字段名会加上订单在列表中的位置作为前缀, 如 OCO 上方订单的价格为 `abovePrice`,
+/// OTOCO 待执行的下方订单类型为 `pendingBelowType`。通过 [`OrderLegBuilder`] 构造。
+#[derive(Debug, Clone)]
+pub struct POrderListLeg {
+    pub order_type: SpotOrderType,
+    pub client_order_id: Option<String>,
+    pub price: Option<f64>,
+    pub stop_price: Option<f64>,
+    /// 追踪止损, 单位为 BIPS
+    pub trailing_delta: Option<usize>,
+    pub iceberg_qty: Option<f64>,
+    pub time_in_force: Option<TimeInForce>,
+}
+
+/// 订单列表的订单构造器
+///
+/// 和 [`SpotOrderBuilder`] 一样, 限价类订单的有效方式默认为 `GTC`,
+/// 追踪止损只能用于止损止盈单。
+///
+/// ```
+/// use bian_rs::enums::TimeInForce;
+/// use bian_rs::params::{OrderLegBuilder, POrderListLeg};
+///
+/// let above: POrderListLeg = OrderLegBuilder::limit_maker(1.5).iceberg_qty(0.5).into();
+/// let below = OrderLegBuilder::stop_loss_limit(1.0, 1.05)
+///     .time_in_force(TimeInForce::IOC)
+///     .trailing_delta(100)
+///     .build();
+/// assert_eq!(below.iceberg_qty, None);
+/// ```
+///
+/// 限价单不能设置追踪止损:
+///
+/// ```compile_fail
+/// use bian_rs::params::OrderLegBuilder;
+///
+/// OrderLegBuilder::limit(1.2).trailing_delta(100);
+/// ```
+#[derive(Debug)]
+pub struct OrderLegBuilder<K> {
+    leg: POrderListLeg,
+    kind: PhantomData<K>,
+}
+
+impl OrderLegBuilder<order_kind::Market> {
+    /// 市价单, 不能用于 OCO
+    pub fn market() -> Self {
+        Self::new(SpotOrderType::Market)
+    }
+}
+
+impl OrderLegBuilder<order_kind::Gtc> {
+    /// 限价单, 不能用于 OCO
+    pub fn limit(price: f64) -> Self {
+        let mut builder = Self::new(SpotOrderType::Limit);
+        builder.leg.price = Some(price);
+        builder.leg.time_in_force = Some(TimeInForce::GTC);
+        builder
+    }
+
+    /// 有效方式, 非 `GTC` 的订单不能设置冰山单数量
+    pub fn time_in_force(self, time_in_force: TimeInForce) -> OrderLegBuilder<order_kind::Limit> {
+        self.with_time_in_force(time_in_force)
+    }
+}
+
+impl OrderLegBuilder<order_kind::LimitMaker> {
+    /// 限价只挂单
+    pub fn limit_maker(price: f64) -> Self {
+        let mut builder = Self::new(SpotOrderType::LimitMaker);
+        builder.leg.price = Some(price);
+        builder
+    }
+}
+
+impl OrderLegBuilder<order_kind::Stop> {
+    /// 止损单
+    pub fn stop_loss(stop_price: f64) -> Self {
+        let mut builder = Self::new(SpotOrderType::StopLoss);
+        builder.leg.stop_price = Some(stop_price);
+        builder
+    }
+
+    /// 止盈单
+    pub fn take_profit(stop_price: f64) -> Self {
+        let mut builder = Self::stop_loss(stop_price);
+        builder.leg.order_type = SpotOrderType::TakeProfit;
+        builder
+    }
+}
+
+impl OrderLegBuilder<order_kind::StopGtc> {
+    /// 限价止损单
+    pub fn stop_loss_limit(price: f64, stop_price: f64) -> Self {
+        let mut builder = Self::new(SpotOrderType::StopLossLimit);
+        builder.leg.price = Some(price);
+        builder.leg.stop_price = Some(stop_price);
+        builder.leg.time_in_force = Some(TimeInForce::GTC);
+        builder
+    }
+
+    /// 限价止盈单
+    pub fn take_profit_limit(price: f64, stop_price: f64) -> Self {
+        let mut builder = Self::stop_loss_limit(price, stop_price);
+        builder.leg.order_type = SpotOrderType::TakeProfitLimit;
+        builder
+    }
+
+    /// 有效方式, 非 `GTC` 的订单不能设置冰山单数量
+    pub fn time_in_force(
+        self,
+        time_in_force: TimeInForce,
+    ) -> OrderLegBuilder<order_kind::StopLimit> {
+        self.with_time_in_force(time_in_force)
+    }
+}
+
+impl<K: order_kind::Iceberg> OrderLegBuilder<K> {
+    /// 冰山单数量
+    pub fn iceberg_qty(self, iceberg_qty: f64) -> OrderLegBuilder<K::Fixed> {
+        let mut leg = self.leg;
+        leg.iceberg_qty = Some(iceberg_qty);
+        OrderLegBuilder {
+            leg,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K: order_kind::Trailing> OrderLegBuilder<K> {
+    /// 追踪止损, 单位为 BIPS
+    pub fn trailing_delta(mut self, trailing_delta: usize) -> Self {
+        self.leg.trailing_delta = Some(trailing_delta);
+        self
+    }
+}
+
+impl<K> OrderLegBuilder<K> {
+    fn new(order_type: SpotOrderType) -> Self {
+        Self {
+            leg: POrderListLeg {
+                order_type,
+                client_order_id: None,
+                price: None,
+                stop_price: None,
+                trailing_delta: None,
+                iceberg_qty: None,
+                time_in_force: None,
+            },
+            kind: PhantomData,
+        }
+    }
+
+    fn with_time_in_force<T>(self, time_in_force: TimeInForce) -> OrderLegBuilder<T> {
+        let mut leg = self.leg;
+        leg.time_in_force = Some(time_in_force);
+        OrderLegBuilder {
+            leg,
+            kind: PhantomData,
+        }
+    }
+
+    /// 自定义订单号
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.leg.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn build(self) -> POrderListLeg {
+        self.leg
+    }
+}
+
+impl<K> From<OrderLegBuilder<K>> for POrderListLeg {
+    fn from(builder: OrderLegBuilder<K>) -> Self {
+        builder.build()
+    }
+}
+
+struct PrefixedLeg<'a>(&'static str, &'a POrderListLeg);
+
+impl Serialize for PrefixedLeg<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let PrefixedLeg(prefix, leg) = self;
+        let key = |name: &str| format!("{}{}", prefix, name);
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(&key("Type"), &leg.order_type)?;
+        if let Some(v) = &leg.client_order_id {
+            map.serialize_entry(&key("ClientOrderId"), v)?;
+        }
+        if let Some(v) = &leg.price {
+            map.serialize_entry(&key("Price"), v)?;
+        }
+        if let Some(v) = &leg.stop_price {
+            map.serialize_entry(&key("StopPrice"), v)?;
+        }
+        if let Some(v) = &leg.trailing_delta {
+            map.serialize_entry(&key("TrailingDelta"), v)?;
+        }
+        if let Some(v) = &leg.iceberg_qty {
+            map.serialize_entry(&key("IcebergQty"), v)?;
+        }
+        if let Some(v) = &leg.time_in_force {
+            map.serialize_entry(&key("TimeInForce"), v)?;
+        }
+        map.end()
+    }
+}
+
+fn above_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("above", leg).serialize(serializer)
+}
+
+fn below_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("below", leg).serialize(serializer)
+}
+
+fn working_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("working", leg).serialize(serializer)
+}
+
+fn pending_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("pending", leg).serialize(serializer)
+}
+
+fn pending_above_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("pendingAbove", leg).serialize(serializer)
+}
+
+fn pending_below_leg<S: Serializer>(leg: &POrderListLeg, serializer: S) -> Result<S::Ok, S::Error> {
+    PrefixedLeg("pendingBelow", leg).serialize(serializer)
+}
+
+/// OCO 订单列表
+///
+/// 上方订单价格高于下方订单, 一个订单成交后另一个订单自动撤销。
+/// 上方订单可以是 `LIMIT_MAKER`, `STOP_LOSS(_LIMIT)` 或 `TAKE_PROFIT(_LIMIT)`, 下方订单相同。
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PBatchOrder {
-    /// 订单列表，最多支持5个订单
-    pub batch_orders: Vec<PFuturesOrderSpec>,
+pub struct POrderListOco {
+    pub symbol: String,
+    /// 整个orderList的唯一ID
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    #[serde(flatten, serialize_with = "above_leg")]
+    pub above: POrderListLeg,
+    #[serde(flatten, serialize_with = "below_leg")]
+    pub below: POrderListLeg,
+    // TODO make it enum
+    pub new_order_resp_type: Option<String>,
     #[serde(flatten)]
     pub ts: PTimestamp,
 }
 
+/// OTO 订单列表
+///
+/// 生效订单 (working) 完全成交后才会挂出待执行订单 (pending)。
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PQueryFuturesOrder {
+pub struct POrderListOto {
     pub symbol: String,
-    pub order_id: Option<usize>,
-    pub orig_client_order_id: Option<String>,
+    /// 整个orderList的唯一ID
+    pub list_client_order_id: Option<String>,
+    pub working_side: OrderSide,
+    pub working_quantity: f64,
+    /// 生效订单, 只能是 `LIMIT` 或 `LIMIT_MAKER`
+    #[serde(flatten, serialize_with = "working_leg")]
+    pub working: POrderListLeg,
+    pub pending_side: OrderSide,
+    pub pending_quantity: f64,
+    #[serde(flatten, serialize_with = "pending_leg")]
+    pub pending: POrderListLeg,
+    // TODO make it enum
+    pub new_order_resp_type: Option<String>,
     #[serde(flatten)]
     pub ts: PTimestamp,
 }
 
+/// OTOCO 订单列表
+///
+/// 生效订单 (working) 完全成交后挂出一组 OCO 待执行订单。
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PQuerySpotOrder {
+pub struct POrderListOtoco {
     pub symbol: String,
-    pub order_id: Option<usize>,
-    pub orig_client_order_id: Option<String>,
-    pub new_client_order_id: Option<String>,
+    /// 整个orderList的唯一ID
+    pub list_client_order_id: Option<String>,
+    pub working_side: OrderSide,
+    pub working_quantity: f64,
+    /// 生效订单, 只能是 `LIMIT` 或 `LIMIT_MAKER`
+    #[serde(flatten, serialize_with = "working_leg")]
+    pub working: POrderListLeg,
+    pub pending_side: OrderSide,
+    pub pending_quantity: f64,
+    #[serde(flatten, serialize_with = "pending_above_leg")]
+    pub pending_above: POrderListLeg,
+    #[serde(flatten, serialize_with = "pending_below_leg")]
+    pub pending_below: POrderListLeg,
+    // TODO make it enum
+    pub new_order_resp_type: Option<String>,
     #[serde(flatten)]
     pub ts: PTimestamp,
 }
 
-#[derive(Debug, Serialize)]
+/// 批量下单中的单个订单, 空字段不发送, 数值与布尔值均以字符串发送
+#[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PQueryAllSpotOrder {
-    pub symbol: String,
-    pub order_id: Option<usize>,
-    pub start_time: Option<i64>,
+struct BatchOrderItem<'a> {
+    symbol: &'a str,
+    side: &'a OrderSide,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position_side: Option<&'a PositionDirect>,
+    #[serde(rename = "type")]
+    order_type: &'a FuturesOrderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reduce_only: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    quantity: Option<f64>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_client_order_id: Option<&'a str>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    stop_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    close_position: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    activation_price: Option<f64>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    callback_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_in_force: Option<&'a TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_type: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price_protect: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_order_resp_type: Option<&'a str>,
+}
+
+impl<'a> From<&'a PFuturesOrderSpec> for BatchOrderItem<'a> {
+    fn from(spec: &'a PFuturesOrderSpec) -> Self {
+        Self {
+            symbol: &spec.symbol,
+            side: &spec.side,
+            position_side: spec.position_side.as_ref(),
+            order_type: &spec.order_type,
+            reduce_only: spec.reduce_only.map(|v| v.to_string()),
+            quantity: spec.quantity,
+            price: spec.price,
+            new_client_order_id: spec.new_client_order_id.as_deref(),
+            stop_price: spec.stop_price,
+            close_position: spec.close_position.map(|v| v.to_string()),
+            activation_price: spec.activation_price,
+            callback_rate: spec.callback_rate,
+            time_in_force: spec.time_in_force.as_ref(),
+            working_type: spec.working_type.as_deref(),
+            price_protect: spec.price_protect.as_deref(),
+            new_order_resp_type: spec.new_order_resp_type.as_deref(),
+        }
+    }
+}
+
+fn batch_order_items<S: Serializer>(
+    orders: &[PFuturesOrderSpec],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let items: Vec<BatchOrderItem> = orders.iter().map(BatchOrderItem::from).collect();
+    json_string(&items, serializer)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PBatchOrder {
+    /// 订单列表，最多支持5个订单
+    #[serde(serialize_with = "batch_order_items")]
+    pub batch_orders: Vec<PFuturesOrderSpec>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 撤消挂单再下单, 撤单的订单通过 `cancel_order_id` 或 `cancel_orig_client_order_id` 指定
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PCancelReplace {
+    /// 新订单参数
+    #[serde(flatten)]
+    pub spec: PSpotOrderSpec,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub cancel_order_id: Option<usize>,
+    pub cancel_orig_client_order_id: Option<String>,
+    /// 撤单请求的自定义ID
+    pub cancel_new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 修改合约限价单, 订单通过 `order_id` 或 `orig_client_order_id` 指定
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PModifyOrderSpec {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+    /// 必须与原订单方向相同
+    pub side: OrderSide,
+    /// U本位合约必填, 币本位合约 `quantity` 与 `price` 至少填写一个
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    pub quantity: Option<f64>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_f64_as_string"
+    )]
+    pub price: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PModifyOrder {
+    #[serde(flatten)]
+    pub spec: PModifyOrderSpec,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PBatchModifyOrder {
+    /// 订单列表, 最多支持5个订单
+    #[serde(serialize_with = "json_string")]
+    pub batch_orders: Vec<PModifyOrderSpec>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POrderAmendment {
+    pub symbol: String,
+    /// `order_id` 与 `orig_client_order_id` 至少填写一个
+    pub order_id: Option<usize>,
+    pub orig_client_order_id: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 默认值:50 最大值:100
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PQueryFuturesOrder {
+    pub symbol: String,
+    pub order_id: Option<usize>,
+    pub orig_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PQuerySpotOrder {
+    pub symbol: String,
+    pub order_id: Option<usize>,
+    pub orig_client_order_id: Option<String>,
+    pub new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PQueryAllSpotOrder {
+    pub symbol: String,
+    pub order_id: Option<usize>,
+    pub start_time: Option<i64>,
     pub end_time: Option<i64>,
     pub limit: Option<usize>,
     #[serde(flatten)]
@@ -476,3 +1523,889 @@ pub struct PUserTrade {
     #[serde(flatten)]
     pub ts: PTimestamp,
 }
+
+#[derive(Debug, Serialize)]
+pub struct POptionalSymbol {
+    /// 不传时返回所有交易对
+    pub symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PUnderlying {
+    /// 标的, 如 BTCUSDT
+    pub underlying: String,
+}
+
+/// 期权下单参数, 目前仅支持限价单
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionsOrderSpec {
+    /// 期权合约, 如 BTC-240628-60000-C
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: FuturesOrderType,
+    #[serde(serialize_with = "f64_as_string")]
+    pub quantity: f64,
+    #[serde(
+        serialize_with = "option_f64_as_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price: Option<f64>,
+    /// 默认 GTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    /// "ACK", "RESULT", 默认 "ACK"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<String>,
+    /// 用户自定义的订单号
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    /// 是否为做市商保护 (MMP) 订单
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mmp: Option<bool>,
+}
+
+impl POptionsOrderSpec {
+    /// 限价单
+    pub fn limit(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            side,
+            order_type: FuturesOrderType::Limit,
+            quantity,
+            price: Some(price),
+            time_in_force: None,
+            reduce_only: None,
+            post_only: None,
+            new_order_resp_type: None,
+            client_order_id: None,
+            is_mmp: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionsOrder {
+    #[serde(flatten)]
+    pub spec: POptionsOrderSpec,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionsBatchOrder {
+    /// 订单列表, 最多支持10个订单
+    #[serde(serialize_with = "json_string")]
+    pub orders: Vec<POptionsOrderSpec>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 期权订单, `order_id` 与 `client_order_id` 必须至少发送一个
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionsQueryOrder {
+    pub symbol: String,
+    pub order_id: Option<usize>,
+    pub client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionsOpenOrders {
+    /// 不传时返回所有期权合约的挂单
+    pub symbol: Option<String>,
+    /// 返回该 orderId 及之后的挂单
+    pub order_id: Option<usize>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 杠杆借款或还款, 逐仓时需要发送 `symbol`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginBorrowRepay {
+    pub asset: String,
+    /// 是否逐仓, 默认全仓
+    #[serde(serialize_with = "option_bool_as_upper")]
+    pub is_isolated: Option<bool>,
+    /// 逐仓交易对
+    pub symbol: Option<String>,
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub loan_type: MarginLoanType,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 杠杆下单, 订单参数与现货相同
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginOrder {
+    #[serde(flatten)]
+    pub spec: PSpotOrderSpec,
+    /// 是否逐仓, 默认全仓
+    #[serde(serialize_with = "option_bool_as_upper")]
+    pub is_isolated: Option<bool>,
+    pub side_effect_type: Option<SideEffectType>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询或撤销杠杆订单, `order_id` 与 `orig_client_order_id` 必须至少发送一个
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginQueryOrder {
+    pub symbol: String,
+    #[serde(serialize_with = "option_bool_as_upper")]
+    pub is_isolated: Option<bool>,
+    pub order_id: Option<usize>,
+    pub orig_client_order_id: Option<String>,
+    /// 撤单请求的自定义ID, 仅撤单时有效
+    pub new_client_order_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginOpenOrders {
+    /// 逐仓时必须发送
+    pub symbol: Option<String>,
+    #[serde(serialize_with = "option_bool_as_upper")]
+    pub is_isolated: Option<bool>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginAllOrders {
+    pub symbol: String,
+    #[serde(serialize_with = "option_bool_as_upper")]
+    pub is_isolated: Option<bool>,
+    /// 返回该 orderId 及之后的订单
+    pub order_id: Option<usize>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 默认值:500 最大值:500
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PIsolatedMarginAccount {
+    /// 逗号分隔的交易对, 最多5个, 不传时返回所有逐仓账户
+    pub symbols: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询最大可借或最大可转出数量
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginMaxAmount {
+    pub asset: String,
+    /// 逐仓交易对, 不传时查询全仓
+    pub isolated_symbol: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMarginInterestHistory {
+    pub asset: Option<String>,
+    /// 逐仓交易对, 不传时查询全仓
+    pub isolated_symbol: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 页码, 从1开始
+    pub current: Option<usize>,
+    /// 每页数量, 默认值:10 最大值:100
+    pub size: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PIsolatedListenKey {
+    pub symbol: String,
+    pub listen_key: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PDepositAddress {
+    pub coin: String,
+    /// 不传时使用默认网络
+    pub network: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询充值历史, 默认查询最近 90 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PDepositHistory {
+    pub coin: Option<String>,
+    pub status: Option<DepositStatus>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub offset: Option<usize>,
+    /// 默认值:1000 最大值:1000
+    pub limit: Option<usize>,
+    /// 充值交易ID
+    pub tx_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 提币申请
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PWithdraw {
+    pub coin: String,
+    /// 自定义提币ID
+    pub withdraw_order_id: Option<String>,
+    /// 不传时使用默认网络
+    pub network: Option<String>,
+    pub address: String,
+    /// 某些币种如 XRP, XMR 需要的备注
+    pub address_tag: Option<String>,
+    pub amount: f64,
+    /// 站内转账时是否由收款方支付手续费
+    pub transaction_fee_flag: Option<bool>,
+    /// 地址备注
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询提币历史, 默认查询最近 90 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PWithdrawHistory {
+    pub coin: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub status: Option<WithdrawStatus>,
+    pub offset: Option<usize>,
+    /// 默认值:1000 最大值:1000
+    pub limit: Option<usize>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 万向划转, 划转到或从逐仓杠杆账户划转时需要发送交易对
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PUniversalTransfer {
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub asset: String,
+    pub amount: f64,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PTransferHistory {
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 页码, 从1开始
+    pub current: Option<usize>,
+    /// 每页数量, 默认值:10 最大值:100
+    pub size: Option<usize>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAssetDetail {
+    /// 不传时返回所有资产
+    pub asset: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 小额资产转换 BNB
+#[derive(Debug)]
+pub struct PDust {
+    /// 需要转换的资产, 发送时每个资产一个 `asset` 参数
+    pub assets: Vec<String>,
+    pub ts: PTimestamp,
+}
+
+impl PDust {
+    /// 编码为 `asset=BTC&asset=ETH&recvWindow=..&timestamp=..`, serde_qs 不支持重复的参数名
+    pub fn to_query(&self) -> String {
+        let mut qs = url::form_urlencoded::Serializer::new(String::new());
+        for asset in self.assets.iter() {
+            qs.append_pair("asset", asset);
+        }
+        let ts = serde_qs::to_string(&self.ts).unwrap();
+        let qs = qs.finish();
+        if qs.is_empty() {
+            ts
+        } else {
+            format!("{}&{}", qs, ts)
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubAccountList {
+    pub email: Option<String>,
+    /// 是否冻结
+    pub is_freeze: Option<bool>,
+    /// 页码, 从1开始
+    pub page: Option<usize>,
+    /// 默认值:1 最大值:200
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 创建虚拟子账户
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PVirtualSubAccount {
+    /// 任意字符串, 用于生成子账户邮箱
+    pub sub_account_string: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubAccountEmail {
+    /// 子账户邮箱
+    pub email: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubSpotSummary {
+    /// 不传时返回所有子账户
+    pub email: Option<String>,
+    /// 页码, 从1开始
+    pub page: Option<usize>,
+    /// 默认值:10 最大值:20
+    pub size: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubFuturesSummary {
+    pub futures_type: SubFuturesType,
+    /// 页码, 从1开始
+    pub page: Option<usize>,
+    /// 默认值:10 最大值:20
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 母子账户万向划转
+///
+/// `from_email` 和 `to_email` 不传时为母账户, 逐仓杠杆账户需要发送 `symbol`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubUniversalTransfer {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    /// 自定义划转ID, 不能重复
+    pub client_tran_id: Option<String>,
+    pub symbol: Option<String>,
+    pub asset: String,
+    pub amount: f64,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubTransferHistory {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub client_tran_id: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 页码, 从1开始
+    pub page: Option<usize>,
+    /// 默认值:500 最大值:500
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubApiKey {
+    /// 子账户邮箱
+    pub email: String,
+    /// 子账户 API Key
+    pub sub_account_api_key: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 设置子账户 API Key 的 IP 限制
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubApiIpRestriction {
+    pub email: String,
+    pub sub_account_api_key: String,
+    pub status: IpRestriction,
+    /// 逗号分隔的 IP 白名单
+    pub ip_address: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 删除子账户 API Key 的白名单 IP
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PSubApiIpList {
+    pub email: String,
+    pub sub_account_api_key: String,
+    /// 逗号分隔的 IP
+    pub ip_address: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 分页参数, 见 [`pagination`](crate::pagination)
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PPage {
+    /// 页码, 从1开始
+    pub current: Option<usize>,
+    /// 每页数量, 默认值:10 最大值:100
+    pub size: Option<usize>,
+}
+
+impl PPage {
+    /// 第一页
+    pub fn first(size: usize) -> Self {
+        Self {
+            current: Some(1),
+            size: Some(size),
+        }
+    }
+
+    /// 下一页
+    pub fn next(self) -> Self {
+        Self {
+            current: Some(self.current.unwrap_or(1) + 1),
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PEarnProductList {
+    /// 不传时返回所有资产
+    pub asset: Option<String>,
+    #[serde(flatten)]
+    pub page: PPage,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 申购活期产品
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PFlexibleSubscribe {
+    pub product_id: String,
+    pub amount: f64,
+    /// 是否自动申购, 默认开启
+    pub auto_subscribe: Option<bool>,
+    /// 默认使用现货账户
+    pub source_account: Option<EarnAccount>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 申购定期产品
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PLockedSubscribe {
+    pub project_id: String,
+    pub amount: f64,
+    /// 到期后是否自动续期, 默认开启
+    pub auto_subscribe: Option<bool>,
+    /// 默认使用现货账户
+    pub source_account: Option<EarnAccount>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 赎回活期产品, `redeem_all` 为 true 时忽略 `amount`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PFlexibleRedeem {
+    pub product_id: String,
+    pub redeem_all: Option<bool>,
+    pub amount: Option<f64>,
+    /// 赎回到现货或资金账户, 默认现货账户
+    pub dest_account: Option<EarnAccount>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 提前赎回定期产品
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PLockedRedeem {
+    pub position_id: usize,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PFlexiblePosition {
+    pub asset: Option<String>,
+    pub product_id: Option<String>,
+    #[serde(flatten)]
+    pub page: PPage,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PLockedPosition {
+    pub asset: Option<String>,
+    pub position_id: Option<usize>,
+    pub project_id: Option<String>,
+    #[serde(flatten)]
+    pub page: PPage,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询活期收益记录, 不传时间时返回最近 30 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PFlexibleRewards {
+    pub product_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    #[serde(rename = "type")]
+    pub reward_type: EarnRewardType,
+    #[serde(flatten)]
+    pub page: PPage,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询定期收益记录, 不传时间时返回最近 30 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PLockedRewards {
+    pub position_id: Option<usize>,
+    pub asset: Option<String>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    #[serde(flatten)]
+    pub page: PPage,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PFlexibleQuota {
+    pub product_id: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PLockedQuota {
+    pub project_id: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 闪兑交易对, `from_asset` 和 `to_asset` 至少传一个
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PConvertPair {
+    pub from_asset: Option<String>,
+    pub to_asset: Option<String>,
+}
+
+/// 闪兑询价, `from_amount` 和 `to_amount` 只能传一个
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PConvertQuote {
+    pub from_asset: String,
+    pub to_asset: String,
+    /// 卖出数量
+    pub from_amount: Option<f64>,
+    /// 买入数量
+    pub to_amount: Option<f64>,
+    /// 默认现货账户
+    pub wallet_type: Option<ConvertWallet>,
+    /// 报价有效时间, 默认 10s
+    pub valid_time: Option<QuoteValidTime>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAcceptQuote {
+    pub quote_id: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询闪兑订单, `order_id` 和 `quote_id` 至少传一个
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PConvertOrderStatus {
+    pub order_id: Option<String>,
+    pub quote_id: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 闪兑交易历史, 查询时间范围最大 30 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PConvertTradeFlow {
+    pub start_time: i64,
+    pub end_time: i64,
+    /// 默认值:100 最大值:1000
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PPortfolioMarginBalance {
+    /// 不传时返回所有资产
+    pub asset: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 将指定资产从合约账户归集到统一账户
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAssetCollection {
+    pub asset: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// U本位合约 TWAP 算法单, 在 `duration` 内按时间平均拆单执行
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PTwapOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    /// 双向持仓模式下必填
+    pub position_side: Option<PositionDirect>,
+    pub quantity: f64,
+    /// 执行时长, 范围 5 分钟到 24 小时, 按秒发送
+    #[serde(serialize_with = "duration_as_secs")]
+    pub duration: Duration,
+    /// 自定义算法单ID, 不传时系统生成
+    pub client_algo_id: Option<String>,
+    pub reduce_only: Option<bool>,
+    /// 限价, 不传时按市价执行
+    pub limit_price: Option<f64>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// U本位合约 VP 算法单, 按市场成交量的一定比例拆单执行
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PVpOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    /// 双向持仓模式下必填
+    pub position_side: Option<PositionDirect>,
+    pub quantity: f64,
+    pub urgency: AlgoUrgency,
+    /// 自定义算法单ID, 不传时系统生成
+    pub client_algo_id: Option<String>,
+    pub reduce_only: Option<bool>,
+    /// 限价, 不传时按市价执行
+    pub limit_price: Option<f64>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAlgoId {
+    pub algo_id: usize,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询历史算法单, 不传时间时返回最近 7 天
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAlgoHistory {
+    pub symbol: Option<String>,
+    pub side: Option<OrderSide>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 页码, 默认值:1
+    pub page: Option<usize>,
+    /// 每页数量, 默认值:100 最大值:100
+    pub page_size: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAlgoSubOrders {
+    pub algo_id: usize,
+    /// 页码, 默认值:1
+    pub page: Option<usize>,
+    /// 每页数量, 默认值:100 最大值:100
+    pub page_size: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 查询资金流水, 不传时间时返回最近 7 天, 查询范围最大 3 个月
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PIncome {
+    pub symbol: Option<String>,
+    /// 不传时返回所有类型
+    pub income_type: Option<IncomeType>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 页码, 从1开始
+    pub page: Option<usize>,
+    /// 默认值:100 最大值:1000
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PUserForceOrders {
+    pub symbol: Option<String>,
+    /// 不传时返回强平和自动减仓订单
+    pub auto_close_type: Option<AutoCloseType>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 默认值:50 最大值:100
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PMultiAssetsMargin {
+    /// "true": 联合保证金模式；"false": 单币保证金模式
+    pub multi_assets_margin: bool,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 生成下载链接的时间范围, 最大 1 年
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PAsyncDownload {
+    pub start_time: i64,
+    pub end_time: i64,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PDownloadId {
+    pub download_id: String,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// 币本位合约资金流水, 不支持页码分页, 需要按时间范围分段查询
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PDFuturesIncome {
+    pub symbol: Option<String>,
+    /// 不传时返回所有类型
+    pub income_type: Option<IncomeType>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    /// 默认值:100 最大值:1000
+    pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct POptionPairQuery {
+    /// 标的交易对, 如 `BTCUSD`
+    pub pair: Option<String>,
+    #[serde(flatten)]
+    pub ts: PTimestamp,
+}
+
+/// - 若无 start_time 和 end_time 限制, 则默认返回当前时间往前的limit值
+/// - 仅支持最近30天的数据
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PBasis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub period: Interval,
+    /// 默认30，最大500
+    pub limit: Option<usize>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+}
@@ -1,11 +1,15 @@
-use std::{collections::HashMap, fmt, usize};
+use std::{collections::HashMap, fmt, str::FromStr, usize};
 
 use crate::enums::{
-    ContractType, FuturesOrderType, MarginType, OcoOrderStatus, OcoStatus, OrderSide, OrderStatus,
-    PositionDirect, SpotOrderType, TimeInForce,
+    AlgoStatus, AlgoType, AlgoUrgency, CancelReplaceResult, ContractType, ConvertStatus,
+    DepositStatus, FuturesOrderType, IncomeType, Interval, IpRestriction, MarginType,
+    OcoOrderStatus, OcoStatus, OptionSide, OptionsOrderStatus, OrderSide, OrderStatus,
+    PortfolioMarginStatus, PositionDirect, SpotOrderType, SubAccountType, TimeInForce,
+    TransferStatus, UniversalTransferType, WithdrawStatus,
 };
+use crate::error::APIError;
 
-use super::{string_as_f64, string_as_usize};
+use super::{string_as_bool, string_as_f64, string_as_i64, string_as_usize};
 use serde::{
     de::{SeqAccess, Unexpected, Visitor},
     Deserialize, Deserializer,
@@ -68,7 +72,7 @@ pub struct RateLimit {
     pub rate_limit_type: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "filterType")]
 pub enum FuturesSymbolFilter {
     #[serde(rename = "PRICE_FILTER")]
@@ -104,8 +108,8 @@ pub enum FuturesSymbolFilter {
     MaxNumAlgoOrders { limit: usize },
     #[serde(rename = "MIN_NOTIONAL")]
     MinNotional {
-        #[serde(deserialize_with = "string_as_usize")]
-        notional: usize,
+        #[serde(deserialize_with = "string_as_f64")]
+        notional: f64,
     },
     #[serde(rename = "PERCENT_PRICE")]
     PercentPrice {
@@ -204,7 +208,7 @@ pub enum SpotSymbolFilter {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CodeResponse {
-    pub code: usize,
+    pub code: i64,
     pub msg: String,
 }
 
@@ -939,10 +943,18 @@ pub struct PositionSide {
 #[serde(rename_all = "camelCase")]
 pub struct FuturesOrder {
     pub client_order_id: String,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 成交量, 仅下单和撤单时返回
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub cum_qty: f64,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 成交量
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 成交金额, 币本位合约和统一账户币本位合约不返回
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub cum_quote: f64,
+    /// 成交金额(标的数量), 仅币本位合约返回
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub cum_base: f64,
     pub order_id: usize,
     #[serde(deserialize_with = "string_as_f64")]
     pub avg_price: f64,
@@ -952,25 +964,37 @@ pub struct FuturesOrder {
     pub price: f64,
     pub reduce_only: bool,
     pub side: OrderSide,
-    pub position_side: PositionSide,
+    pub position_side: PositionDirect,
     pub status: OrderStatus,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 触发价, 统一账户订单不返回
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub stop_price: f64,
-    #[serde(deserialize_with = "string_as_f64")]
-    pub close_position: f64,
+    /// 是否条件全平仓, 统一账户订单不返回
+    #[serde(default)]
+    pub close_position: bool,
     pub symbol: String,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: FuturesOrderType,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 跟踪止损激活价格, 仅`TRAILING_STOP_MARKET` 订单返回此字段
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub activate_price: f64,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 跟踪止损回调比例, 仅`TRAILING_STOP_MARKET` 订单返回此字段
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub price_rate: f64,
     pub update_time: i64,
+    /// 统一账户订单不返回
+    #[serde(default)]
     pub working_type: String,
+    /// 统一账户订单不返回
+    #[serde(default)]
     pub price_protect: bool,
 }
 
+fn no_order_list() -> i64 {
+    -1
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotOrderAck {
@@ -978,7 +1002,8 @@ pub struct SpotOrderAck {
     pub symbol: String,
     /// 系统订单ID
     pub order_id: usize,
-    /// OCO订单ID,否则为-1
+    /// OCO订单ID,否则为-1, 杠杆订单不返回
+    #[serde(default = "no_order_list")]
     pub order_list_id: i64,
     /// 客户自己设置的ID
     pub client_order_id: String,
@@ -993,7 +1018,8 @@ pub struct SpotOrderResult {
     pub symbol: String,
     /// 系统订单ID
     pub order_id: usize,
-    /// OCO订单ID,否则为-1
+    /// OCO订单ID,否则为-1, 杠杆订单不返回
+    #[serde(default = "no_order_list")]
     pub order_list_id: i64,
     /// 客户自己设置的ID
     pub client_order_id: String,
@@ -1029,7 +1055,8 @@ pub struct SpotOrderFull {
     pub symbol: String,
     /// 系统订单ID
     pub order_id: usize,
-    /// OCO订单ID,否则为-1
+    /// OCO订单ID,否则为-1, 杠杆订单不返回
+    #[serde(default = "no_order_list")]
     pub order_list_id: i64,
     /// 客户自己设置的ID
     pub client_order_id: String,
@@ -1079,9 +1106,22 @@ pub struct OrderFill {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum SpotOrder {
-    Ack(SpotOrderAck),
-    Result(SpotOrderResult),
+    // untagged 按顺序尝试, 字段最多的放在最前面
     Full(SpotOrderFull),
+    Result(SpotOrderResult),
+    Ack(SpotOrderAck),
+}
+
+/// 智能订单路由 (SOR) 下单, 返回类型固定为 `FULL`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SorOrder {
+    #[serde(flatten)]
+    pub order: SpotOrderFull,
+    /// 订单是否经过 SOR 路由
+    pub used_sor: bool,
+    /// 订单所在的撮合场所, `EXCHANGE` 或 `SOR`
+    pub working_floor: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1182,6 +1222,77 @@ pub enum BatchOrderResponse {
     Code(CodeResponse),
 }
 
+/// 撤单或下单的返回值, 失败时为错误码
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum OrderResponse<T> {
+    Order(T),
+    Code(CodeResponse),
+}
+
+/// 撤消挂单再下单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotCancelReplace {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    /// 撤单返回值
+    pub cancel_response: OrderResponse<SpotOpOrder>,
+    /// 下单返回值, 未尝试下单时为 `None`
+    #[serde(default)]
+    pub new_order_response: Option<OrderResponse<SpotOrder>>,
+}
+
+impl SpotCancelReplace {
+    /// 撤单或下单失败时接口返回 4xx 错误, 撤单和下单结果在错误信息的 `data` 字段中
+    pub fn from_error(err: &APIError) -> Option<Self> {
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            data: SpotCancelReplace,
+        }
+        match err {
+            APIError::ClientSideError(body) => {
+                serde_json::from_str::<ErrorBody>(body).ok().map(|b| b.data)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 合约订单修改前后的值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendmentChange {
+    #[serde(deserialize_with = "string_as_f64")]
+    pub before: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub after: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendmentChange,
+    pub orig_qty: AmendmentChange,
+    /// 订单修改次数
+    pub count: usize,
+}
+
+/// 合约订单修改历史
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: usize,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: usize,
+    pub client_order_id: String,
+    /// 修改时间
+    pub time: i64,
+    pub amendment: Amendment,
+}
+
+/// 订单列表, OCO, OTO 和 OTOCO 接口返回相同结构
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OcoOrder {
@@ -1224,9 +1335,11 @@ pub struct OcoOrderReport {
     pub cummulative_quote_qty: f64,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
     pub order_type: SpotOrderType,
     pub side: OrderSide,
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 非止损止盈单没有止损价格, 为 0
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub stop_price: f64,
 }
 
@@ -1426,7 +1539,7 @@ pub struct PositionMarginHist {
     /// 调整方向 1: 增加逐仓保证金，2: 减少逐仓保证金
     #[serde(rename = "type")]
     pub margin_type: usize,
-    pub position_side: PositionSide,
+    pub position_side: PositionDirect,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1438,6 +1551,7 @@ pub struct PositionRisk {
     // 逐仓模式或全仓模式
     pub margin_type: MarginType,
 
+    #[serde(deserialize_with = "string_as_bool")]
     pub is_auto_add_margin: bool,
     // 逐仓保证金
     #[serde(deserialize_with = "string_as_f64")]
@@ -1451,8 +1565,8 @@ pub struct PositionRisk {
     // 当前标记价格
     #[serde(deserialize_with = "string_as_f64")]
     pub mark_price: f64,
-    // 当前杠杆倍数允许的名义价值上限
-    #[serde(deserialize_with = "string_as_f64")]
+    // 当前杠杆倍数允许的名义价值上限, 币本位合约不返回此字段
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub max_notional_value: f64,
     // 头寸数量，符号代表多空方向, 正数为多，负数为空
     #[serde(deserialize_with = "string_as_f64")]
@@ -1463,45 +1577,45 @@ pub struct PositionRisk {
     #[serde(deserialize_with = "string_as_f64")]
     pub un_realized_profit: f64,
     // 持仓方向
-    pub position_side: PositionSide,
+    pub position_side: PositionDirect,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserFuturesTrade {
     /// 是否是买方
-    buyer: bool,
+    pub buyer: bool,
     /// 手续费
     #[serde(deserialize_with = "string_as_f64")]
-    commission: f64,
+    pub commission: f64,
     /// 手续费计价单位
-    commission_asset: String,
+    pub commission_asset: String,
     /// 交易ID
-    id: usize,
+    pub id: usize,
     /// 是否是挂单方
-    maker: bool,
+    pub maker: bool,
     /// 订单编号
-    order_id: usize,
+    pub order_id: usize,
     /// 成交价
     #[serde(deserialize_with = "string_as_f64")]
-    price: f64,
+    pub price: f64,
     /// 成交量
     #[serde(deserialize_with = "string_as_f64")]
-    qty: f64,
-    /// 成交额
-    #[serde(deserialize_with = "string_as_f64")]
-    quote_qty: f64,
+    pub qty: f64,
+    /// 成交额, 币本位合约不返回此字段
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub quote_qty: f64,
     /// 实现盈亏
     #[serde(deserialize_with = "string_as_f64")]
-    realized_pnl: f64,
+    pub realized_pnl: f64,
     /// 买卖方向
-    side: OrderSide,
+    pub side: OrderSide,
     /// 持仓方向
-    position_side: PositionSide,
+    pub position_side: PositionDirect,
     /// 交易对
-    symbol: String,
+    pub symbol: String,
     /// 时间
-    time: i64,
+    pub time: i64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1531,3 +1645,1793 @@ pub struct UserSpotTrade {
 pub struct ListenKey {
     pub listen_key: String,
 }
+
+/// 期权合约, 格式为 `标的资产-到期日-行权价-类型`, 如 `BTC-240628-60000-C`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    /// 标的资产, 如 BTC
+    pub base_asset: String,
+    /// 到期日, 合约在到期日 08:00 (UTC) 行权
+    pub expiry: chrono::NaiveDate,
+    /// 行权价
+    pub strike: f64,
+    pub side: OptionSide,
+}
+
+impl OptionSymbol {
+    /// 行权时间戳, 单位毫秒
+    pub fn expiry_time(&self) -> i64 {
+        self.expiry
+            .and_hms_opt(8, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = APIError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || APIError::DecodeError(format!("invalid option symbol {}", s));
+        let parts: Vec<&str> = s.split('-').collect();
+        if parts.len() != 4 || parts[0].is_empty() {
+            return Err(invalid());
+        }
+        let expiry =
+            chrono::NaiveDate::parse_from_str(parts[1], "%y%m%d").map_err(|_| invalid())?;
+        let strike = parts[2].parse::<f64>().map_err(|_| invalid())?;
+        let side = match parts[3] {
+            "C" => OptionSide::Call,
+            "P" => OptionSide::Put,
+            _ => return Err(invalid()),
+        };
+        Ok(Self {
+            base_asset: parts[0].to_string(),
+            expiry,
+            strike,
+            side,
+        })
+    }
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.side {
+            OptionSide::Call => "C",
+            OptionSide::Put => "P",
+        };
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            self.base_asset,
+            self.expiry.format("%y%m%d"),
+            self.strike,
+            side
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionSymbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsExchangeInfo {
+    pub timezone: String,
+    pub server_time: i64,
+    pub option_contracts: Vec<OptionsContract>,
+    pub option_assets: Vec<OptionsAsset>,
+    pub option_symbols: Vec<OptionsSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+/// 期权标的
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    /// 标的, 如 BTCUSDT
+    pub underlying: String,
+    /// 结算资产
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsSymbol {
+    /// 期权合约
+    pub symbol: String,
+    /// 标的
+    pub underlying: String,
+    pub side: OptionSide,
+    /// 行权价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub strike_price: f64,
+    /// 行权时间
+    pub expiry_date: i64,
+    /// 合约乘数
+    pub unit: usize,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub maker_fee_rate: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub taker_fee_rate: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub min_qty: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub max_qty: f64,
+    /// 初始保证金率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub initial_margin: f64,
+    /// 维持保证金率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub maintenance_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub min_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub min_maintenance_margin: f64,
+    /// 价格小数点位数
+    pub price_scale: usize,
+    /// 数量小数点位数
+    pub quantity_scale: usize,
+    pub quote_asset: String,
+    pub filters: Vec<FuturesSymbolFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsDepth {
+    /// 撮合引擎时间
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// 更新ID
+    #[serde(rename = "u")]
+    pub update_id: usize,
+    /// 买单
+    pub bids: Vec<DepthOrder>,
+    /// 卖单
+    pub asks: Vec<DepthOrder>,
+}
+
+/// 期权K线, 与现货和合约不同, 接口返回的是对象而不是数组
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsKline {
+    pub interval: Interval,
+    pub open_time: i64,
+    pub close_time: i64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub open: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub high: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub low: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub close: f64,
+    /// 成交量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub volume: f64,
+    /// 成交额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 成交笔数
+    pub trade_count: usize,
+    /// 主动买入成交量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub taker_volume: f64,
+    /// 主动买入成交额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub taker_amount: f64,
+}
+
+/// 期权标记价格和希腊值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsMark {
+    pub symbol: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub mark_price: f64,
+    /// 买一隐含波动率
+    #[serde(rename = "bidIV", deserialize_with = "string_as_f64")]
+    pub bid_iv: f64,
+    /// 卖一隐含波动率
+    #[serde(rename = "askIV", deserialize_with = "string_as_f64")]
+    pub ask_iv: f64,
+    /// 标记价格隐含波动率
+    #[serde(rename = "markIV", deserialize_with = "string_as_f64")]
+    pub mark_iv: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub delta: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub theta: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub gamma: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub vega: f64,
+    /// 当前价格上限
+    #[serde(deserialize_with = "string_as_f64")]
+    pub high_price_limit: f64,
+    /// 当前价格下限
+    #[serde(deserialize_with = "string_as_f64")]
+    pub low_price_limit: f64,
+    /// 无风险利率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub risk_free_interest: f64,
+}
+
+/// 标的指数价格
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsIndex {
+    pub time: i64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub index_price: f64,
+}
+
+/// 期权订单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsOrder {
+    pub order_id: usize,
+    pub symbol: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub price: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 成交数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 手续费
+    #[serde(deserialize_with = "string_as_f64")]
+    pub fee: f64,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: FuturesOrderType,
+    pub time_in_force: TimeInForce,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: i64,
+    pub update_time: i64,
+    pub status: OptionsOrderStatus,
+    /// 成交均价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub avg_price: f64,
+    pub client_order_id: String,
+    pub price_scale: usize,
+    pub quantity_scale: usize,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    /// 是否为做市商保护 (MMP) 订单
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+/// 期权下单 `ACK` 返回值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsOrderAck {
+    pub order_id: usize,
+    pub symbol: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub price: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: FuturesOrderType,
+    pub create_date: i64,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+/// 期权下单返回值, 由下单参数 `new_order_resp_type` 决定
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum NewOptionsOrder {
+    // untagged 按顺序尝试, 字段最多的放在最前面
+    Result(OptionsOrder),
+    Ack(OptionsOrderAck),
+}
+
+/// 期权持仓
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsPosition {
+    pub symbol: String,
+    /// 持仓方向, LONG 或 SHORT
+    pub side: PositionDirect,
+    /// 持仓数量, 空仓为负数
+    #[serde(deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 可平仓数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub reducible_qty: f64,
+    /// 开仓均价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub entry_price: f64,
+    /// 持仓价值
+    #[serde(deserialize_with = "string_as_f64")]
+    pub mark_value: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub mark_price: f64,
+    /// 收益率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub ror: f64,
+    #[serde(rename = "unrealizedPNL", deserialize_with = "string_as_f64")]
+    pub unrealized_pnl: f64,
+    /// 持仓成本
+    #[serde(deserialize_with = "string_as_f64")]
+    pub position_cost: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub strike_price: f64,
+    /// 行权时间
+    pub expiry_date: i64,
+    pub price_scale: usize,
+    pub quantity_scale: usize,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+/// 期权账户
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsAccount {
+    #[serde(rename = "asset")]
+    pub assets: Vec<OptionsBalance>,
+    #[serde(rename = "greek")]
+    pub greeks: Vec<OptionsGreek>,
+    pub time: i64,
+    /// 账户风险等级, NORMAL, MEDIUM, HIGH
+    pub risk_level: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsBalance {
+    pub asset: String,
+    /// 账户余额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub margin_balance: f64,
+    /// 账户权益
+    #[serde(deserialize_with = "string_as_f64")]
+    pub equity: f64,
+    /// 可用保证金
+    #[serde(deserialize_with = "string_as_f64")]
+    pub available: f64,
+    /// 冻结保证金
+    #[serde(deserialize_with = "string_as_f64")]
+    pub locked: f64,
+    #[serde(rename = "unrealizedPNL", deserialize_with = "string_as_f64")]
+    pub unrealized_pnl: f64,
+}
+
+/// 按标的汇总的希腊值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsGreek {
+    pub underlying: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub delta: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub gamma: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub theta: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub vega: f64,
+}
+
+/// 杠杆借款或还款的交易ID
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransaction {
+    pub tran_id: usize,
+}
+
+/// 杠杆撤单结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginOpOrder {
+    /// 交易对
+    pub symbol: String,
+    /// 是否逐仓
+    #[serde(default)]
+    pub is_isolated: bool,
+    pub orig_client_order_id: String,
+    /// 系统订单ID
+    pub order_id: usize,
+    /// 客户自己设置的ID
+    pub client_order_id: String,
+    /// 订单价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 用户设置的原始订单数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub orig_qty: f64,
+    /// 交易的订单数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 累计交易的金额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cummulative_quote_qty: f64,
+    /// 订单状态
+    pub status: OrderStatus,
+    /// 订单的时效方式
+    pub time_in_force: TimeInForce,
+    /// 订单类型
+    #[serde(rename = "type")]
+    pub order_type: SpotOrderType,
+    /// 订单方向
+    pub side: OrderSide,
+}
+
+/// 杠杆订单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryMarginOrder {
+    /// 交易对
+    pub symbol: String,
+    /// 是否逐仓
+    #[serde(default)]
+    pub is_isolated: bool,
+    /// 系统订单ID
+    pub order_id: usize,
+    /// 客户自己设置的ID
+    pub client_order_id: String,
+    /// 订单价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 用户设置的原始订单数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub orig_qty: f64,
+    /// 交易的订单数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 累计交易的金额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cummulative_quote_qty: f64,
+    /// 订单状态
+    pub status: OrderStatus,
+    /// 订单的时效方式
+    pub time_in_force: TimeInForce,
+    /// 订单类型
+    #[serde(rename = "type")]
+    pub order_type: SpotOrderType,
+    /// 订单方向
+    pub side: OrderSide,
+    /// 止损价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub stop_price: f64,
+    /// 冰山数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub iceberg_qty: f64,
+    /// 订单时间
+    pub time: i64,
+    /// 最后更新时间
+    pub update_time: i64,
+    /// 订单是否出现的 order book 中
+    pub is_working: bool,
+}
+
+/// 全仓杠杆账户
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccount {
+    /// 是否可以借款
+    pub borrow_enabled: bool,
+    /// 是否可以交易
+    pub trade_enabled: bool,
+    /// 是否可以划转
+    #[serde(default)]
+    pub transfer_enabled: bool,
+    /// 风险率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub margin_level: f64,
+    /// 总资产, 以 BTC 计价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_asset_of_btc: f64,
+    /// 总负债, 以 BTC 计价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_liability_of_btc: f64,
+    /// 净资产, 以 BTC 计价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_net_asset_of_btc: f64,
+    pub user_assets: Vec<MarginUserAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginUserAsset {
+    pub asset: String,
+    /// 已借数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub borrowed: f64,
+    /// 可用数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub free: f64,
+    /// 未还利息
+    #[serde(deserialize_with = "string_as_f64")]
+    pub interest: f64,
+    /// 冻结数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub locked: f64,
+    /// 净资产
+    #[serde(deserialize_with = "string_as_f64")]
+    pub net_asset: f64,
+}
+
+/// 逐仓杠杆账户
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAccount {
+    pub assets: Vec<IsolatedMarginSymbol>,
+    /// 总资产, 以 BTC 计价, 指定交易对查询时为 0
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub total_asset_of_btc: f64,
+    /// 总负债, 以 BTC 计价, 指定交易对查询时为 0
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub total_liability_of_btc: f64,
+    /// 净资产, 以 BTC 计价, 指定交易对查询时为 0
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub total_net_asset_of_btc: f64,
+}
+
+/// 逐仓交易对
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginSymbol {
+    pub symbol: String,
+    pub base_asset: IsolatedMarginAsset,
+    pub quote_asset: IsolatedMarginAsset,
+    /// 是否已创建逐仓账户
+    pub isolated_created: bool,
+    /// 账户是否启用
+    #[serde(default)]
+    pub enabled: bool,
+    /// 风险率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub margin_level: f64,
+    /// 风险率状态, `EXCESSIVE`, `NORMAL`, `MARGIN_CALL`, `PRE_LIQUIDATION` 或 `FORCE_LIQUIDATION`
+    pub margin_level_status: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub margin_ratio: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub index_price: f64,
+    /// 强平价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub liquidate_price: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub liquidate_rate: f64,
+    pub trade_enabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginAsset {
+    pub asset: String,
+    pub borrow_enabled: bool,
+    pub repay_enabled: bool,
+    /// 已借数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub borrowed: f64,
+    /// 可用数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub free: f64,
+    /// 未还利息
+    #[serde(deserialize_with = "string_as_f64")]
+    pub interest: f64,
+    /// 冻结数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub locked: f64,
+    /// 净资产
+    #[serde(deserialize_with = "string_as_f64")]
+    pub net_asset: f64,
+    /// 净资产, 以 BTC 计价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub net_asset_of_btc: f64,
+    /// 总资产
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_asset: f64,
+}
+
+/// 最大可借数量
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    /// 账户当前可借数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 平台借款限额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub borrow_limit: f64,
+}
+
+/// 最大可转出数量
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+}
+
+/// 分页查询结果, 页码参数见 [`PPage`](crate::params::PPage)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    /// 当前页的记录, 没有记录时不返回
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    /// 记录总数
+    pub total: usize,
+}
+
+/// 利息历史
+pub type MarginInterestHistory = Page<MarginInterest>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterest {
+    pub tx_id: usize,
+    /// 计息时间
+    #[serde(rename = "interestAccuredTime")]
+    pub interest_accrued_time: i64,
+    pub asset: String,
+    /// 本金
+    #[serde(deserialize_with = "string_as_f64")]
+    pub principal: f64,
+    /// 利息
+    #[serde(deserialize_with = "string_as_f64")]
+    pub interest: f64,
+    /// 日利率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub interest_rate: f64,
+    /// 计息类型, `PERIODIC`, `ON_BORROW`, `PERIODIC_CONVERTED` 或 `ON_BORROW_CONVERTED`
+    #[serde(rename = "type")]
+    pub interest_type: String,
+    /// 逐仓交易对, 全仓时不返回
+    pub isolated_symbol: Option<String>,
+}
+
+/// 币种信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfo {
+    pub coin: String,
+    pub name: String,
+    /// 是否可以充值
+    pub deposit_all_enable: bool,
+    /// 是否可以提现
+    pub withdraw_all_enable: bool,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub free: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub locked: f64,
+    /// 冻结数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub freeze: f64,
+    /// 提现中数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdrawing: f64,
+    pub is_legal_money: bool,
+    pub trading: bool,
+    pub network_list: Vec<CoinNetwork>,
+}
+
+/// 币种支持的网络
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinNetwork {
+    pub network: String,
+    pub coin: String,
+    pub name: String,
+    /// 是否为默认网络
+    pub is_default: bool,
+    pub deposit_enable: bool,
+    pub withdraw_enable: bool,
+    /// 网络是否拥堵
+    #[serde(default)]
+    pub busy: bool,
+    /// 上账所需的最小确认数
+    pub min_confirm: usize,
+    /// 解锁所需的确认数
+    pub un_lock_confirm: usize,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdraw_fee: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdraw_min: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdraw_max: f64,
+    /// 提现数量需要为该值的整数倍
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdraw_integer_multiple: f64,
+    #[serde(default)]
+    pub deposit_desc: String,
+    #[serde(default)]
+    pub withdraw_desc: String,
+}
+
+/// 充值地址
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddress {
+    pub address: String,
+    pub coin: String,
+    /// 地址备注, 没有时为空字符串
+    pub tag: String,
+    /// 区块浏览器链接
+    pub url: String,
+}
+
+/// 充值记录
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: i64,
+    /// 0: 站外转账, 1: 站内转账
+    pub transfer_type: u8,
+    /// 确认数, 如 `12/12`
+    pub confirm_times: String,
+}
+
+/// 提币申请ID
+#[derive(Debug, Deserialize)]
+pub struct WithdrawId {
+    pub id: String,
+}
+
+/// 提币记录
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 手续费
+    #[serde(deserialize_with = "string_as_f64")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    /// 提现交易ID, 未上链时不返回
+    #[serde(default)]
+    pub tx_id: String,
+    /// 申请时间, UTC 时间, 格式为 `2019-10-12 11:12:02`
+    pub apply_time: String,
+    pub network: String,
+    /// 0: 站外转账, 1: 站内转账
+    pub transfer_type: u8,
+    /// 自定义提币ID
+    pub withdraw_order_id: Option<String>,
+    /// 提币失败原因
+    #[serde(default)]
+    pub info: String,
+    /// 提现确认数
+    #[serde(default)]
+    pub confirm_no: usize,
+}
+
+/// 划转交易ID
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    pub tran_id: usize,
+}
+
+/// 万向划转历史
+pub type TransferHistory = Page<TransferRecord>;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub status: TransferStatus,
+    pub tran_id: usize,
+    pub timestamp: i64,
+}
+
+/// 资产充提详情
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDetail {
+    /// 最小提现数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub min_withdraw_amount: f64,
+    /// 是否可以充值
+    pub deposit_status: bool,
+    /// 提现手续费
+    pub withdraw_fee: f64,
+    /// 是否可以提现
+    pub withdraw_status: bool,
+    /// 暂停充值的原因
+    pub deposit_tip: Option<String>,
+}
+
+/// 交易手续费率
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    pub symbol: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub maker_commission: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub taker_commission: f64,
+}
+
+/// 可以转换为 BNB 的小额资产
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    pub details: Vec<DustAsset>,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_transfer_btc: f64,
+    #[serde(rename = "totalTransferBNB", deserialize_with = "string_as_f64")]
+    pub total_transfer_bnb: f64,
+    /// 手续费率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub dribblet_percentage: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    pub asset: String,
+    pub asset_full_name: String,
+    /// 可转换数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount_free: f64,
+    /// 折合 BTC
+    #[serde(rename = "toBTC", deserialize_with = "string_as_f64")]
+    pub to_btc: f64,
+    /// 折合 BNB, 未扣除手续费
+    #[serde(rename = "toBNB", deserialize_with = "string_as_f64")]
+    pub to_bnb: f64,
+    /// 折合 BNB, 已扣除手续费
+    #[serde(rename = "toBNBOffExchange", deserialize_with = "string_as_f64")]
+    pub to_bnb_off_exchange: f64,
+    /// 手续费
+    #[serde(deserialize_with = "string_as_f64")]
+    pub exchange: f64,
+}
+
+/// 小额资产转换结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustResult {
+    /// 总手续费
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_service_charge: f64,
+    /// 转换得到的 BNB 总数
+    #[serde(rename = "totalTransfered", deserialize_with = "string_as_f64")]
+    pub total_transferred: f64,
+    pub transfer_result: Vec<DustTransfer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransfer {
+    pub tran_id: usize,
+    pub from_asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 手续费
+    #[serde(deserialize_with = "string_as_f64")]
+    pub service_charge_amount: f64,
+    /// 转换得到的 BNB
+    #[serde(rename = "transferedAmount", deserialize_with = "string_as_f64")]
+    pub transferred_amount: f64,
+    pub operate_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountList {
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+/// 子账户
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    /// 是否冻结
+    pub is_freeze: bool,
+    pub create_time: i64,
+    /// 是否为托管子账户
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    /// 是否为资管子账户
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+/// 新建的虚拟子账户
+#[derive(Debug, Deserialize)]
+pub struct VirtualSubAccount {
+    pub email: String,
+}
+
+/// 子账户现货资产
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssets {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub free: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub locked: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub freeze: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub withdrawing: f64,
+}
+
+/// 子账户现货资产汇总, 以 BTC 计价
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubSpotSummary {
+    pub total_count: usize,
+    /// 母账户总资产
+    #[serde(deserialize_with = "string_as_f64")]
+    pub master_account_total_asset: f64,
+    #[serde(rename = "spotSubUserAssetBtcVoList")]
+    pub sub_accounts: Vec<SubSpotAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubSpotAsset {
+    pub email: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_asset: f64,
+}
+
+/// 子账户合约资产汇总, 按查询的合约类型返回其中一个字段
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubFuturesSummary {
+    /// U本位合约
+    #[serde(rename = "futureAccountSummaryResp")]
+    pub usd_futures: Option<SubUsdFuturesSummary>,
+    /// 币本位合约
+    #[serde(rename = "deliveryAccountSummaryResp")]
+    pub coin_futures: Option<SubCoinFuturesSummary>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubUsdFuturesSummary {
+    /// 计价资产
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_maintenance_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_margin_balance: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_position_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_unrealized_profit: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_wallet_balance: f64,
+    pub sub_account_list: Vec<SubUsdFuturesAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubUsdFuturesAccount {
+    pub email: String,
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_maintenance_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_margin_balance: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_position_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_unrealized_profit: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_wallet_balance: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubCoinFuturesSummary {
+    /// 计价资产
+    pub asset: String,
+    #[serde(rename = "totalMarginBalanceOfBTC", deserialize_with = "string_as_f64")]
+    pub total_margin_balance_of_btc: f64,
+    #[serde(
+        rename = "totalUnrealizedProfitOfBTC",
+        deserialize_with = "string_as_f64"
+    )]
+    pub total_unrealized_profit_of_btc: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", deserialize_with = "string_as_f64")]
+    pub total_wallet_balance_of_btc: f64,
+    pub sub_account_list: Vec<SubCoinFuturesAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubCoinFuturesAccount {
+    pub email: String,
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_margin_balance: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_unrealized_profit: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_wallet_balance: f64,
+}
+
+/// 母子账户万向划转结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubTransferResult {
+    pub tran_id: usize,
+    /// 自定义划转ID, 未设置时为空字符串
+    #[serde(default)]
+    pub client_tran_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubTransferHistory {
+    pub result: Vec<SubTransferRecord>,
+    pub total_count: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubTransferRecord {
+    pub tran_id: usize,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    pub create_time_stamp: i64,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    /// 划转状态, 如 `SUCCESS`
+    pub status: String,
+    #[serde(default)]
+    pub client_tran_id: String,
+}
+
+/// 子账户 API Key 的 IP 限制
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubApiIpRestriction {
+    pub status: IpRestriction,
+    /// IP 白名单
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: i64,
+    pub api_key: String,
+}
+
+/// 子账户合约或杠杆开通状态
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountEnabled {
+    pub email: String,
+    #[serde(alias = "isFuturesEnabled", alias = "isMarginEnabled")]
+    pub enabled: bool,
+}
+
+/// 活期理财产品
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub product_id: String,
+    pub asset: String,
+    /// 最新年化收益率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub latest_annual_percentage_rate: f64,
+    /// 阶梯年化收益率, 如 `"0-5BTC": 0.05`
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    /// 空投年化收益率
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub air_drop_percentage_rate: f64,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    /// 最小申购数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub min_purchase_amount: f64,
+    pub subscription_start_time: i64,
+    /// 产品状态, 如 `PURCHASING`
+    pub status: String,
+}
+
+/// 定期理财产品
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    /// 收益资产
+    pub reward_asset: String,
+    /// 锁定天数
+    pub duration: usize,
+    /// 是否可以续期
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    /// 年化收益率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub apr: f64,
+    /// 产品状态, 如 `CREATED`
+    pub status: String,
+    pub subscription_start_time: i64,
+    /// 额外奖励资产
+    pub extra_reward_asset: Option<String>,
+    /// 额外奖励年化收益率
+    #[serde(rename = "extraRewardAPR", default, deserialize_with = "string_as_f64")]
+    pub extra_reward_apr: f64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    /// 个人申购额度
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_personal_quota: f64,
+    /// 最小申购数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub minimum: f64,
+}
+
+/// 申购结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnSubscribeResult {
+    pub purchase_id: usize,
+    /// 定期产品的持仓ID, 活期产品不返回
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+/// 赎回结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedeemResult {
+    pub redeem_id: usize,
+    pub success: bool,
+}
+
+/// 活期理财持仓
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    pub product_id: String,
+    pub asset: String,
+    /// 持仓数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_amount: f64,
+    /// 最新年化收益率
+    #[serde(deserialize_with = "string_as_f64")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    pub can_redeem: bool,
+    /// 作为抵押品的数量
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub collateral_amount: f64,
+    /// 昨日实时收益
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub yesterday_real_time_rewards: f64,
+    /// 累计额外奖励
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub cumulative_bonus_rewards: f64,
+    /// 累计实时收益
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub cumulative_real_time_rewards: f64,
+    /// 累计总收益
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub cumulative_total_rewards: f64,
+    pub auto_subscribe: bool,
+}
+
+/// 定期理财持仓
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    pub position_id: usize,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 申购时间
+    #[serde(deserialize_with = "string_as_i64")]
+    pub purchase_time: i64,
+    /// 锁定天数
+    #[serde(deserialize_with = "string_as_usize")]
+    pub duration: usize,
+    /// 已计息天数
+    #[serde(deserialize_with = "string_as_usize")]
+    pub accrual_days: usize,
+    pub reward_asset: String,
+    #[serde(rename = "APY", deserialize_with = "string_as_f64")]
+    pub apy: f64,
+    /// 已发放收益
+    #[serde(deserialize_with = "string_as_f64")]
+    pub reward_amt: f64,
+    /// 到期时间
+    #[serde(deserialize_with = "string_as_i64")]
+    pub rewards_end_date: i64,
+    /// 本金到账时间
+    #[serde(deserialize_with = "string_as_i64")]
+    pub deliver_date: i64,
+    /// 提前赎回可得数量
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub redeem_amount_early: f64,
+    pub can_redeem_early: bool,
+    pub auto_subscribe: bool,
+    /// 持仓状态, 如 `HOLDING`, `REDEEMING`
+    pub status: String,
+}
+
+/// 活期理财收益记录
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleReward {
+    pub asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub rewards: f64,
+    pub project_id: String,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: i64,
+}
+
+/// 定期理财收益记录
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedReward {
+    pub position_id: usize,
+    pub time: i64,
+    pub asset: String,
+    /// 锁定天数
+    #[serde(deserialize_with = "string_as_usize")]
+    pub lock_period: usize,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 收益类型, 如 `Locked Rewards`
+    #[serde(rename = "type")]
+    pub reward_type: String,
+}
+
+/// 个人剩余申购额度
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnQuota {
+    #[serde(deserialize_with = "string_as_f64")]
+    pub left_personal_quota: f64,
+}
+
+/// 闪兑交易对
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPair {
+    pub from_asset: String,
+    pub to_asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub from_asset_min_amount: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub from_asset_max_amount: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub to_asset_min_amount: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub to_asset_max_amount: f64,
+}
+
+/// 闪兑报价
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    pub quote_id: String,
+    /// 1 个卖出资产可以兑换的买入资产数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub ratio: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub inverse_ratio: f64,
+    /// 报价过期时间
+    pub valid_timestamp: i64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub from_amount: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub to_amount: f64,
+}
+
+impl Quote {
+    /// 报价是否已过期
+    pub fn is_expired(&self) -> bool {
+        chrono::Utc::now().timestamp_millis() >= self.valid_timestamp
+    }
+}
+
+/// 接受报价结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedQuote {
+    pub order_id: String,
+    pub create_time: i64,
+    pub order_status: ConvertStatus,
+}
+
+/// 闪兑订单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    pub order_id: u64,
+    /// 查询订单状态时不返回
+    pub quote_id: Option<String>,
+    pub order_status: ConvertStatus,
+    pub from_asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub to_amount: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub ratio: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub inverse_ratio: f64,
+    pub create_time: i64,
+}
+
+/// 闪兑交易历史
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTradeFlow {
+    pub list: Vec<ConvertOrder>,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub limit: usize,
+    /// 是否还有更多数据, 需要缩小时间范围再次查询
+    pub more_data: bool,
+}
+
+/// 统一账户资产余额
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMarginBalance {
+    pub asset: String,
+    /// 钱包余额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_wallet_balance: f64,
+    /// 全仓杠杆资产
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cross_margin_asset: f64,
+    /// 全仓杠杆借贷
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cross_margin_borrowed: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cross_margin_free: f64,
+    /// 全仓杠杆利息
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cross_margin_interest: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cross_margin_locked: f64,
+    /// U本位合约钱包余额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub um_wallet_balance: f64,
+    /// U本位合约未实现盈亏
+    #[serde(rename = "umUnrealizedPNL", deserialize_with = "string_as_f64")]
+    pub um_unrealized_pnl: f64,
+    /// 币本位合约钱包余额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub cm_wallet_balance: f64,
+    /// 币本位合约未实现盈亏
+    #[serde(rename = "cmUnrealizedPNL", deserialize_with = "string_as_f64")]
+    pub cm_unrealized_pnl: f64,
+    /// 负余额
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub negative_balance: f64,
+    pub update_time: i64,
+}
+
+/// 统一账户信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMarginAccount {
+    /// 统一账户维持保证金率
+    #[serde(rename = "uniMMR", deserialize_with = "string_as_f64")]
+    pub uni_mmr: f64,
+    /// 以 USD 计价的账户权益
+    #[serde(deserialize_with = "string_as_f64")]
+    pub account_equity: f64,
+    /// 不考虑质押率的账户权益
+    #[serde(deserialize_with = "string_as_f64")]
+    pub actual_equity: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub account_initial_margin: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub account_maint_margin: f64,
+    pub account_status: PortfolioMarginStatus,
+    /// 最大可转出
+    #[serde(deserialize_with = "string_as_f64")]
+    pub virtual_max_withdraw_amount: f64,
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub total_available_balance: f64,
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub total_margin_open_loss: f64,
+    pub update_time: i64,
+}
+
+/// 统一账户合约持仓
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMarginPosition {
+    pub symbol: String,
+    /// 头寸数量，符号代表多空方向, 正数为多，负数为空
+    #[serde(deserialize_with = "string_as_f64")]
+    pub position_amt: f64,
+    /// 开仓均价
+    #[serde(deserialize_with = "string_as_f64")]
+    pub entry_price: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub mark_price: f64,
+    /// 持仓未实现盈亏
+    #[serde(deserialize_with = "string_as_f64")]
+    pub un_realized_profit: f64,
+    /// 参考强平价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub liquidation_price: f64,
+    #[serde(deserialize_with = "string_as_usize")]
+    pub leverage: usize,
+    pub position_side: PositionDirect,
+    /// 名义价值, 仅U本位合约返回
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub notional: f64,
+    /// 当前杠杆倍数允许的名义价值上限, 仅U本位合约返回
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub max_notional_value: f64,
+    /// 名义价值(标的数量), 仅币本位合约返回
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub notional_value: f64,
+    /// 当前杠杆倍数允许的最大张数, 仅币本位合约返回
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub max_qty: f64,
+    pub update_time: i64,
+}
+
+/// 资金归集结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionResult {
+    pub msg: String,
+}
+
+/// 算法单下单结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderResult {
+    pub client_algo_id: String,
+    pub success: bool,
+    pub code: i64,
+    pub msg: String,
+}
+
+/// 算法单撤单结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoCancelResult {
+    pub algo_id: usize,
+    pub success: bool,
+    pub code: i64,
+    pub msg: String,
+}
+
+/// 算法单列表
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrders {
+    pub total: usize,
+    #[serde(default = "Vec::new")]
+    pub orders: Vec<AlgoOrder>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrder {
+    pub algo_id: usize,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: PositionDirect,
+    /// 总数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub total_qty: f64,
+    /// 已成交数量
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 已成交金额
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_amt: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub avg_price: f64,
+    pub client_algo_id: String,
+    /// 下单时间
+    pub book_time: i64,
+    /// 结束时间, 执行中为 0
+    pub end_time: i64,
+    pub algo_status: AlgoStatus,
+    pub algo_type: AlgoType,
+    /// VP 算法单的紧急程度, TWAP 算法单不返回
+    pub urgency: Option<AlgoUrgency>,
+}
+
+/// 算法单的子订单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrders {
+    pub total: usize,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_amt: f64,
+    #[serde(default = "Vec::new")]
+    pub sub_orders: Vec<AlgoSubOrder>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrder {
+    pub algo_id: usize,
+    pub order_id: u64,
+    pub order_status: OrderStatus,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub executed_amt: f64,
+    /// 手续费, 负数表示返佣
+    #[serde(deserialize_with = "string_as_f64")]
+    pub fee_amt: f64,
+    pub fee_asset: String,
+    pub book_time: i64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub avg_price: f64,
+    pub side: OrderSide,
+    pub symbol: String,
+    /// 子订单序号
+    pub sub_id: usize,
+    /// 有效方式, 如 `IMMEDIATE_OR_CANCEL`
+    pub time_in_force: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub orig_qty: f64,
+}
+
+/// 资金流水
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// 与交易对无关的流水为空字符串
+    pub symbol: String,
+    pub income_type: IncomeType,
+    /// 数量, 负数表示支出
+    #[serde(deserialize_with = "string_as_f64")]
+    pub income: f64,
+    pub asset: String,
+    pub info: String,
+    pub time: i64,
+    pub tran_id: u64,
+    /// 成交ID, 与成交无关的流水为空字符串
+    pub trade_id: String,
+}
+
+/// 交易对手续费率
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub maker_commission_rate: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub taker_commission_rate: f64,
+}
+
+/// 持仓 ADL 队列位置
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantileValue,
+}
+
+/// ADL 队列分数, 0 到 4, 分数越高越可能被自动减仓
+#[derive(Debug, Deserialize)]
+pub struct AdlQuantileValue {
+    /// 双向持仓模式下多头
+    #[serde(rename = "LONG")]
+    pub long: Option<u8>,
+    /// 双向持仓模式下空头
+    #[serde(rename = "SHORT")]
+    pub short: Option<u8>,
+    /// 单向持仓模式
+    #[serde(rename = "BOTH")]
+    pub both: Option<u8>,
+    /// 双向持仓模式下同时持有多空时, `LONG` 和 `SHORT` 中较高的分数
+    #[serde(rename = "HEDGE")]
+    pub hedge: Option<u8>,
+}
+
+/// 杠杆分层标准
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub symbol: String,
+    /// 用户分层系数, 不为 1 时表示交易对的名义价值上下限需要乘以该系数
+    #[serde(default)]
+    pub notional_coef: Option<f64>,
+    pub brackets: Vec<Bracket>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bracket {
+    /// 层级
+    pub bracket: usize,
+    /// 该层允许的最高初始杠杆倍数
+    pub initial_leverage: usize,
+    /// 该层名义价值上限
+    pub notional_cap: f64,
+    /// 该层名义价值下限
+    pub notional_floor: f64,
+    /// 维持保证金率
+    pub maint_margin_ratio: f64,
+    /// 速算数
+    pub cum: f64,
+}
+
+/// 联合保证金模式
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiAssetsMargin {
+    /// "true": 联合保证金模式；"false": 单币保证金模式
+    pub multi_assets_margin: bool,
+}
+
+/// 合约 API 交易量化规则指标
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatus {
+    /// 以交易对为键, 未触发规则时为空
+    #[serde(default)]
+    pub indicators: HashMap<String, Vec<TradingIndicator>>,
+    pub update_time: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingIndicator {
+    /// 是否被禁止交易
+    pub is_locked: bool,
+    /// 禁止交易的恢复时间
+    #[serde(default)]
+    pub planned_recover_time: i64,
+    /// 指标名称, 如 `UFR`, `IFER`, `GCR`, `DR`
+    pub indicator: String,
+    /// 当前值
+    pub value: f64,
+    /// 触发值
+    pub trigger_value: f64,
+}
+
+/// 异步下载ID
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncDownloadId {
+    /// 最近 30 天平均生成耗时, 单位毫秒
+    #[serde(rename = "avgCostTimestampOfLast30d")]
+    pub avg_cost_timestamp_of_last_30d: i64,
+    pub download_id: String,
+}
+
+/// 异步下载链接
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AsyncDownloadLink {
+    pub download_id: String,
+    /// `completed` 或 `processing`, 处理中时 `url` 为空
+    pub status: String,
+    pub url: String,
+    /// 为 false 时表示下载还未完成
+    pub notified: bool,
+    /// 链接过期时间
+    pub expiration_timestamp: i64,
+    pub is_expired: Option<bool>,
+}
+
+/// 币本位合约杠杆分层标准, 按标的交易对返回
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DLeverageBracket {
+    pub pair: String,
+    pub brackets: Vec<DBracket>,
+}
+
+/// 币本位合约按标的资产数量分层, 而不是名义价值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DBracket {
+    /// 层级
+    pub bracket: usize,
+    /// 该层允许的最高初始杠杆倍数
+    pub initial_leverage: usize,
+    /// 该层对应的数量上限
+    pub qty_cap: f64,
+    /// 该层对应的数量下限
+    pub qty_floor: f64,
+    /// 维持保证金率
+    pub maint_margin_ratio: f64,
+    /// 速算数
+    pub cum: f64,
+}
+
+/// 基差
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub index_price: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub futures_price: f64,
+    /// 合约价格 - 指数价格
+    #[serde(deserialize_with = "string_as_f64")]
+    pub basis: f64,
+    #[serde(deserialize_with = "string_as_f64")]
+    pub basis_rate: f64,
+    /// 年化基差率, 永续合约为 0
+    #[serde(deserialize_with = "string_as_f64")]
+    pub annualized_basis_rate: f64,
+    pub timestamp: i64,
+}
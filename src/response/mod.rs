@@ -65,3 +65,46 @@ where
 {
     deserializer.deserialize_str(UsizeVisitor)
 }
+
+/// 字符串格式的时间戳
+fn string_as_i64<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(UsizeVisitor).map(|v| v as i64)
+}
+
+struct BoolVisitor;
+
+impl<'de> Visitor<'de> for BoolVisitor {
+    type Value = bool;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a bool or a string representation of a bool")
+    }
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(v)
+    }
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "true" | "TRUE" => Ok(true),
+            "false" | "FALSE" | "" => Ok(false),
+            _ => Err(E::invalid_value(
+                Unexpected::Str(v),
+                &"a string representation as bool",
+            )),
+        }
+    }
+}
+
+fn string_as_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(BoolVisitor)
+}
@@ -1,12 +1,13 @@
 use std::usize;
 
-use super::{string_as_f64, string_as_usize, DepthOrder};
+use super::{string_as_f64, string_as_usize, DepthOrder, OptionSymbol};
 use crate::enums::{
-    ContractType, FuturesOrderType, Interval, MarginType, OrderSide, OrderStatus, PositionDirect,
-    TimeInForce,
+    ContractStatus, ContractType, FuturesOrderType, Interval, MarginType, OcoOrderStatus,
+    OcoStatus, OptionsOrderStatus, OrderSide, OrderStatus, PortfolioMarginStatus, PositionDirect,
+    SpotOrderType, TimeInForce,
 };
 use crate::error::{APIError, BianResult};
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 use tungstenite::client::AutoGenericStream;
 
 pub trait WebsocketResponse<R: serde::de::DeserializeOwned> {
@@ -70,7 +71,7 @@ pub struct WSTrade {
     #[serde(rename = "s")]
     pub symbol: String,
     /// 交易ID
-    #[serde(rename="t")]
+    #[serde(rename = "t")]
     pub transaction_id: usize,
     /// 成交价格
     #[serde(rename = "p", deserialize_with = "string_as_f64")]
@@ -121,6 +122,24 @@ pub struct WSPrice {
     pub trade_time: i64,
 }
 
+/// 币本位合约指数价格
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSIndexPrice {
+    /// 事件类型 indexPriceUpdate
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 标的交易对
+    #[serde(rename = "i")]
+    pub pair: String,
+    /// 指数价格
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price: f64,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WSKline {
@@ -158,6 +177,24 @@ pub struct WSContinuousKline {
     pub data: KData,
 }
 
+/// 指数价格或标记价格K线
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSPriceKline {
+    /// 事件类型 indexPrice_kline 或 markPrice_kline
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 标的交易对
+    #[serde(rename = "ps")]
+    pub pair: String,
+    /// K 线数据, 成交量相关字段为 0, 指数价格K线的交易对为 `0`
+    #[serde(rename = "k")]
+    pub data: KData,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KData {
@@ -596,7 +633,7 @@ pub struct WSMarginCall {
     #[serde(rename = "E")]
     pub event_time: i64,
     /// 除去逐仓仓位保证金的钱包余额, 仅在全仓 margin call 情况下推送此字段
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub cw: f64,
     /// 仓位数据
     #[serde(rename = "p")]
@@ -628,6 +665,9 @@ pub struct WSBalance {
     /// 除去逐仓仓位保证金的钱包余额
     #[serde(deserialize_with = "string_as_f64")]
     pub cw: f64,
+    /// 除去盈亏与交易手续费以外的钱包余额改变量
+    #[serde(default, deserialize_with = "string_as_f64")]
+    pub bc: f64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -648,17 +688,21 @@ pub struct WSPosition {
     /// 未实现盈亏
     #[serde(rename = "up", deserialize_with = "string_as_f64")]
     pub unrealized_pnl: f64,
-    /// 保证金模式
-    #[serde(rename = "mt")]
+    /// 保证金模式, 统一账户只支持全仓, 不推送此字段
+    #[serde(rename = "mt", default = "cross_margin")]
     pub margin_type: MarginType,
     /// 若为逐仓，仓位保证金
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub iw: f64,
     /// 持仓方向
     #[serde(rename = "ps")]
     pub position_side: PositionDirect,
 }
 
+fn cross_margin() -> MarginType {
+    MarginType::Crossed
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WSAccountUpdate {
@@ -688,14 +732,13 @@ pub struct WSOrderUpdate {
     /// 撮合时间
     #[serde(rename = "T")]
     pub trade_matching_time: i64,
-    /// 账户更新事件
-    #[serde(rename = "a")]
+    /// 订单更新事件
+    #[serde(rename = "o")]
     pub event: WSOrderEvent,
 }
 
 #[allow(non_snake_case)]
 #[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
 pub struct WSOrderEvent {
     /// 交易对
     pub s: String,
@@ -738,47 +781,51 @@ pub struct WSOrderEvent {
     /// 订单末次成交价格
     #[serde(deserialize_with = "string_as_f64")]
     pub L: f64,
-    /// 手续费资产类型
+    /// 手续费资产类型, 无手续费时不推送此字段
+    #[serde(default)]
     pub N: String,
-    /// 手续费数量
-    #[serde(deserialize_with = "string_as_f64")]
+    /// 手续费数量, 无手续费时不推送此字段
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub n: f64,
     /// 成交时间
     pub T: i64,
     /// 成交ID
     pub t: usize,
     /// 买单净值
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub b: f64,
     /// 卖单净值
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub a: f64,
     /// 该成交是作为挂单成交吗？
     pub m: bool,
     /// 是否是只减仓单
     pub R: bool,
-    /// 触发价类型
+    /// 触发价类型, 统一账户不推送此字段
+    #[serde(default)]
     pub wt: String,
-    /// 原始订单类型
-    pub ot: FuturesOrderType,
+    /// 原始订单类型, 统一账户不推送此字段
+    #[serde(default)]
+    pub ot: Option<FuturesOrderType>,
     /// 持仓方向
     pub ps: PositionDirect,
     /// 是否为触发平仓单; 仅在条件订单情况下会推送此字段
+    #[serde(default)]
     pub cp: bool,
     /// 追踪止损激活价格, 仅在追踪止损单时会推送此字段
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub AP: f64,
     /// 追踪止损回调比例, 仅在追踪止损单时会推送此字段
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub cr: f64,
     /// 该交易实现盈亏
-    #[serde(deserialize_with = "string_as_f64")]
+    #[serde(default, deserialize_with = "string_as_f64")]
     pub rp: f64,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct WSLeverageUpdate {
+pub struct WSAccountConfigUpdate {
     /// 事件类型
     #[serde(rename = "e")]
     pub event_type: String,
@@ -788,8 +835,12 @@ pub struct WSLeverageUpdate {
     /// 撮合时间
     #[serde(rename = "T")]
     pub trade_matching_time: i64,
+    /// 交易对杠杆倍数变化
     #[serde(rename = "ac")]
-    pub event: WSLeverageEvent,
+    pub leverage: Option<WSLeverageEvent>,
+    /// 联合保证金模式变化
+    #[serde(rename = "ai")]
+    pub multi_assets: Option<WSMultiAssetsEvent>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -797,18 +848,925 @@ pub struct WSLeverageUpdate {
 pub struct WSLeverageEvent {
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "l", deserialize_with = "string_as_usize")]
+    #[serde(rename = "l")]
     pub leverage: usize,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct WSMultiAssetsEvent {
+    /// 是否开启联合保证金模式
+    #[serde(rename = "j")]
+    pub multi_assets_margin: bool,
+}
+
+/// 精简的成交推送, 比 `ORDER_TRADE_UPDATE` 延迟更低
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSTradeLite {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合时间
+    #[serde(rename = "T")]
+    pub trade_matching_time: i64,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 订单原始数量
+    #[serde(rename = "q", deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 订单原始价格
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 是否为挂单方
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    /// 客户端自定订单ID
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// 订单方向
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// 末次成交价格
+    #[serde(rename = "L", deserialize_with = "string_as_f64")]
+    pub last_price: f64,
+    /// 末次成交量
+    #[serde(rename = "l", deserialize_with = "string_as_f64")]
+    pub last_qty: f64,
+    /// 成交ID
+    #[serde(rename = "t")]
+    pub trade_id: usize,
+    /// 订单ID
+    #[serde(rename = "i")]
+    pub order_id: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSConditionalOrderReject {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合时间
+    #[serde(rename = "T")]
+    pub trade_matching_time: i64,
+    #[serde(rename = "or")]
+    pub event: WSConditionalOrderRejectEvent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSConditionalOrderRejectEvent {
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 订单ID
+    #[serde(rename = "i")]
+    pub order_id: usize,
+    /// 拒绝原因
+    #[serde(rename = "r")]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSStrategyUpdate {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合时间
+    #[serde(rename = "T")]
+    pub trade_matching_time: i64,
+    #[serde(rename = "su")]
+    pub event: WSStrategyEvent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSStrategyEvent {
+    /// 策略ID
+    #[serde(rename = "si")]
+    pub strategy_id: usize,
+    /// 策略类型
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+    /// 策略状态
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 更新时间
+    #[serde(rename = "ut")]
+    pub update_time: i64,
+    /// 操作代码
+    #[serde(rename = "c")]
+    pub op_code: i64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSGridUpdate {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合时间
+    #[serde(rename = "T")]
+    pub trade_matching_time: i64,
+    #[serde(rename = "gu")]
+    pub event: WSGridEvent,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSGridEvent {
+    /// 策略ID
+    #[serde(rename = "si")]
+    pub strategy_id: usize,
+    /// 策略类型
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+    /// 策略状态
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 已实现盈亏
+    #[serde(rename = "r", deserialize_with = "string_as_f64")]
+    pub realized_pnl: f64,
+    /// 未配对均价
+    #[serde(rename = "up", deserialize_with = "string_as_f64")]
+    pub unmatched_avg_price: f64,
+    /// 未配对数量
+    #[serde(rename = "uq", deserialize_with = "string_as_f64")]
+    pub unmatched_qty: f64,
+    /// 未配对手续费
+    #[serde(rename = "uf", deserialize_with = "string_as_f64")]
+    pub unmatched_fee: f64,
+    /// 已配对盈亏
+    #[serde(rename = "mp", deserialize_with = "string_as_f64")]
+    pub matched_pnl: f64,
+    /// 更新时间
+    #[serde(rename = "ut")]
+    pub update_time: i64,
+}
+
+/// 合约用户数据推送, 按 `e` 字段区分事件类型
+#[derive(Debug)]
 pub enum WSUserStream {
+    /// `listenKeyExpired`
     ListenKeyExpired(WSListenKeyExpired),
-    MarginPosition(WSMarginPosition),
+    /// `MARGIN_CALL`
+    MarginCall(WSMarginCall),
+    /// `ACCOUNT_UPDATE`
     AccountUpdate(WSAccountUpdate),
+    /// `ORDER_TRADE_UPDATE`
     OrderUpdate(WSOrderUpdate),
-    LeverageUpdate(WSLeverageUpdate),
+    /// `ACCOUNT_CONFIG_UPDATE`
+    AccountConfigUpdate(WSAccountConfigUpdate),
+    /// `TRADE_LITE`
+    TradeLite(WSTradeLite),
+    /// `CONDITIONAL_ORDER_TRIGGER_REJECT`
+    ConditionalOrderReject(WSConditionalOrderReject),
+    /// `STRATEGY_UPDATE`
+    StrategyUpdate(WSStrategyUpdate),
+    /// `GRID_UPDATE`
+    GridUpdate(WSGridUpdate),
+    /// 尚未支持的事件, 保留原始数据
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for WSUserStream {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event_type = value.get("e").and_then(|e| e.as_str()).unwrap_or_default();
+        let event = match event_type {
+            "listenKeyExpired" => serde_json::from_value(value).map(Self::ListenKeyExpired),
+            "MARGIN_CALL" => serde_json::from_value(value).map(Self::MarginCall),
+            "ACCOUNT_UPDATE" => serde_json::from_value(value).map(Self::AccountUpdate),
+            "ORDER_TRADE_UPDATE" => serde_json::from_value(value).map(Self::OrderUpdate),
+            "ACCOUNT_CONFIG_UPDATE" => serde_json::from_value(value).map(Self::AccountConfigUpdate),
+            "TRADE_LITE" => serde_json::from_value(value).map(Self::TradeLite),
+            "CONDITIONAL_ORDER_TRIGGER_REJECT" => {
+                serde_json::from_value(value).map(Self::ConditionalOrderReject)
+            }
+            "STRATEGY_UPDATE" => serde_json::from_value(value).map(Self::StrategyUpdate),
+            "GRID_UPDATE" => serde_json::from_value(value).map(Self::GridUpdate),
+            _ => Ok(Self::Unknown(value)),
+        };
+        event.map_err(de::Error::custom)
+    }
+}
+
+/// 现货账户余额变化, 仅包含发生变化的资产
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSSpotAccountPosition {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 账户末次更新时间
+    #[serde(rename = "u")]
+    pub last_update_time: i64,
+    #[serde(rename = "B")]
+    pub balances: Vec<WSSpotBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSSpotBalance {
+    /// 资产名称
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// 可用余额
+    #[serde(rename = "f", deserialize_with = "string_as_f64")]
+    pub free: f64,
+    /// 冻结余额
+    #[serde(rename = "l", deserialize_with = "string_as_f64")]
+    pub locked: f64,
+}
+
+/// 充值, 提现或划转导致的余额变化
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSSpotBalanceUpdate {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 资产名称
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// 余额变化量
+    #[serde(rename = "d", deserialize_with = "string_as_f64")]
+    pub delta: f64,
+    /// 清算时间
+    #[serde(rename = "T")]
+    pub clear_time: i64,
+}
+
+/// 现货订单更新
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSExecutionReport {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 客户端自定订单ID
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// 订单方向
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// 订单类型
+    #[serde(rename = "o")]
+    pub order_type: SpotOrderType,
+    /// 有效方式
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    /// 订单原始数量
+    #[serde(rename = "q", deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 订单原始价格
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 止盈止损单触发价格
+    #[serde(rename = "P", deserialize_with = "string_as_f64")]
+    pub stop_price: f64,
+    /// 冰山订单数量
+    #[serde(rename = "F", deserialize_with = "string_as_f64")]
+    pub iceberg_qty: f64,
+    /// OCO 订单 orderListId, 非 OCO 订单为 -1
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// 原始订单自定义ID, 撤单时为被撤销订单的自定义ID
+    #[serde(rename = "C")]
+    pub orig_client_order_id: String,
+    /// 本次事件的具体执行类型
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    /// 订单的当前状态
+    #[serde(rename = "X")]
+    pub status: OrderStatus,
+    /// 订单被拒绝的原因
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    /// 订单ID
+    #[serde(rename = "i")]
+    pub order_id: usize,
+    /// 订单末次成交量
+    #[serde(rename = "l", deserialize_with = "string_as_f64")]
+    pub last_qty: f64,
+    /// 订单累计已成交量
+    #[serde(rename = "z", deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 订单末次成交价格
+    #[serde(rename = "L", deserialize_with = "string_as_f64")]
+    pub last_price: f64,
+    /// 手续费数量
+    #[serde(rename = "n", deserialize_with = "string_as_f64")]
+    pub commission: f64,
+    /// 手续费资产类型, 无成交时为 null
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    /// 成交时间
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// 成交ID, 无成交时为 -1
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    /// 订单是否在订单簿上
+    #[serde(rename = "w")]
+    pub is_working: bool,
+    /// 该成交是作为挂单成交吗？
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    /// 订单创建时间
+    #[serde(rename = "O")]
+    pub create_time: i64,
+    /// 订单累计已成交金额
+    #[serde(rename = "Z", deserialize_with = "string_as_f64")]
+    pub cumulative_quote_qty: f64,
+    /// 订单末次成交金额
+    #[serde(rename = "Y", deserialize_with = "string_as_f64")]
+    pub last_quote_qty: f64,
+    /// 报价资产数量, 按成交额下的市价单有效
+    #[serde(rename = "Q", deserialize_with = "string_as_f64")]
+    pub quote_order_qty: f64,
+}
+
+/// OCO 订单状态更新
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSListStatus {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// OCO 订单 orderListId
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// 订单组类型, 如 `OCO`
+    #[serde(rename = "c")]
+    pub contingency_type: String,
+    /// 状态类型
+    #[serde(rename = "l")]
+    pub list_status_type: OcoStatus,
+    /// 订单状态
+    #[serde(rename = "L")]
+    pub list_order_status: OcoOrderStatus,
+    /// 订单组被拒绝的原因
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    /// 订单组自定义ID
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+    /// 成交时间
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// 订单组中的订单
+    #[serde(rename = "O")]
+    pub orders: Vec<WSListOrder>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSListOrder {
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 订单ID
+    #[serde(rename = "i")]
+    pub order_id: usize,
+    /// 客户端自定订单ID
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
+
+/// 现货用户数据推送, 按 `e` 字段区分事件类型
+#[derive(Debug)]
+pub enum WSSpotUserStream {
+    /// `listenKeyExpired`
+    ListenKeyExpired(WSListenKeyExpired),
+    /// `outboundAccountPosition`
+    AccountPosition(WSSpotAccountPosition),
+    /// `balanceUpdate`
+    BalanceUpdate(WSSpotBalanceUpdate),
+    /// `executionReport`
+    ExecutionReport(WSExecutionReport),
+    /// `listStatus`
+    ListStatus(WSListStatus),
+    /// 尚未支持的事件, 保留原始数据
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for WSSpotUserStream {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event_type = value.get("e").and_then(|e| e.as_str()).unwrap_or_default();
+        let event = match event_type {
+            "listenKeyExpired" => serde_json::from_value(value).map(Self::ListenKeyExpired),
+            "outboundAccountPosition" => serde_json::from_value(value).map(Self::AccountPosition),
+            "balanceUpdate" => serde_json::from_value(value).map(Self::BalanceUpdate),
+            "executionReport" => serde_json::from_value(value).map(Self::ExecutionReport),
+            "listStatus" => serde_json::from_value(value).map(Self::ListStatus),
+            _ => Ok(Self::Unknown(value)),
+        };
+        event.map_err(de::Error::custom)
+    }
+}
+
+/// 期权24小时 ticker
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsTicker {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合引擎时间
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// 期权合约
+    #[serde(rename = "s")]
+    pub symbol: OptionSymbol,
+    /// 开盘价
+    #[serde(rename = "o", deserialize_with = "string_as_f64")]
+    pub open: f64,
+    /// 最高价
+    #[serde(rename = "h", deserialize_with = "string_as_f64")]
+    pub high: f64,
+    /// 最低价
+    #[serde(rename = "l", deserialize_with = "string_as_f64")]
+    pub low: f64,
+    /// 最新成交价
+    #[serde(rename = "c", deserialize_with = "string_as_f64")]
+    pub close: f64,
+    /// 成交量
+    #[serde(rename = "V", deserialize_with = "string_as_f64")]
+    pub volume: f64,
+    /// 成交额
+    #[serde(rename = "A", deserialize_with = "string_as_f64")]
+    pub amount: f64,
+    /// 价格变动百分比
+    #[serde(rename = "P", deserialize_with = "string_as_f64")]
+    pub price_change_percent: f64,
+    /// 价格变动
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price_change: f64,
+    /// 最后一笔成交量
+    #[serde(rename = "Q", deserialize_with = "string_as_f64")]
+    pub last_qty: f64,
+    /// 第一笔成交ID
+    #[serde(rename = "F", deserialize_with = "string_as_usize")]
+    pub first_trade_id: usize,
+    /// 最后一笔成交ID
+    #[serde(rename = "L", deserialize_with = "string_as_usize")]
+    pub last_trade_id: usize,
+    /// 成交笔数
+    #[serde(rename = "n")]
+    pub trade_count: usize,
+    /// 买一价
+    #[serde(rename = "bo", deserialize_with = "string_as_f64")]
+    pub best_bid: f64,
+    /// 卖一价
+    #[serde(rename = "ao", deserialize_with = "string_as_f64")]
+    pub best_ask: f64,
+    /// 买一量
+    #[serde(rename = "bq", deserialize_with = "string_as_f64")]
+    pub best_bid_qty: f64,
+    /// 卖一量
+    #[serde(rename = "aq", deserialize_with = "string_as_f64")]
+    pub best_ask_qty: f64,
+    /// 买一隐含波动率
+    #[serde(rename = "b", deserialize_with = "string_as_f64")]
+    pub bid_iv: f64,
+    /// 卖一隐含波动率
+    #[serde(rename = "a", deserialize_with = "string_as_f64")]
+    pub ask_iv: f64,
+    #[serde(rename = "d", deserialize_with = "string_as_f64")]
+    pub delta: f64,
+    #[serde(rename = "t", deserialize_with = "string_as_f64")]
+    pub theta: f64,
+    #[serde(rename = "g", deserialize_with = "string_as_f64")]
+    pub gamma: f64,
+    #[serde(rename = "v", deserialize_with = "string_as_f64")]
+    pub vega: f64,
+    /// 隐含波动率
+    #[serde(rename = "vo", deserialize_with = "string_as_f64")]
+    pub implied_volatility: f64,
+    /// 标记价格
+    #[serde(rename = "mp", deserialize_with = "string_as_f64")]
+    pub mark_price: f64,
+    /// 最高买价
+    #[serde(rename = "hl", deserialize_with = "string_as_f64")]
+    pub high_price_limit: f64,
+    /// 最低卖价
+    #[serde(rename = "ll", deserialize_with = "string_as_f64")]
+    pub low_price_limit: f64,
+    /// 预估行权价格, 仅在行权前半小时有值
+    #[serde(rename = "eep", deserialize_with = "string_as_f64")]
+    pub estimated_exercise_price: f64,
+}
+
+/// 期权标记价格, 按标的资产推送所有期权合约
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsMarkPrice {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 期权合约
+    #[serde(rename = "s")]
+    pub symbol: OptionSymbol,
+    /// 标记价格
+    #[serde(rename = "mp", deserialize_with = "string_as_f64")]
+    pub mark_price: f64,
+}
+
+/// 期权有限档深度信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsDepth {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 撮合引擎时间
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+    /// 期权合约
+    #[serde(rename = "s")]
+    pub symbol: OptionSymbol,
+    /// 更新ID
+    #[serde(rename = "u")]
+    pub update_id: usize,
+    /// 上一次推送的更新ID
+    pub pu: usize,
+    /// 买方
+    #[serde(rename = "b")]
+    pub buy: Vec<DepthOrder>,
+    /// 卖方
+    #[serde(rename = "a")]
+    pub sell: Vec<DepthOrder>,
+}
+
+/// 期权账户余额
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsBalance {
+    /// 保证金资产
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// 账户余额
+    #[serde(rename = "b", deserialize_with = "string_as_f64")]
+    pub balance: f64,
+    /// 持仓价值
+    #[serde(rename = "m", deserialize_with = "string_as_f64")]
+    pub position_value: f64,
+    /// 未实现盈亏
+    #[serde(rename = "u", deserialize_with = "string_as_f64")]
+    pub unrealized_pnl: f64,
+    /// 维持保证金
+    #[serde(rename = "M", deserialize_with = "string_as_f64")]
+    pub maintenance_margin: f64,
+    /// 初始保证金
+    #[serde(rename = "i", deserialize_with = "string_as_f64")]
+    pub initial_margin: f64,
+}
+
+/// 按标的汇总的希腊值
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsGreek {
+    /// 标的
+    #[serde(rename = "ui")]
+    pub underlying: String,
+    #[serde(rename = "d")]
+    pub delta: f64,
+    #[serde(rename = "t")]
+    pub theta: f64,
+    #[serde(rename = "g")]
+    pub gamma: f64,
+    #[serde(rename = "v")]
+    pub vega: f64,
+}
+
+/// 期权持仓
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsPosition {
+    /// 期权合约
+    #[serde(rename = "s")]
+    pub symbol: OptionSymbol,
+    /// 持仓数量, 空仓为负数
+    #[serde(rename = "c", deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 可平仓数量
+    #[serde(rename = "r", deserialize_with = "string_as_f64")]
+    pub reducible_qty: f64,
+    /// 持仓价值
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub position_value: f64,
+    /// 开仓均价
+    #[serde(rename = "a", deserialize_with = "string_as_f64")]
+    pub entry_price: f64,
+}
+
+/// 期权账户更新
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsAccountUpdate {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "B", default)]
+    pub balances: Vec<WSOptionsBalance>,
+    #[serde(rename = "G", default)]
+    pub greeks: Vec<WSOptionsGreek>,
+    #[serde(rename = "P", default)]
+    pub positions: Vec<WSOptionsPosition>,
+    pub uid: usize,
+}
+
+/// 期权订单成交明细
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsFill {
+    /// 成交ID
+    #[serde(rename = "t", deserialize_with = "string_as_usize")]
+    pub trade_id: usize,
+    /// 成交价格
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 成交数量
+    #[serde(rename = "q", deserialize_with = "string_as_f64")]
+    pub qty: f64,
+    /// 成交时间
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    /// MAKER 或 TAKER
+    #[serde(rename = "m")]
+    pub liquidity: String,
+    /// 手续费
+    #[serde(rename = "f", deserialize_with = "string_as_f64")]
+    pub fee: f64,
+}
+
+/// 期权订单
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsOrder {
+    /// 创建时间
+    #[serde(rename = "T")]
+    pub create_time: i64,
+    /// 更新时间
+    #[serde(rename = "t")]
+    pub update_time: i64,
+    /// 期权合约
+    #[serde(rename = "s")]
+    pub symbol: OptionSymbol,
+    /// 自定义订单ID
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// 订单ID
+    #[serde(rename = "oid", deserialize_with = "string_as_usize")]
+    pub order_id: usize,
+    /// 订单价格
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub price: f64,
+    /// 订单数量, 卖单为负数
+    #[serde(rename = "q", deserialize_with = "string_as_f64")]
+    pub quantity: f64,
+    /// 只减仓
+    #[serde(rename = "r")]
+    pub reduce_only: bool,
+    /// 只做 maker
+    #[serde(rename = "po")]
+    pub post_only: bool,
+    /// 订单状态
+    #[serde(rename = "S")]
+    pub status: OptionsOrderStatus,
+    /// 已成交数量
+    #[serde(rename = "e", deserialize_with = "string_as_f64")]
+    pub executed_qty: f64,
+    /// 已成交金额
+    #[serde(rename = "ec", deserialize_with = "string_as_f64")]
+    pub executed_cost: f64,
+    /// 手续费
+    #[serde(rename = "f", deserialize_with = "string_as_f64")]
+    pub fee: f64,
+    /// 有效方式
+    #[serde(rename = "tif")]
+    pub time_in_force: TimeInForce,
+    /// 订单类型
+    #[serde(rename = "oty")]
+    pub order_type: FuturesOrderType,
+    /// 成交明细
+    #[serde(rename = "fi", default)]
+    pub fills: Vec<WSOptionsFill>,
+}
+
+/// 期权订单更新
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsOrderUpdate {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "o")]
+    pub orders: Vec<WSOptionsOrder>,
+}
+
+/// 期权账户风险等级变化
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSOptionsRiskLevelChange {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 风险等级, NORMAL, REDUCE_ONLY 等
+    #[serde(rename = "s")]
+    pub risk_level: String,
+    /// 保证金余额
+    #[serde(rename = "mb", deserialize_with = "string_as_f64")]
+    pub margin_balance: f64,
+    /// 维持保证金
+    #[serde(rename = "mm", deserialize_with = "string_as_f64")]
+    pub maintenance_margin: f64,
+}
+
+/// 期权用户数据推送, 按 `e` 字段区分事件类型
+#[derive(Debug)]
+pub enum WSOptionsUserStream {
+    /// `listenKeyExpired`
+    ListenKeyExpired(WSListenKeyExpired),
+    /// `ACCOUNT_UPDATE`
+    AccountUpdate(WSOptionsAccountUpdate),
+    /// `ORDER_TRADE_UPDATE`
+    OrderUpdate(WSOptionsOrderUpdate),
+    /// `RISK_LEVEL_CHANGE`
+    RiskLevelChange(WSOptionsRiskLevelChange),
+    /// 尚未支持的事件, 保留原始数据
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for WSOptionsUserStream {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event_type = value.get("e").and_then(|e| e.as_str()).unwrap_or_default();
+        let event = match event_type {
+            "listenKeyExpired" => serde_json::from_value(value).map(Self::ListenKeyExpired),
+            "ACCOUNT_UPDATE" => serde_json::from_value(value).map(Self::AccountUpdate),
+            "ORDER_TRADE_UPDATE" => serde_json::from_value(value).map(Self::OrderUpdate),
+            "RISK_LEVEL_CHANGE" => serde_json::from_value(value).map(Self::RiskLevelChange),
+            _ => Ok(Self::Unknown(value)),
+        };
+        event.map_err(de::Error::custom)
+    }
+}
+
+/// 统一账户风险等级变化
+#[derive(Debug, Deserialize)]
+pub struct WSPortfolioMarginRiskLevel {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 统一账户维持保证金率
+    #[serde(rename = "u", deserialize_with = "string_as_f64")]
+    pub uni_mmr: f64,
+    #[serde(rename = "s")]
+    pub status: PortfolioMarginStatus,
+    /// 账户权益
+    #[serde(rename = "eq", deserialize_with = "string_as_f64")]
+    pub account_equity: f64,
+    /// 不考虑质押率的账户权益
+    #[serde(rename = "ae", deserialize_with = "string_as_f64")]
+    pub actual_equity: f64,
+    /// 维持保证金
+    #[serde(rename = "m", deserialize_with = "string_as_f64")]
+    pub maint_margin: f64,
+}
+
+/// 全仓杠杆负债变化
+#[derive(Debug, Deserialize)]
+pub struct WSLiabilityChange {
+    /// 事件类型
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件推送时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// 负债类型, 如 `BORROW`
+    #[serde(rename = "t")]
+    pub liability_type: String,
+    /// 交易ID
+    #[serde(rename = "T")]
+    pub tran_id: u64,
+    /// 本金
+    #[serde(rename = "p", deserialize_with = "string_as_f64")]
+    pub principal: f64,
+    /// 利息
+    #[serde(rename = "i", deserialize_with = "string_as_f64")]
+    pub interest: f64,
+    /// 总负债
+    #[serde(rename = "l", deserialize_with = "string_as_f64")]
+    pub total_liability: f64,
+}
+
+/// 统一账户用户数据推送
+///
+/// 合约事件按 `fs` 字段区分U本位和币本位, 格式与合约推送相同; 杠杆事件格式与现货推送相同
+#[derive(Debug)]
+pub enum WSPortfolioMarginUserStream {
+    /// `listenKeyExpired`
+    ListenKeyExpired(WSListenKeyExpired),
+    /// `fs` 为 `UM` 的U本位合约事件
+    UsdFutures(WSUserStream),
+    /// `fs` 为 `CM` 的币本位合约事件
+    CoinFutures(WSUserStream),
+    /// 全仓杠杆事件
+    Margin(WSSpotUserStream),
+    /// `riskLevelChange`
+    RiskLevelChange(WSPortfolioMarginRiskLevel),
+    /// `liabilityChange`
+    LiabilityChange(WSLiabilityChange),
+    /// 尚未支持的事件, 保留原始数据
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for WSPortfolioMarginUserStream {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event_type = value.get("e").and_then(|e| e.as_str()).unwrap_or_default();
+        let business_unit = value
+            .get("fs")
+            .and_then(|fs| fs.as_str())
+            .unwrap_or_default();
+        let event = match (business_unit, event_type) {
+            (_, "listenKeyExpired") => serde_json::from_value(value).map(Self::ListenKeyExpired),
+            ("UM", _) => serde_json::from_value(value).map(Self::UsdFutures),
+            ("CM", _) => serde_json::from_value(value).map(Self::CoinFutures),
+            (_, "outboundAccountPosition" | "balanceUpdate" | "executionReport" | "listStatus") => {
+                serde_json::from_value(value).map(Self::Margin)
+            }
+            (_, "riskLevelChange") => serde_json::from_value(value).map(Self::RiskLevelChange),
+            (_, "liabilityChange") => serde_json::from_value(value).map(Self::LiabilityChange),
+            _ => Ok(Self::Unknown(value)),
+        };
+        event.map_err(de::Error::custom)
+    }
 }
 
 impl<R: serde::de::DeserializeOwned> WebsocketResponse<R>
@@ -858,3 +1816,62 @@ impl<R: serde::de::DeserializeOwned> WebsocketResponse<R>
         self.close(None).unwrap();
     }
 }
+
+/// 合约信息变化, 上下架, 交割和杠杆分层变化时推送
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSContractInfo {
+    /// 事件类型 contractInfo
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// 事件时间
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// 交易对
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// 标的交易对
+    #[serde(rename = "ps")]
+    pub pair: String,
+    /// 合约类型
+    #[serde(rename = "ct")]
+    pub contract_type: ContractType,
+    /// 交割时间
+    #[serde(rename = "dt")]
+    pub delivery_date: i64,
+    /// 上线时间
+    #[serde(rename = "ot")]
+    pub onboard_date: i64,
+    /// 合约状态
+    #[serde(rename = "cs")]
+    pub contract_status: ContractStatus,
+    /// 杠杆分层, 仅在分层变化时推送
+    #[serde(rename = "bks", default)]
+    pub brackets: Vec<WSContractBracket>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSContractBracket {
+    /// 层级
+    #[serde(rename = "bs")]
+    pub bracket: usize,
+    /// 该层下限
+    #[serde(rename = "bnf")]
+    pub floor: f64,
+    /// 该层上限
+    #[serde(rename = "bnc")]
+    pub cap: f64,
+    /// 维持保证金率
+    #[serde(rename = "mmr")]
+    pub maint_margin_ratio: f64,
+    /// 速算数
+    #[serde(rename = "cf")]
+    pub cum: f64,
+    /// 最低杠杆倍数
+    #[serde(rename = "mi")]
+    pub min_leverage: usize,
+    /// 最高杠杆倍数
+    #[serde(rename = "ma")]
+    pub max_leverage: usize,
+}
@@ -0,0 +1,706 @@
+//! 交易对规则
+//!
+//! 根据 `exchange_info` 返回的过滤器在本地校验和修正下单参数,
+//! 避免请求发出后才收到 `-1013`, `-4164` 等错误。
+//!
+//! ```no_run
+//! # async fn run(client: bian_rs::client::UFuturesHttpClient, mut spec: bian_rs::params::PFuturesOrderSpec) -> bian_rs::error::BianResult<()> {
+//! use bian_rs::rules::{MarketContext, SymbolRulesCache};
+//!
+//! let rules = SymbolRulesCache::from_ufutures(&client.exchange_info().await?);
+//! // 价格按 tickSize 取整, 数量按 stepSize 向下取整, 再校验数量, 价格和名义价值
+//! rules.normalize_futures(&mut spec, &MarketContext::default())?;
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::enums::{FuturesOrderType, SpotOrderType};
+use crate::params::{
+    PBatchModifyOrder, PBatchOrder, PCancelReplace, PFuturesOrder, PFuturesOrderSpec, PMarginOrder,
+    PModifyOrder, PModifyOrderSpec, POcoOrder, POrderListLeg, POrderListOco, POrderListOto,
+    POrderListOtoco, PSpotOrderSpec,
+};
+use crate::response::{self, FuturesSymbolFilter, SpotSymbolFilter};
+
+/// 违反交易对规则的原因
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum RuleViolation {
+    #[error("unknown symbol {0}")]
+    UnknownSymbol(String),
+    #[error("mandatory parameter {0} was not sent")]
+    MissingParam(&'static str),
+    #[error("price {price} out of range [{min}, {max}]")]
+    PriceOutOfRange { price: f64, min: f64, max: f64 },
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    InvalidTickSize { price: f64, tick_size: f64 },
+    #[error("quantity {qty} out of range [{min}, {max}]")]
+    QtyOutOfRange { qty: f64, min: f64, max: f64 },
+    #[error("quantity {qty} is not a multiple of step size {step_size}")]
+    InvalidStepSize { qty: f64, step_size: f64 },
+    #[error("notional {notional} is smaller than {min_notional}")]
+    MinNotional { notional: f64, min_notional: f64 },
+    #[error("price {price} out of percent price range [{min}, {max}]")]
+    PercentPrice { price: f64, min: f64, max: f64 },
+    #[error("open orders reach the limit {0}")]
+    MaxNumOrders(usize),
+}
+
+impl RuleViolation {
+    /// 服务器对应的错误码, 现货违反过滤器时统一返回 -1013 (Filter failure)
+    pub fn code(&self, product: ProductType) -> i64 {
+        match (self, product) {
+            (RuleViolation::UnknownSymbol(_), _) => -1121,
+            (RuleViolation::MissingParam(_), _) => -1102,
+            (RuleViolation::InvalidTickSize { .. }, ProductType::Futures) => -4014,
+            (RuleViolation::InvalidStepSize { .. }, ProductType::Futures) => -1111,
+            (RuleViolation::MinNotional { .. }, ProductType::Futures) => -4164,
+            _ => -1013,
+        }
+    }
+}
+
+/// 规则所属的产品, 决定违反规则时对应的错误码
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProductType {
+    #[default]
+    Spot,
+    /// U本位和币本位合约
+    Futures,
+}
+
+/// 下单时的市场状态
+#[derive(Debug, Clone, Default)]
+pub struct MarketContext {
+    /// 参考价格, 现货为平均价格, 合约为标记价格
+    ///
+    /// 用于校验 `PERCENT_PRICE` 和市价单的名义价值, 为 `None` 时跳过这两项检查
+    pub reference_price: Option<f64>,
+    /// 该交易对当前挂单数量
+    pub open_orders: usize,
+}
+
+/// 单个交易对的规则, 值为 0 表示没有对应限制
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub product: ProductType,
+    pub min_price: f64,
+    pub max_price: f64,
+    pub tick_size: f64,
+    pub min_qty: f64,
+    pub max_qty: f64,
+    pub step_size: f64,
+    pub market_min_qty: f64,
+    pub market_max_qty: f64,
+    pub market_step_size: f64,
+    pub min_notional: f64,
+    /// 市价单是否检查最小名义价值
+    pub apply_min_notional_to_market: bool,
+    pub multiplier_up: f64,
+    pub multiplier_down: f64,
+    pub max_num_orders: usize,
+}
+
+/// 返回 step 的小数位数
+fn decimals(step: f64) -> usize {
+    let mut d = 0;
+    while d < 16 && (step * 10f64.powi(d as i32)).fract().abs() > 1e-9 {
+        d += 1;
+    }
+    d
+}
+
+fn fix_precision(value: f64, step: f64) -> f64 {
+    format!("{:.*}", decimals(step), value)
+        .parse()
+        .unwrap_or(value)
+}
+
+/// 判断 value 是否为 step 的整数倍
+fn is_multiple(value: f64, step: f64) -> bool {
+    if step <= 0.0 {
+        return true;
+    }
+    let n = value / step;
+    (n - n.round()).abs() < 1e-6
+}
+
+fn out_of_range(value: f64, min: f64, max: f64) -> bool {
+    value < min || (max > 0.0 && value > max)
+}
+
+impl SymbolRules {
+    fn from_spot(symbol: &response::SpotSymbol) -> Self {
+        let mut rules = SymbolRules {
+            symbol: symbol.symbol.clone(),
+            apply_min_notional_to_market: true,
+            ..Default::default()
+        };
+        for filter in symbol.filters.iter() {
+            match *filter {
+                SpotSymbolFilter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    rules.min_price = min_price;
+                    rules.max_price = max_price;
+                    rules.tick_size = tick_size;
+                }
+                SpotSymbolFilter::PercentPrice {
+                    multiplier_down,
+                    multiplier_up,
+                    ..
+                } => {
+                    rules.multiplier_down = multiplier_down;
+                    rules.multiplier_up = multiplier_up;
+                }
+                SpotSymbolFilter::LOTSize {
+                    step_size,
+                    max_qty,
+                    min_qty,
+                } => {
+                    rules.min_qty = min_qty;
+                    rules.max_qty = max_qty;
+                    rules.step_size = step_size;
+                }
+                SpotSymbolFilter::MarketLOTSize {
+                    step_size,
+                    max_qty,
+                    min_qty,
+                } => {
+                    rules.market_min_qty = min_qty;
+                    rules.market_max_qty = max_qty;
+                    rules.market_step_size = step_size;
+                }
+                SpotSymbolFilter::MinNotional {
+                    min_notional,
+                    apply_to_market,
+                    ..
+                } => {
+                    rules.min_notional = min_notional;
+                    rules.apply_min_notional_to_market = apply_to_market;
+                }
+                SpotSymbolFilter::MaxNumOrders { max_number_orders } => {
+                    rules.max_num_orders = max_number_orders;
+                }
+                _ => {}
+            }
+        }
+        rules
+    }
+
+    pub(crate) fn from_futures(symbol: &str, filters: &[FuturesSymbolFilter]) -> Self {
+        let mut rules = SymbolRules {
+            symbol: symbol.to_string(),
+            product: ProductType::Futures,
+            apply_min_notional_to_market: true,
+            ..Default::default()
+        };
+        for filter in filters {
+            match *filter {
+                FuturesSymbolFilter::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    rules.min_price = min_price;
+                    rules.max_price = max_price;
+                    rules.tick_size = tick_size;
+                }
+                FuturesSymbolFilter::LOTSize {
+                    step_size,
+                    max_qty,
+                    min_qty,
+                } => {
+                    rules.min_qty = min_qty;
+                    rules.max_qty = max_qty;
+                    rules.step_size = step_size;
+                }
+                FuturesSymbolFilter::MarketLOTSize {
+                    step_size,
+                    max_qty,
+                    min_qty,
+                } => {
+                    rules.market_min_qty = min_qty;
+                    rules.market_max_qty = max_qty;
+                    rules.market_step_size = step_size;
+                }
+                FuturesSymbolFilter::MaxNumOrders { limit } => {
+                    rules.max_num_orders = limit;
+                }
+                FuturesSymbolFilter::MinNotional { notional } => {
+                    rules.min_notional = notional;
+                }
+                FuturesSymbolFilter::PercentPrice {
+                    multiplier_down,
+                    multiplier_up,
+                    ..
+                } => {
+                    rules.multiplier_down = multiplier_down;
+                    rules.multiplier_up = multiplier_up;
+                }
+                _ => {}
+            }
+        }
+        rules
+    }
+
+    /// 市价单数量规则, 未设置 `MARKET_LOT_SIZE` 时使用 `LOT_SIZE`
+    fn lot_size(&self, market: bool) -> (f64, f64, f64) {
+        if market && self.market_step_size > 0.0 {
+            (
+                self.market_min_qty,
+                self.market_max_qty,
+                self.market_step_size,
+            )
+        } else {
+            (self.min_qty, self.max_qty, self.step_size)
+        }
+    }
+
+    /// 价格按 tickSize 四舍五入
+    pub fn round_price(&self, price: f64) -> f64 {
+        if self.tick_size <= 0.0 {
+            return price;
+        }
+        fix_precision(
+            (price / self.tick_size).round() * self.tick_size,
+            self.tick_size,
+        )
+    }
+
+    /// 数量按 stepSize 向下取整
+    pub fn round_qty(&self, qty: f64, market: bool) -> f64 {
+        let (_, _, step_size) = self.lot_size(market);
+        if step_size <= 0.0 {
+            return qty;
+        }
+        fix_precision((qty / step_size + 1e-9).floor() * step_size, step_size)
+    }
+
+    fn check_qty(&self, qty: f64, market: bool) -> Result<(), RuleViolation> {
+        let (min, max, step_size) = self.lot_size(market);
+        if qty <= 0.0 || out_of_range(qty, min, max) {
+            return Err(RuleViolation::QtyOutOfRange { qty, min, max });
+        }
+        if !is_multiple(qty, step_size) {
+            return Err(RuleViolation::InvalidStepSize { qty, step_size });
+        }
+        Ok(())
+    }
+
+    fn check_stop_price(&self, stop_price: f64) -> Result<(), RuleViolation> {
+        if !is_multiple(stop_price, self.tick_size) {
+            return Err(RuleViolation::InvalidTickSize {
+                price: stop_price,
+                tick_size: self.tick_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// 校验订单
+    ///
+    /// - `quantity` 为 `None` 时跳过数量检查, 如按成交额下单或条件全平仓
+    /// - `price` 为 `None` 表示市价单
+    /// - `notional` 为 `None` 时根据数量和价格计算名义价值
+    pub fn check(
+        &self,
+        quantity: Option<f64>,
+        price: Option<f64>,
+        notional: Option<f64>,
+        reduce_only: bool,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        let market = price.is_none();
+        if self.max_num_orders > 0 && ctx.open_orders >= self.max_num_orders {
+            return Err(RuleViolation::MaxNumOrders(self.max_num_orders));
+        }
+        if let Some(price) = price {
+            if out_of_range(price, self.min_price, self.max_price) {
+                return Err(RuleViolation::PriceOutOfRange {
+                    price,
+                    min: self.min_price,
+                    max: self.max_price,
+                });
+            }
+            if !is_multiple(price, self.tick_size) {
+                return Err(RuleViolation::InvalidTickSize {
+                    price,
+                    tick_size: self.tick_size,
+                });
+            }
+            if let Some(reference) = ctx.reference_price {
+                let (min, max) = (
+                    reference * self.multiplier_down,
+                    reference * self.multiplier_up,
+                );
+                if self.multiplier_up > 0.0 && out_of_range(price, min, max) {
+                    return Err(RuleViolation::PercentPrice { price, min, max });
+                }
+            }
+        }
+        if let Some(qty) = quantity {
+            self.check_qty(qty, market)?;
+        }
+        let check_notional = self.min_notional > 0.0
+            && !reduce_only
+            && (!market || self.apply_min_notional_to_market);
+        if check_notional {
+            let notional = notional.or_else(|| {
+                let price = price.or(ctx.reference_price)?;
+                Some(price * quantity?)
+            });
+            if let Some(notional) = notional {
+                if notional < self.min_notional {
+                    return Err(RuleViolation::MinNotional {
+                        notional,
+                        min_notional: self.min_notional,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 校验现货下单参数
+    pub fn validate_spot(
+        &self,
+        spec: &PSpotOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        let price = match spec.order_type {
+            SpotOrderType::Market | SpotOrderType::StopLoss | SpotOrderType::TakeProfit => None,
+            _ => Some(spec.price.ok_or(RuleViolation::MissingParam("price"))?),
+        };
+        if spec.quantity.is_none() && spec.quote_order_qty.is_none() {
+            return Err(RuleViolation::MissingParam("quantity"));
+        }
+        if let Some(stop_price) = spec.stop_price {
+            self.check_stop_price(stop_price)?;
+        }
+        self.check(spec.quantity, price, spec.quote_order_qty, false, ctx)
+    }
+
+    /// 修正现货下单参数的精度后校验
+    pub fn normalize_spot(
+        &self,
+        spec: &mut PSpotOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        let market = matches!(
+            spec.order_type,
+            SpotOrderType::Market | SpotOrderType::StopLoss | SpotOrderType::TakeProfit
+        );
+        spec.price = spec.price.map(|p| self.round_price(p));
+        spec.stop_price = spec.stop_price.map(|p| self.round_price(p));
+        spec.quantity = spec.quantity.map(|q| self.round_qty(q, market));
+        spec.iceberg_qty = spec.iceberg_qty.map(|q| self.round_qty(q, false));
+        self.validate_spot(spec, ctx)
+    }
+
+    /// 校验合约下单参数
+    pub fn validate_futures(
+        &self,
+        spec: &PFuturesOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        let price = match spec.order_type {
+            FuturesOrderType::Limit | FuturesOrderType::Stop | FuturesOrderType::TakeProfit => {
+                Some(spec.price.ok_or(RuleViolation::MissingParam("price"))?)
+            }
+            _ => None,
+        };
+        if spec.order_type != FuturesOrderType::Limit
+            && spec.order_type != FuturesOrderType::Market
+            && spec.order_type != FuturesOrderType::TrailingStopMarket
+        {
+            let stop_price = spec
+                .stop_price
+                .ok_or(RuleViolation::MissingParam("stopPrice"))?;
+            self.check_stop_price(stop_price)?;
+        }
+        let close_position = spec.close_position.unwrap_or(false);
+        let quantity = if close_position {
+            None
+        } else {
+            Some(
+                spec.quantity
+                    .ok_or(RuleViolation::MissingParam("quantity"))?,
+            )
+        };
+        let reduce_only = spec.reduce_only.unwrap_or(false) || close_position;
+        self.check(quantity, price, None, reduce_only, ctx)
+    }
+
+    /// 修正合约下单参数的精度后校验
+    pub fn normalize_futures(
+        &self,
+        spec: &mut PFuturesOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        let market = !matches!(
+            spec.order_type,
+            FuturesOrderType::Limit | FuturesOrderType::Stop | FuturesOrderType::TakeProfit
+        );
+        spec.price = spec.price.map(|p| self.round_price(p));
+        spec.stop_price = spec.stop_price.map(|p| self.round_price(p));
+        spec.activation_price = spec.activation_price.map(|p| self.round_price(p));
+        spec.quantity = spec.quantity.map(|q| self.round_qty(q, market));
+        self.validate_futures(spec, ctx)
+    }
+
+    /// 校验合约改单参数, 修改后的订单仍为限价单
+    pub fn validate_modify(&self, spec: &PModifyOrderSpec) -> Result<(), RuleViolation> {
+        match (spec.quantity, spec.price) {
+            (None, None) => Err(RuleViolation::MissingParam("quantity")),
+            (quantity, Some(price)) => self.check(
+                quantity,
+                Some(price),
+                None,
+                false,
+                &MarketContext::default(),
+            ),
+            (Some(quantity), None) => self.check_qty(quantity, false),
+        }
+    }
+
+    /// 校验订单列表中的单个订单, 止损止盈单需要设置触发价或追踪止损
+    pub fn validate_leg(&self, quantity: f64, leg: &POrderListLeg) -> Result<(), RuleViolation> {
+        let price = match leg.order_type {
+            SpotOrderType::Market | SpotOrderType::StopLoss | SpotOrderType::TakeProfit => None,
+            _ => Some(leg.price.ok_or(RuleViolation::MissingParam("price"))?),
+        };
+        match (leg.order_type, leg.stop_price, leg.trailing_delta) {
+            (_, Some(stop_price), _) => self.check_stop_price(stop_price)?,
+            (
+                SpotOrderType::StopLoss
+                | SpotOrderType::StopLossLimit
+                | SpotOrderType::TakeProfit
+                | SpotOrderType::TakeProfitLimit,
+                None,
+                None,
+            ) => return Err(RuleViolation::MissingParam("stopPrice")),
+            _ => {}
+        }
+        if let Some(iceberg_qty) = leg.iceberg_qty {
+            self.check_qty(iceberg_qty, false)?;
+        }
+        self.check(
+            Some(quantity),
+            price,
+            None,
+            false,
+            &MarketContext::default(),
+        )
+    }
+}
+
+/// 交易对规则缓存
+#[derive(Debug, Clone, Default)]
+pub struct SymbolRulesCache {
+    symbols: HashMap<String, SymbolRules>,
+}
+
+impl SymbolRulesCache {
+    /// 根据现货交易规则创建
+    pub fn from_spot(info: &response::SpotExchangeInfo) -> Self {
+        let symbols = info
+            .symbols
+            .iter()
+            .map(|s| (s.symbol.clone(), SymbolRules::from_spot(s)))
+            .collect();
+        Self { symbols }
+    }
+
+    /// 根据U本位合约交易规则创建
+    pub fn from_ufutures(info: &response::UFuturesExchangeInfo) -> Self {
+        let symbols = info
+            .symbols
+            .iter()
+            .map(|s| {
+                let rules = SymbolRules::from_futures(&s.symbol, &s.filters);
+                (s.symbol.clone(), rules)
+            })
+            .collect();
+        Self { symbols }
+    }
+
+    /// 根据币本位合约交易规则创建
+    pub fn from_dfutures(info: &response::DFuturesExchangeInfo) -> Self {
+        let symbols = info
+            .symbols
+            .iter()
+            .map(|s| {
+                let rules = SymbolRules::from_futures(&s.symbol, &s.filters);
+                (s.symbol.clone(), rules)
+            })
+            .collect();
+        Self { symbols }
+    }
+
+    /// 添加或替换交易对规则
+    pub fn insert(&mut self, rules: SymbolRules) {
+        self.symbols.insert(rules.symbol.clone(), rules);
+    }
+
+    /// 查询交易对规则
+    pub fn get(&self, symbol: &str) -> Result<&SymbolRules, RuleViolation> {
+        self.symbols
+            .get(symbol)
+            .ok_or_else(|| RuleViolation::UnknownSymbol(symbol.to_string()))
+    }
+
+    /// 校验现货下单参数
+    pub fn validate_spot(
+        &self,
+        spec: &PSpotOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        self.get(&spec.symbol)?.validate_spot(spec, ctx)
+    }
+
+    /// 修正现货下单参数的精度后校验
+    pub fn normalize_spot(
+        &self,
+        spec: &mut PSpotOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        self.get(&spec.symbol)?.normalize_spot(spec, ctx)
+    }
+
+    /// 校验合约下单参数
+    pub fn validate_futures(
+        &self,
+        spec: &PFuturesOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        self.get(&spec.symbol)?.validate_futures(spec, ctx)
+    }
+
+    /// 修正合约下单参数的精度后校验
+    pub fn normalize_futures(
+        &self,
+        spec: &mut PFuturesOrderSpec,
+        ctx: &MarketContext,
+    ) -> Result<(), RuleViolation> {
+        self.get(&spec.symbol)?.normalize_futures(spec, ctx)
+    }
+}
+
+/// 可以按交易对规则在本地校验的下单参数, 用于 dry run
+///
+/// 没有规则缓存时使用 [`SymbolRules::default`], 只检查必填参数和数量。
+pub trait ValidateOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation>;
+}
+
+/// 取出交易对规则后执行校验
+fn with_rules(
+    rules: Option<&SymbolRulesCache>,
+    symbol: &str,
+    f: impl FnOnce(&SymbolRules) -> Result<(), RuleViolation>,
+) -> Result<(), RuleViolation> {
+    match rules {
+        Some(cache) => f(cache.get(symbol)?),
+        None => f(&SymbolRules::default()),
+    }
+}
+
+impl ValidateOrder for PFuturesOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.spec.symbol, |r| {
+            r.validate_futures(&self.spec, &MarketContext::default())
+        })
+    }
+}
+
+impl ValidateOrder for PBatchOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        self.batch_orders.iter().try_for_each(|spec| {
+            with_rules(rules, &spec.symbol, |r| {
+                r.validate_futures(spec, &MarketContext::default())
+            })
+        })
+    }
+}
+
+impl ValidateOrder for PModifyOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.spec.symbol, |r| r.validate_modify(&self.spec))
+    }
+}
+
+impl ValidateOrder for PBatchModifyOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        self.batch_orders
+            .iter()
+            .try_for_each(|spec| with_rules(rules, &spec.symbol, |r| r.validate_modify(spec)))
+    }
+}
+
+impl ValidateOrder for PCancelReplace {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.spec.symbol, |r| {
+            r.validate_spot(&self.spec, &MarketContext::default())
+        })
+    }
+}
+
+impl ValidateOrder for PMarginOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.spec.symbol, |r| {
+            r.validate_spot(&self.spec, &MarketContext::default())
+        })
+    }
+}
+
+impl ValidateOrder for POcoOrder {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        let spec = &self.spec;
+        with_rules(rules, &spec.symbol, |r| {
+            let ctx = MarketContext::default();
+            r.check(Some(spec.quantity), Some(spec.price), None, false, &ctx)?;
+            r.check_stop_price(spec.stop_price)?;
+            r.check(
+                Some(spec.quantity),
+                spec.stop_limit_price,
+                None,
+                false,
+                &ctx,
+            )
+        })
+    }
+}
+
+impl ValidateOrder for POrderListOco {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.symbol, |r| {
+            r.validate_leg(self.quantity, &self.above)?;
+            r.validate_leg(self.quantity, &self.below)
+        })
+    }
+}
+
+impl ValidateOrder for POrderListOto {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.symbol, |r| {
+            r.validate_leg(self.working_quantity, &self.working)?;
+            r.validate_leg(self.pending_quantity, &self.pending)
+        })
+    }
+}
+
+impl ValidateOrder for POrderListOtoco {
+    fn validate(&self, rules: Option<&SymbolRulesCache>) -> Result<(), RuleViolation> {
+        with_rules(rules, &self.symbol, |r| {
+            r.validate_leg(self.working_quantity, &self.working)?;
+            r.validate_leg(self.pending_quantity, &self.pending_above)?;
+            r.validate_leg(self.pending_quantity, &self.pending_below)
+        })
+    }
+}
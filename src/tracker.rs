@@ -0,0 +1,378 @@
+//! 本地订单状态跟踪
+//!
+//! [`OrderTracker`] 以 `client_order_id` 为键维护订单的最新状态, 数据来源包括下单返回值,
+//! 用户数据推送 ([`WSOrderUpdate`], [`WSExecutionReport`]) 以及 http 查询。
+//! 推送可能乱序或在断线期间丢失, 因此状态只会向前推进: 已成交数量不会减少, 订单进入终态后不再变化。
+//! 断线重连后调用 [`OrderTracker::reconcile`] 通过 http 接口补齐缺失的状态。
+//!
+//! ```no_run
+//! use bian_rs::client::{UFuturesHttpClient, UFuturesWSClient};
+//! use bian_rs::response::WSUserStream;
+//! use bian_rs::tracker::OrderTracker;
+//! use bian_rs::user_stream::{UFuturesUserDataStream, UserDataEvent};
+//!
+//! # async fn run(http: UFuturesHttpClient, ws: UFuturesWSClient) {
+//! let mut tracker = OrderTracker::new();
+//! let mut stream = UFuturesUserDataStream::start(http.clone(), ws);
+//! while let Some(Ok(event)) = stream.next().await {
+//!     let transitions = match event {
+//!         UserDataEvent::Update(WSUserStream::OrderUpdate(update)) => {
+//!             tracker.apply_order_update(&update).into_iter().collect()
+//!         }
+//!         UserDataEvent::Connected(_) => tracker.reconcile(&http, None).await.unwrap(),
+//!         _ => vec![],
+//!     };
+//!     for transition in transitions {
+//!         println!("{:?}", transition);
+//!     }
+//! }
+//! # }
+//! ```
+use std::collections::HashMap;
+
+use crate::enums::{OrderSide, OrderStatus};
+use crate::error::BianResult;
+use crate::response::{WSExecutionReport, WSOrderUpdate};
+use crate::trading::{Order, OrderExecution};
+
+/// 跟踪中的订单
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedOrder {
+    pub symbol: String,
+    pub client_order_id: String,
+    pub order_id: usize,
+    pub side: OrderSide,
+    pub status: OrderStatus,
+    /// 委托价格, 市价单为 0
+    pub price: f64,
+    /// 委托数量
+    pub quantity: f64,
+    /// 累计成交数量
+    pub executed_qty: f64,
+    /// 成交均价, 未成交时为 0
+    pub avg_price: f64,
+    /// 按资产累计的手续费, 如同时使用 BNB 和 USDT 抵扣时分别累计, 未成交时为空
+    pub fees: HashMap<String, f64>,
+    /// 最后更新时间, 部分接口不返回时为 0
+    pub update_time: i64,
+    /// 存在未通过推送得知的成交, 手续费可能不完整
+    fee_incomplete: bool,
+}
+
+impl TrackedOrder {
+    /// 订单是否已结束
+    pub fn is_finished(&self) -> bool {
+        is_final(self.status)
+    }
+
+    /// 未成交数量
+    pub fn remaining_qty(&self) -> f64 {
+        (self.quantity - self.executed_qty).max(0.0)
+    }
+
+    /// 指定资产的累计手续费
+    pub fn fee(&self, asset: &str) -> f64 {
+        self.fees.get(asset).copied().unwrap_or(0.0)
+    }
+}
+
+/// 订单状态变化
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderTransition {
+    pub client_order_id: String,
+    pub symbol: String,
+    /// 变化前的状态, 首次出现的订单为 `None`
+    pub previous: Option<OrderStatus>,
+    /// 变化后的状态
+    pub status: OrderStatus,
+    /// 本次新增的成交数量
+    pub filled_qty: f64,
+}
+
+/// 订单状态跟踪器
+#[derive(Debug, Default)]
+pub struct OrderTracker {
+    orders: HashMap<String, TrackedOrder>,
+}
+
+/// 一次订单状态更新, 由不同来源的数据转换而来
+struct OrderSnapshot {
+    symbol: String,
+    client_order_id: String,
+    order_id: usize,
+    side: OrderSide,
+    status: OrderStatus,
+    price: f64,
+    quantity: f64,
+    executed_qty: f64,
+    avg_price: f64,
+    update_time: i64,
+    /// 推送中的末次成交
+    last_fill: Option<LastFill>,
+}
+
+struct LastFill {
+    qty: f64,
+    fee: f64,
+    fee_asset: String,
+}
+
+fn is_final(status: OrderStatus) -> bool {
+    !matches!(
+        status,
+        OrderStatus::New
+            | OrderStatus::PartiallyFilled
+            | OrderStatus::PendingNew
+            | OrderStatus::PendingCancel
+            | OrderStatus::NewInsurance
+            | OrderStatus::NewAdl
+    )
+}
+
+fn add_fee(fees: &mut HashMap<String, f64>, asset: &str, fee: f64) {
+    if !asset.is_empty() {
+        *fees.entry(asset.to_string()).or_insert(0.0) += fee;
+    }
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按自定义订单号查询订单
+    pub fn get(&self, client_order_id: &str) -> Option<&TrackedOrder> {
+        self.orders.get(client_order_id)
+    }
+
+    /// 按交易所订单号查询订单
+    pub fn get_by_order_id(&self, symbol: &str, order_id: usize) -> Option<&TrackedOrder> {
+        self.orders
+            .values()
+            .find(|o| o.order_id == order_id && o.symbol == symbol)
+    }
+
+    /// 所有未结束的订单
+    pub fn open_orders(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders.values().filter(|o| !o.is_finished())
+    }
+
+    /// 所有跟踪中的订单, 包括已结束的订单
+    pub fn orders(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders.values()
+    }
+
+    /// 移除已结束的订单并返回
+    pub fn remove_finished(&mut self) -> Vec<TrackedOrder> {
+        let finished: Vec<String> = self
+            .orders
+            .values()
+            .filter(|o| o.is_finished())
+            .map(|o| o.client_order_id.clone())
+            .collect();
+        finished
+            .iter()
+            .filter_map(|id| self.orders.remove(id))
+            .collect()
+    }
+
+    /// 应用下单返回值或 http 查询到的订单
+    pub fn apply_order(&mut self, order: &Order) -> Option<OrderTransition> {
+        self.apply(OrderSnapshot {
+            symbol: order.symbol.clone(),
+            client_order_id: order.client_order_id.clone(),
+            order_id: order.order_id,
+            side: order.side,
+            status: order.status,
+            price: order.price,
+            quantity: order.quantity,
+            executed_qty: order.executed_qty,
+            avg_price: order.avg_price,
+            update_time: order.update_time,
+            last_fill: None,
+        })
+    }
+
+    /// 应用合约订单推送
+    pub fn apply_order_update(&mut self, update: &WSOrderUpdate) -> Option<OrderTransition> {
+        let o = &update.event;
+        let last_fill = if o.x == "TRADE" {
+            Some(LastFill {
+                qty: o.l,
+                fee: o.n,
+                fee_asset: o.N.clone(),
+            })
+        } else {
+            None
+        };
+        self.apply(OrderSnapshot {
+            symbol: o.s.clone(),
+            client_order_id: o.c.clone(),
+            order_id: o.i,
+            side: o.S,
+            status: o.X,
+            price: o.p,
+            quantity: o.q,
+            executed_qty: o.z,
+            avg_price: o.ap,
+            update_time: o.T,
+            last_fill,
+        })
+    }
+
+    /// 应用现货订单推送
+    pub fn apply_execution_report(
+        &mut self,
+        report: &WSExecutionReport,
+    ) -> Option<OrderTransition> {
+        // 撤单推送中 c 为撤单请求的自定义ID, C 为原订单的自定义ID
+        let client_order_id = if report.orig_client_order_id.is_empty() {
+            report.client_order_id.clone()
+        } else {
+            report.orig_client_order_id.clone()
+        };
+        let last_fill = match (&report.commission_asset, report.execution_type.as_str()) {
+            (Some(asset), "TRADE") => Some(LastFill {
+                qty: report.last_qty,
+                fee: report.commission,
+                fee_asset: asset.clone(),
+            }),
+            _ => None,
+        };
+        let avg_price = if report.executed_qty > 0.0 {
+            report.cumulative_quote_qty / report.executed_qty
+        } else {
+            0.0
+        };
+        self.apply(OrderSnapshot {
+            symbol: report.symbol.clone(),
+            client_order_id,
+            order_id: report.order_id,
+            side: report.side,
+            status: report.status,
+            price: report.price,
+            quantity: report.quantity,
+            executed_qty: report.executed_qty,
+            avg_price,
+            update_time: report.transaction_time,
+            last_fill,
+        })
+    }
+
+    /// 通过 http 接口同步订单状态, 用于启动或断线重连后补齐丢失的推送
+    ///
+    /// 查询当前挂单, 对于本地未结束但已不在挂单列表中的订单逐个查询最终状态,
+    /// 并通过成交记录补齐断线期间成交的手续费。`symbol` 为 `None` 时同步所有交易对。
+    pub async fn reconcile<C: OrderExecution + ?Sized>(
+        &mut self,
+        client: &C,
+        symbol: Option<&str>,
+    ) -> BianResult<Vec<OrderTransition>> {
+        let mut transitions = vec![];
+        let open = client.fetch_open_orders(symbol).await?;
+        for order in open.iter() {
+            transitions.extend(self.apply_order(order));
+        }
+        let missing: Vec<(String, usize)> = self
+            .open_orders()
+            .filter(|o| match symbol {
+                Some(s) => s == o.symbol,
+                None => true,
+            })
+            .filter(|o| {
+                !open
+                    .iter()
+                    .any(|r| r.order_id == o.order_id && r.symbol == o.symbol)
+            })
+            .map(|o| (o.symbol.clone(), o.order_id))
+            .collect();
+        for (symbol, order_id) in missing {
+            let order = client.fetch_order(&symbol, order_id).await?;
+            transitions.extend(self.apply_order(&order));
+        }
+
+        let mut symbols: Vec<String> = self
+            .orders
+            .values()
+            .filter(|o| o.fee_incomplete)
+            .map(|o| o.symbol.clone())
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        for symbol in symbols {
+            let fills = client.fetch_fills(&symbol).await?;
+            for order in self.orders.values_mut() {
+                if !order.fee_incomplete || order.symbol != symbol {
+                    continue;
+                }
+                let fills: Vec<_> = fills
+                    .iter()
+                    .filter(|f| f.order_id == order.order_id)
+                    .collect();
+                let qty: f64 = fills.iter().map(|f| f.qty).sum();
+                if !fills.is_empty() {
+                    order.fees.clear();
+                    for fill in fills {
+                        add_fee(&mut order.fees, &fill.fee_asset, fill.fee);
+                    }
+                }
+                order.fee_incomplete = qty + f64::EPSILON < order.executed_qty;
+            }
+        }
+        Ok(transitions)
+    }
+
+    fn apply(&mut self, snapshot: OrderSnapshot) -> Option<OrderTransition> {
+        let previous = self.orders.get(&snapshot.client_order_id).map(|o| o.status);
+        let order = self
+            .orders
+            .entry(snapshot.client_order_id.clone())
+            .or_insert_with(|| TrackedOrder {
+                symbol: snapshot.symbol.clone(),
+                client_order_id: snapshot.client_order_id.clone(),
+                order_id: snapshot.order_id,
+                side: snapshot.side,
+                status: snapshot.status,
+                price: snapshot.price,
+                quantity: snapshot.quantity,
+                executed_qty: 0.0,
+                avg_price: 0.0,
+                fees: HashMap::new(),
+                update_time: snapshot.update_time,
+                fee_incomplete: false,
+            });
+        if let Some(previous) = previous {
+            // 过期的推送或查询结果
+            if is_final(previous) || snapshot.executed_qty < order.executed_qty {
+                return None;
+            }
+            if previous == snapshot.status && snapshot.executed_qty == order.executed_qty {
+                return None;
+            }
+        }
+        let filled_qty = snapshot.executed_qty - order.executed_qty;
+        if filled_qty > 0.0 {
+            match snapshot.last_fill {
+                Some(fill) => {
+                    add_fee(&mut order.fees, &fill.fee_asset, fill.fee);
+                    // 断线期间可能有多次成交
+                    if fill.qty + f64::EPSILON < filled_qty {
+                        order.fee_incomplete = true;
+                    }
+                }
+                None => order.fee_incomplete = true,
+            }
+            order.avg_price = snapshot.avg_price;
+        }
+        order.status = snapshot.status;
+        order.executed_qty = snapshot.executed_qty;
+        order.update_time = order.update_time.max(snapshot.update_time);
+        Some(OrderTransition {
+            client_order_id: order.client_order_id.clone(),
+            symbol: order.symbol.clone(),
+            previous,
+            status: order.status,
+            filled_qty,
+        })
+    }
+}
@@ -0,0 +1,952 @@
+//! 统一交易接口
+//!
+//! 现货, U本位合约和币本位合约的下单参数和返回值各不相同,
+//! 这里提供与产品无关的 [`MarketData`] 和 [`OrderExecution`] trait 以及统一的订单, 成交, 余额和持仓类型,
+//! 策略代码可以对交易场所泛型, 同时适用于真实客户端和 [`PaperExchange`](crate::paper::PaperExchange)。
+//!
+//! ```no_run
+//! use bian_rs::enums::OrderSide;
+//! use bian_rs::error::BianResult;
+//! use bian_rs::trading::{MarketData, Order, OrderExecution, OrderRequest};
+//!
+//! async fn buy_at_bid<C: MarketData + OrderExecution>(client: &C, symbol: &str) -> BianResult<Order> {
+//!     let quote = client.best_quote(symbol).await?;
+//!     let req = OrderRequest::limit(symbol, OrderSide::Buy, 0.01, quote.bid_price);
+//!     client.submit_order(req).await
+//! }
+//! ```
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::client::{DFuturesHttpClient, SpotHttpClient, UFuturesHttpClient};
+use crate::enums::{
+    FuturesNewOrderType, NewOrderType, OrderSide, OrderStatus, PositionDirect, TimeInForce,
+};
+use crate::error::{APIError, BianResult};
+use crate::params::{
+    self, FuturesOrderBuilder, PFuturesOrderSpec, PSpotOrderSpec, PTimestamp, SpotOrderBuilder,
+};
+use crate::response;
+
+/// 订单种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKind {
+    /// 市价单
+    Market,
+    /// 限价单
+    Limit,
+    /// 只做 maker 的限价单, 现货为 `LIMIT_MAKER`, 合约为 `GTX`
+    PostOnly,
+}
+
+/// 下单请求
+#[derive(Debug, Clone)]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub kind: OrderKind,
+    /// 下单数量, 币本位合约为合约张数
+    pub quantity: f64,
+    /// 委托价格, 市价单忽略此字段
+    pub price: Option<f64>,
+    /// 有效方式, 限价单默认 `GTC`
+    pub time_in_force: Option<TimeInForce>,
+    pub client_order_id: Option<String>,
+    /// 只减仓, 现货忽略此字段
+    pub reduce_only: bool,
+    /// 持仓方向, 现货忽略此字段
+    pub position_side: Option<PositionDirect>,
+}
+
+impl OrderRequest {
+    /// 市价单
+    pub fn market(symbol: &str, side: OrderSide, quantity: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            side,
+            kind: OrderKind::Market,
+            quantity,
+            price: None,
+            time_in_force: None,
+            client_order_id: None,
+            reduce_only: false,
+            position_side: None,
+        }
+    }
+
+    /// 限价单
+    pub fn limit(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self {
+            kind: OrderKind::Limit,
+            price: Some(price),
+            ..Self::market(symbol, side, quantity)
+        }
+    }
+
+    /// 只做 maker 的限价单
+    pub fn post_only(symbol: &str, side: OrderSide, quantity: f64, price: f64) -> Self {
+        Self {
+            kind: OrderKind::PostOnly,
+            ..Self::limit(symbol, side, quantity, price)
+        }
+    }
+
+    /// 转换为现货下单参数
+    pub fn to_spot_spec(&self) -> PSpotOrderSpec {
+        let (symbol, side, qty) = (self.symbol.as_str(), self.side, self.quantity);
+        let price = self.price.unwrap_or_default();
+        let mut spec = match self.kind {
+            OrderKind::Market => SpotOrderBuilder::market(symbol, side, qty).build(),
+            OrderKind::Limit => {
+                let time_in_force = self.time_in_force.unwrap_or(TimeInForce::GTC);
+                SpotOrderBuilder::limit(symbol, side, qty, price)
+                    .time_in_force(time_in_force)
+                    .build()
+            }
+            OrderKind::PostOnly => SpotOrderBuilder::limit_maker(symbol, side, qty, price).build(),
+        };
+        spec.new_client_order_id = self.client_order_id.clone();
+        spec.new_order_resp_type = Some(NewOrderType::Full.as_str().to_string());
+        spec
+    }
+
+    /// 转换为合约下单参数
+    pub fn to_futures_spec(&self) -> PFuturesOrderSpec {
+        let (symbol, side, qty) = (self.symbol.as_str(), self.side, self.quantity);
+        let price = self.price.unwrap_or_default();
+        let mut builder = match self.kind {
+            OrderKind::Market => FuturesOrderBuilder::market(symbol, side, qty),
+            OrderKind::Limit => {
+                let time_in_force = self.time_in_force.unwrap_or(TimeInForce::GTC);
+                FuturesOrderBuilder::limit(symbol, side, qty, price, time_in_force)
+            }
+            OrderKind::PostOnly => FuturesOrderBuilder::post_only(symbol, side, qty, price),
+        };
+        if let Some(id) = &self.client_order_id {
+            builder = builder.client_order_id(id.as_str());
+        }
+        if let Some(position_side) = self.position_side {
+            builder = builder.position_side(position_side);
+        }
+        if self.reduce_only {
+            builder = builder.reduce_only();
+        }
+        builder.resp_type(FuturesNewOrderType::Result).build()
+    }
+
+    fn limit_price(&self) -> Option<f64> {
+        match self.kind {
+            OrderKind::Market => None,
+            _ => self.price,
+        }
+    }
+}
+
+/// 下单结果不确定且查询不到订单时, 使用同一自定义订单号重新下单的最大次数
+pub const SUBMIT_RETRIES: usize = 2;
+
+/// 下单结果不确定时, 查询订单前的等待时间, 每次重新下单后翻倍
+///
+/// 超时的请求可能仍在发往撮合引擎的途中, 立即查询会得到订单不存在 (-2013),
+/// 随后重新下单就会重复挂单。
+pub const SUBMIT_QUERY_DELAY: Duration = Duration::from_millis(200);
+
+/// 进程内所有生成器共享的序号, 保证同一毫秒内创建的生成器也不会生成重复的订单号
+static NEXT_ORDER_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// 自定义订单号生成器
+///
+/// 生成的订单号由前缀, 生成器创建时间和进程内自增序号组成, 满足币安对 `newClientOrderId`
+/// 的格式要求 (`^[\.A-Z\:/a-z0-9_-]{1,36}$`)。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientOrderIdGenerator {
+    prefix: String,
+    session: String,
+}
+
+impl ClientOrderIdGenerator {
+    /// 前缀最大长度
+    pub const MAX_PREFIX_LEN: usize = 12;
+
+    /// 前缀超过 [`MAX_PREFIX_LEN`](Self::MAX_PREFIX_LEN) 个字符或包含非法字符时 panic
+    pub fn new(prefix: &str) -> Self {
+        assert!(
+            prefix.len() <= Self::MAX_PREFIX_LEN
+                && prefix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || ".:/_-".contains(c)),
+            "invalid client order id prefix {:?}",
+            prefix
+        );
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        Self {
+            prefix: prefix.to_string(),
+            session: base36(now),
+        }
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// 生成新的订单号
+    pub fn next_id(&self) -> String {
+        let seq = NEXT_ORDER_SEQ.fetch_add(1, Ordering::Relaxed);
+        format!("{}{}_{}", self.prefix, self.session, base36(seq))
+    }
+}
+
+impl Default for ClientOrderIdGenerator {
+    fn default() -> Self {
+        Self::new("bian_")
+    }
+}
+
+fn base36(mut n: u64) -> String {
+    const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let mut buf = vec![];
+    loop {
+        buf.push(DIGITS[(n % 36) as usize]);
+        n /= 36;
+        if n == 0 {
+            break;
+        }
+    }
+    buf.reverse();
+    String::from_utf8(buf).unwrap()
+}
+
+/// 请求可能已被交易所处理, 但无法得知结果的错误
+pub fn is_ambiguous(err: &APIError) -> bool {
+    matches!(
+        err,
+        APIError::RequestError(_) | APIError::ServerSideError(_) | APIError::DecodeError(_)
+    )
+}
+
+/// 交易所返回订单不存在 (-2013)
+fn is_unknown_order(err: &APIError) -> bool {
+    match err {
+        APIError::ClientSideError(body) => {
+            matches!(
+                serde_json::from_str::<serde_json::Value>(body),
+                Ok(v) if v["code"] == -2013
+            )
+        }
+        _ => false,
+    }
+}
+
+/// 下单, 结果不确定时等待 [`SUBMIT_QUERY_DELAY`] 后按自定义订单号查询,
+/// 确认订单不存在后才重新下单, 避免重复挂单
+///
+/// `send` 每次调用都使用相同的自定义订单号下单。
+async fn submit_idempotent<C, F, Fut>(
+    client: &C,
+    symbol: &str,
+    client_order_id: &str,
+    send: F,
+) -> BianResult<Order>
+where
+    C: OrderExecution + ?Sized + Sync,
+    F: Fn() -> Fut + Send,
+    Fut: Future<Output = BianResult<Order>> + Send,
+{
+    let mut retries = 0;
+    loop {
+        let err = match send().await {
+            Err(e) if is_ambiguous(&e) => e,
+            result => return result,
+        };
+        tokio::time::sleep(SUBMIT_QUERY_DELAY * 2u32.pow(retries as u32)).await;
+        match client
+            .fetch_order_by_client_id(symbol, client_order_id)
+            .await
+        {
+            Ok(order) => return Ok(order),
+            Err(e) if is_unknown_order(&e) && retries < SUBMIT_RETRIES => retries += 1,
+            Err(_) => return Err(err),
+        }
+    }
+}
+
+/// 订单
+#[derive(Debug, Clone, PartialEq)]
+pub struct Order {
+    pub symbol: String,
+    pub order_id: usize,
+    pub client_order_id: String,
+    pub side: OrderSide,
+    pub status: OrderStatus,
+    /// 委托价格, 市价单为 0
+    pub price: f64,
+    /// 委托数量
+    pub quantity: f64,
+    /// 已成交数量
+    pub executed_qty: f64,
+    /// 成交均价, 未成交时为 0
+    pub avg_price: f64,
+    /// 更新时间, 部分接口不返回时为 0
+    pub update_time: i64,
+}
+
+/// 成交明细
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fill {
+    pub symbol: String,
+    pub order_id: usize,
+    pub trade_id: usize,
+    pub side: OrderSide,
+    pub price: f64,
+    pub qty: f64,
+    /// 手续费
+    pub fee: f64,
+    /// 手续费资产
+    pub fee_asset: String,
+    /// 是否为挂单方
+    pub maker: bool,
+    /// 已实现盈亏, 现货为 0
+    pub realized_pnl: f64,
+    pub time: i64,
+}
+
+/// 资产余额
+#[derive(Debug, Clone, PartialEq)]
+pub struct Balance {
+    pub asset: String,
+    /// 总余额
+    pub total: f64,
+    /// 可用余额
+    pub available: f64,
+}
+
+/// 持仓
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub symbol: String,
+    pub position_side: PositionDirect,
+    /// 持仓数量, 正数为多, 负数为空
+    pub amount: f64,
+    pub entry_price: f64,
+    pub unrealized_pnl: f64,
+}
+
+/// 最优挂单
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    pub symbol: String,
+    pub bid_price: f64,
+    pub bid_qty: f64,
+    pub ask_price: f64,
+    pub ask_qty: f64,
+}
+
+/// 深度, 每一档为 (价格, 数量)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBook {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+/// 行情接口
+#[async_trait]
+pub trait MarketData {
+    /// 最新成交价
+    async fn last_price(&self, symbol: &str) -> BianResult<f64>;
+
+    /// 最优挂单
+    async fn best_quote(&self, symbol: &str) -> BianResult<Quote>;
+
+    /// 深度
+    async fn order_book(&self, symbol: &str, limit: usize) -> BianResult<OrderBook>;
+}
+
+/// 交易接口
+#[async_trait]
+pub trait OrderExecution {
+    /// 下单
+    async fn submit_order(&self, req: OrderRequest) -> BianResult<Order>;
+
+    /// 撤销订单
+    async fn cancel(&self, symbol: &str, order_id: usize) -> BianResult<Order>;
+
+    /// 查询订单
+    async fn fetch_order(&self, symbol: &str, order_id: usize) -> BianResult<Order>;
+
+    /// 按自定义订单号查询订单
+    async fn fetch_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> BianResult<Order>;
+
+    /// 当前挂单, `symbol` 为 `None` 时查询全部交易对
+    async fn fetch_open_orders(&self, symbol: Option<&str>) -> BianResult<Vec<Order>>;
+
+    /// 最近成交
+    async fn fetch_fills(&self, symbol: &str) -> BianResult<Vec<Fill>>;
+
+    /// 账户余额
+    async fn fetch_balances(&self) -> BianResult<Vec<Balance>>;
+
+    /// 交易对持仓, 现货总是返回空
+    async fn fetch_positions(&self, symbol: &str) -> BianResult<Vec<Position>>;
+}
+
+fn book(depth: Vec<response::DepthOrder>) -> Vec<(f64, f64)> {
+    depth.into_iter().map(|d| (d.0, d.1)).collect()
+}
+
+fn avg_price(quote: f64, qty: f64) -> f64 {
+    if qty > 0.0 {
+        quote / qty
+    } else {
+        0.0
+    }
+}
+
+fn user_trade(symbol: &str) -> params::PUserTrade {
+    params::PUserTrade {
+        symbol: symbol.to_string(),
+        start_time: None,
+        end_time: None,
+        from_id: None,
+        limit: None,
+        ts: PTimestamp::now(),
+    }
+}
+
+impl From<response::FuturesOrder> for Order {
+    fn from(o: response::FuturesOrder) -> Self {
+        Self {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: o.side,
+            status: o.status,
+            price: o.price,
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty.max(o.cum_qty),
+            avg_price: o.avg_price,
+            update_time: o.update_time,
+        }
+    }
+}
+
+impl From<response::QuerySpotOrder> for Order {
+    fn from(o: response::QuerySpotOrder) -> Self {
+        Self {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: o.side,
+            status: o.status,
+            price: o.price,
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty,
+            avg_price: avg_price(o.cummulative_quote_qty, o.executed_qty),
+            update_time: o.update_time,
+        }
+    }
+}
+
+impl From<response::SpotOpOrder> for Order {
+    fn from(o: response::SpotOpOrder) -> Self {
+        Self {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.orig_client_order_id,
+            side: o.side,
+            status: o.status,
+            price: o.price,
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty,
+            avg_price: avg_price(o.cummulative_quote_qty, o.executed_qty),
+            update_time: 0,
+        }
+    }
+}
+
+impl From<response::UserFuturesTrade> for Fill {
+    fn from(t: response::UserFuturesTrade) -> Self {
+        Self {
+            symbol: t.symbol,
+            order_id: t.order_id,
+            trade_id: t.id,
+            side: t.side,
+            price: t.price,
+            qty: t.qty,
+            fee: t.commission,
+            fee_asset: t.commission_asset,
+            maker: t.maker,
+            realized_pnl: t.realized_pnl,
+            time: t.time,
+        }
+    }
+}
+
+impl From<response::UserSpotTrade> for Fill {
+    fn from(t: response::UserSpotTrade) -> Self {
+        Self {
+            symbol: t.symbol,
+            order_id: t.order_id,
+            trade_id: t.id,
+            side: if t.is_buyer {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            },
+            price: t.price,
+            qty: t.qty,
+            fee: t.commission,
+            fee_asset: t.commission_asset,
+            maker: t.is_maker,
+            realized_pnl: 0.0,
+            time: t.time,
+        }
+    }
+}
+
+impl From<response::PositionRisk> for Position {
+    fn from(p: response::PositionRisk) -> Self {
+        Self {
+            symbol: p.symbol,
+            position_side: p.position_side,
+            amount: p.position_amt,
+            entry_price: p.entry_price,
+            unrealized_pnl: p.un_realized_profit,
+        }
+    }
+}
+
+/// 将现货下单返回值转换为订单, `ACK` 返回中缺少的字段从请求中补全
+fn spot_order(resp: response::SpotOrder, req: &OrderRequest) -> Order {
+    match resp {
+        response::SpotOrder::Full(o) => Order {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: o.side,
+            status: o.status,
+            price: o.price,
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty,
+            avg_price: avg_price(o.cummulative_quote_qty, o.executed_qty),
+            update_time: o.transact_time,
+        },
+        response::SpotOrder::Result(o) => Order {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: o.side,
+            status: o.status,
+            price: o.price,
+            quantity: o.orig_qty,
+            executed_qty: o.executed_qty,
+            avg_price: avg_price(o.cummulative_quote_qty, o.executed_qty),
+            update_time: o.transact_time,
+        },
+        response::SpotOrder::Ack(o) => Order {
+            symbol: o.symbol,
+            order_id: o.order_id,
+            client_order_id: o.client_order_id,
+            side: req.side,
+            status: OrderStatus::New,
+            price: req.limit_price().unwrap_or_default(),
+            quantity: req.quantity,
+            executed_qty: 0.0,
+            avg_price: 0.0,
+            update_time: o.transact_time,
+        },
+    }
+}
+
+#[async_trait]
+impl MarketData for SpotHttpClient {
+    async fn last_price(&self, symbol: &str) -> BianResult<f64> {
+        let param = params::PSymbol {
+            symbol: symbol.to_string(),
+        };
+        Ok(self.price(param).await?.price)
+    }
+
+    async fn best_quote(&self, symbol: &str) -> BianResult<Quote> {
+        let param = params::PSymbol {
+            symbol: symbol.to_string(),
+        };
+        let t = self.book_ticker(param).await?;
+        Ok(Quote {
+            symbol: t.symbol,
+            bid_price: t.bid_price,
+            bid_qty: t.bid_qty,
+            ask_price: t.ask_price,
+            ask_qty: t.ask_qty,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, limit: usize) -> BianResult<OrderBook> {
+        let param = params::PDepth {
+            symbol: symbol.to_string(),
+            limit,
+        };
+        let depth = self.depth(param).await?;
+        Ok(OrderBook {
+            bids: book(depth.bids),
+            asks: book(depth.asks),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderExecution for SpotHttpClient {
+    async fn submit_order(&self, mut req: OrderRequest) -> BianResult<Order> {
+        let client_order_id = req
+            .client_order_id
+            .get_or_insert_with(|| self.client_order_ids.next_id())
+            .clone();
+        let req = &req;
+        submit_idempotent(self, &req.symbol, &client_order_id, move || async move {
+            let param = params::PSpotOrder {
+                spec: req.to_spot_spec(),
+                ts: PTimestamp::now(),
+            };
+            let resp = self.order(param).await?;
+            Ok(spot_order(resp, req))
+        })
+        .await
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQuerySpotOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.cancel_order(param).await?.into())
+    }
+
+    async fn fetch_order(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQuerySpotOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.list_order(param).await?.into())
+    }
+
+    async fn fetch_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> BianResult<Order> {
+        let param = params::PQuerySpotOrder {
+            symbol: symbol.to_string(),
+            order_id: None,
+            orig_client_order_id: Some(client_order_id.to_string()),
+            new_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.list_order(param).await?.into())
+    }
+
+    async fn fetch_open_orders(&self, symbol: Option<&str>) -> BianResult<Vec<Order>> {
+        let param = params::POptionSymbolQuery {
+            symbol: symbol.map(String::from),
+            ts: PTimestamp::now(),
+        };
+        let orders = self.open_orders(param).await?;
+        Ok(orders.into_iter().map(Order::from).collect())
+    }
+
+    async fn fetch_fills(&self, symbol: &str) -> BianResult<Vec<Fill>> {
+        let trades = self.my_trades(user_trade(symbol)).await?;
+        Ok(trades.into_iter().map(Fill::from).collect())
+    }
+
+    async fn fetch_balances(&self) -> BianResult<Vec<Balance>> {
+        let account = self.account(PTimestamp::now()).await?;
+        let balances = account
+            .balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.asset,
+                total: b.free + b.locked,
+                available: b.free,
+            })
+            .collect();
+        Ok(balances)
+    }
+
+    async fn fetch_positions(&self, _symbol: &str) -> BianResult<Vec<Position>> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl MarketData for UFuturesHttpClient {
+    async fn last_price(&self, symbol: &str) -> BianResult<f64> {
+        let param = params::PSymbol {
+            symbol: symbol.to_string(),
+        };
+        Ok(self.price(param).await?.price)
+    }
+
+    async fn best_quote(&self, symbol: &str) -> BianResult<Quote> {
+        let param = params::PSymbol {
+            symbol: symbol.to_string(),
+        };
+        let t = self.book_ticker(param).await?;
+        Ok(Quote {
+            symbol: t.symbol,
+            bid_price: t.bid_price,
+            bid_qty: t.bid_qty,
+            ask_price: t.ask_price,
+            ask_qty: t.ask_qty,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, limit: usize) -> BianResult<OrderBook> {
+        let param = params::PDepth {
+            symbol: symbol.to_string(),
+            limit,
+        };
+        let depth = self.depth(param).await?;
+        Ok(OrderBook {
+            bids: book(depth.bids),
+            asks: book(depth.asks),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderExecution for UFuturesHttpClient {
+    async fn submit_order(&self, mut req: OrderRequest) -> BianResult<Order> {
+        let client_order_id = req
+            .client_order_id
+            .get_or_insert_with(|| self.client_order_ids.next_id())
+            .clone();
+        let req = &req;
+        submit_idempotent(self, &req.symbol, &client_order_id, move || async move {
+            let param = params::PFuturesOrder {
+                spec: req.to_futures_spec(),
+                ts: PTimestamp::now(),
+            };
+            Ok(self.order(param).await?.into())
+        })
+        .await
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.cancel_order(param).await?.into())
+    }
+
+    async fn fetch_order(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.query_order(param).await?.into())
+    }
+
+    async fn fetch_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: None,
+            orig_client_order_id: Some(client_order_id.to_string()),
+            ts: PTimestamp::now(),
+        };
+        Ok(self.query_order(param).await?.into())
+    }
+
+    async fn fetch_open_orders(&self, symbol: Option<&str>) -> BianResult<Vec<Order>> {
+        let param = params::POptionSymbolQuery {
+            symbol: symbol.map(String::from),
+            ts: PTimestamp::now(),
+        };
+        let orders = self.open_orders(param).await?;
+        Ok(orders.into_iter().map(Order::from).collect())
+    }
+
+    async fn fetch_fills(&self, symbol: &str) -> BianResult<Vec<Fill>> {
+        let trades = self.user_trades(user_trade(symbol)).await?;
+        Ok(trades.into_iter().map(Fill::from).collect())
+    }
+
+    async fn fetch_balances(&self) -> BianResult<Vec<Balance>> {
+        let balances = self.account_balance_v2(PTimestamp::now()).await?;
+        let balances = balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.asset,
+                total: b.balance,
+                available: b.available_balance,
+            })
+            .collect();
+        Ok(balances)
+    }
+
+    async fn fetch_positions(&self, symbol: &str) -> BianResult<Vec<Position>> {
+        let param = params::PSymbolWithTs {
+            symbol: symbol.to_string(),
+            ts: PTimestamp::now(),
+        };
+        let positions = self.position_risk(param).await?;
+        Ok(positions.into_iter().map(Position::from).collect())
+    }
+}
+
+#[async_trait]
+impl MarketData for DFuturesHttpClient {
+    async fn last_price(&self, symbol: &str) -> BianResult<f64> {
+        let param = params::PSymbolPair {
+            symbol: Some(symbol.to_string()),
+            pair: None,
+        };
+        self.price(param)
+            .await?
+            .into_iter()
+            .find(|p| p.symbol == symbol)
+            .map(|p| p.price)
+            .ok_or_else(|| APIError::NoMarketData(symbol.to_string()))
+    }
+
+    async fn best_quote(&self, symbol: &str) -> BianResult<Quote> {
+        let param = params::PSymbolPair {
+            symbol: Some(symbol.to_string()),
+            pair: None,
+        };
+        let t = self
+            .book_ticker(param)
+            .await?
+            .into_iter()
+            .find(|t| t.symbol == symbol)
+            .ok_or_else(|| APIError::NoMarketData(symbol.to_string()))?;
+        Ok(Quote {
+            symbol: t.symbol,
+            bid_price: t.bid_price,
+            bid_qty: t.bid_qty,
+            ask_price: t.ask_price,
+            ask_qty: t.ask_qty,
+        })
+    }
+
+    async fn order_book(&self, symbol: &str, limit: usize) -> BianResult<OrderBook> {
+        let param = params::PDepth {
+            symbol: symbol.to_string(),
+            limit,
+        };
+        let depth = self.depth(param).await?;
+        Ok(OrderBook {
+            bids: book(depth.bids),
+            asks: book(depth.asks),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderExecution for DFuturesHttpClient {
+    async fn submit_order(&self, mut req: OrderRequest) -> BianResult<Order> {
+        let client_order_id = req
+            .client_order_id
+            .get_or_insert_with(|| self.client_order_ids.next_id())
+            .clone();
+        let req = &req;
+        submit_idempotent(self, &req.symbol, &client_order_id, move || async move {
+            let param = params::PFuturesOrder {
+                spec: req.to_futures_spec(),
+                ts: PTimestamp::now(),
+            };
+            Ok(self.order(param).await?.into())
+        })
+        .await
+    }
+
+    async fn cancel(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.cancel_order(param).await?.into())
+    }
+
+    async fn fetch_order(&self, symbol: &str, order_id: usize) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: Some(order_id),
+            orig_client_order_id: None,
+            ts: PTimestamp::now(),
+        };
+        Ok(self.query_order(param).await?.into())
+    }
+
+    async fn fetch_order_by_client_id(
+        &self,
+        symbol: &str,
+        client_order_id: &str,
+    ) -> BianResult<Order> {
+        let param = params::PQueryFuturesOrder {
+            symbol: symbol.to_string(),
+            order_id: None,
+            orig_client_order_id: Some(client_order_id.to_string()),
+            ts: PTimestamp::now(),
+        };
+        Ok(self.query_order(param).await?.into())
+    }
+
+    async fn fetch_open_orders(&self, symbol: Option<&str>) -> BianResult<Vec<Order>> {
+        let param = params::POptionSymbolQuery {
+            symbol: symbol.map(String::from),
+            ts: PTimestamp::now(),
+        };
+        let orders = self.open_orders(param).await?;
+        Ok(orders.into_iter().map(Order::from).collect())
+    }
+
+    async fn fetch_fills(&self, symbol: &str) -> BianResult<Vec<Fill>> {
+        let trades = self.user_trades(user_trade(symbol)).await?;
+        Ok(trades.into_iter().map(Fill::from).collect())
+    }
+
+    async fn fetch_balances(&self) -> BianResult<Vec<Balance>> {
+        let balances = self.account_balance(PTimestamp::now()).await?;
+        let balances = balances
+            .into_iter()
+            .map(|b| Balance {
+                asset: b.asset,
+                total: b.balance,
+                available: b.available_balance,
+            })
+            .collect();
+        Ok(balances)
+    }
+
+    async fn fetch_positions(&self, symbol: &str) -> BianResult<Vec<Position>> {
+        let param = params::PSymbolWithTs {
+            symbol: symbol.to_string(),
+            ts: PTimestamp::now(),
+        };
+        let positions = self.position_risk(param).await?;
+        Ok(positions.into_iter().map(Position::from).collect())
+    }
+}
@@ -0,0 +1,529 @@
+//! 自动管理 listenKey 的用户数据推送
+//!
+//! 直接使用 `user_data` 时需要手动生成 listenKey, 并每 30 分钟调用一次 `update_listen_key` 续期,
+//! 否则连接会在 listenKey 过期后中断。[`UserDataStream`] 会在后台定时续期, 并在 listenKey 过期,
+//! 续期失败或连接断开时重新生成 listenKey 并重连, drop 时关闭 listenKey。
+//!
+//! ```no_run
+//! use bian_rs::client::{UFuturesHttpClient, UFuturesWSClient};
+//! use bian_rs::user_stream::{UFuturesUserDataStream, UserDataEvent};
+//!
+//! # async fn run(http: UFuturesHttpClient, ws: UFuturesWSClient) {
+//! let mut stream = UFuturesUserDataStream::start(http, ws);
+//! while let Some(event) = stream.next().await {
+//!     match event {
+//!         Ok(UserDataEvent::Update(update)) => println!("{:?}", update),
+//!         Ok(event) => println!("{:?}", event),
+//!         Err(e) => eprintln!("{}", e),
+//!     }
+//! }
+//! # }
+//! ```
+use std::io::ErrorKind;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tungstenite::client::{connect_with_config, AutoGenericStream};
+use tungstenite::stream::Stream;
+use tungstenite::{Message, WebSocket};
+
+use crate::client::{
+    DFuturesHttpClient, DFuturesWSClient, MarginHttpClient, OptionsHttpClient, OptionsWSClient,
+    PortfolioMarginHttpClient, PortfolioMarginWSClient, SpotHttpClient, SpotWSClient,
+    UFuturesHttpClient, UFuturesWSClient,
+};
+use crate::error::{APIError, BianResult};
+use crate::params::{PIsolatedListenKey, PListenKey, PSymbol};
+use crate::response;
+
+/// listenKey 续期间隔, listenKey 有效期为 60 分钟
+pub const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// 生成 listenKey 或建立连接失败后的重试间隔
+pub const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 读取推送的超时时间, 超时后检查管理任务是否已退出
+const SOCKET_READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+const LISTEN_KEY_EXPIRED: &str = "listenKeyExpired";
+
+/// 现货用户数据推送
+pub type SpotUserDataStream = UserDataStream<SpotHttpClient, SpotWSClient>;
+/// U本位合约用户数据推送
+pub type UFuturesUserDataStream = UserDataStream<UFuturesHttpClient, UFuturesWSClient>;
+/// 币本位合约用户数据推送
+pub type DFuturesUserDataStream = UserDataStream<DFuturesHttpClient, DFuturesWSClient>;
+/// 欧式期权用户数据推送
+pub type OptionsUserDataStream = UserDataStream<OptionsHttpClient, OptionsWSClient>;
+/// 全仓杠杆用户数据推送, 推送格式与现货相同
+pub type MarginUserDataStream = UserDataStream<MarginHttpClient, SpotWSClient>;
+/// 逐仓杠杆用户数据推送, 推送格式与现货相同
+pub type IsolatedMarginUserDataStream = UserDataStream<IsolatedMarginListenKey, SpotWSClient>;
+/// 统一账户用户数据推送, 包括合约和杠杆账户
+pub type PortfolioMarginUserDataStream =
+    UserDataStream<PortfolioMarginHttpClient, PortfolioMarginWSClient>;
+
+/// listenKey 相关 http 接口
+#[async_trait]
+pub trait ListenKeyClient: Clone + Send + Sync + 'static {
+    /// 生成 listenKey, 已存在有效的 listenKey 时返回该 listenKey
+    async fn start_user_stream(&self) -> BianResult<String>;
+
+    /// 延长 listenKey 有效期
+    async fn keepalive_user_stream(&self, listen_key: &str) -> BianResult<()>;
+
+    /// 关闭 listenKey
+    async fn close_user_stream(&self, listen_key: &str) -> BianResult<()>;
+}
+
+/// 建立用户数据 websocket 连接
+pub trait UserDataConnector: Clone + Send + 'static {
+    /// 推送的事件类型
+    type Event: DeserializeOwned + Send + 'static;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>>;
+}
+
+/// 重连原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectReason {
+    /// 收到 `listenKeyExpired` 事件
+    ListenKeyExpired,
+    /// 续期 listenKey 失败
+    KeepaliveFailed,
+    /// 连接断开或建立连接失败
+    Disconnected,
+}
+
+/// 用户数据推送事件
+#[derive(Debug)]
+pub enum UserDataEvent<E> {
+    /// 使用新的 listenKey 建立连接
+    Connected(String),
+    /// 用户数据更新
+    Update(E),
+    /// 即将重新生成 listenKey 并重连, 重连前的推送可能丢失, 需要通过 http 接口同步状态
+    Reconnecting(ReconnectReason),
+}
+
+/// 自动续期和重连的用户数据推送, 需要在 tokio runtime 中使用
+pub struct UserDataStream<H: ListenKeyClient, W: UserDataConnector> {
+    http: H,
+    events: mpsc::UnboundedReceiver<BianResult<UserDataEvent<W::Event>>>,
+    listen_key: Arc<Mutex<Option<String>>>,
+    task: JoinHandle<()>,
+}
+
+impl<H: ListenKeyClient, W: UserDataConnector> UserDataStream<H, W> {
+    /// 启动推送, 每 30 分钟续期一次 listenKey
+    pub fn start(http: H, ws: W) -> Self {
+        Self::with_keepalive_interval(http, ws, KEEPALIVE_INTERVAL)
+    }
+
+    /// 使用自定义续期间隔启动推送
+    pub fn with_keepalive_interval(http: H, ws: W, keepalive_interval: Duration) -> Self {
+        let (tx, events) = mpsc::unbounded_channel();
+        let listen_key = Arc::new(Mutex::new(None));
+        let task = tokio::spawn(drive(
+            http.clone(),
+            ws,
+            keepalive_interval,
+            listen_key.clone(),
+            tx,
+        ));
+        Self {
+            http,
+            events,
+            listen_key,
+            task,
+        }
+    }
+
+    /// 当前使用的 listenKey
+    pub fn listen_key(&self) -> Option<String> {
+        self.listen_key.lock().unwrap().clone()
+    }
+
+    /// 下一个事件, 推送不会主动结束, 错误不影响后续推送
+    pub async fn next(&mut self) -> Option<BianResult<UserDataEvent<W::Event>>> {
+        self.events.recv().await
+    }
+
+    /// 停止推送并关闭 listenKey
+    pub async fn close(self) -> BianResult<()> {
+        self.task.abort();
+        let listen_key = self.listen_key.lock().unwrap().take();
+        match listen_key {
+            Some(listen_key) => self.http.close_user_stream(&listen_key).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl<H: ListenKeyClient, W: UserDataConnector> Drop for UserDataStream<H, W> {
+    fn drop(&mut self) {
+        self.task.abort();
+        let listen_key = self.listen_key.lock().unwrap().take();
+        if let (Some(listen_key), Ok(handle)) = (listen_key, tokio::runtime::Handle::try_current())
+        {
+            let http = self.http.clone();
+            handle.spawn(async move {
+                if let Err(e) = http.close_user_stream(&listen_key).await {
+                    log::warn!("close listenKey failed: {}", e);
+                }
+            });
+        }
+    }
+}
+
+enum SocketMessage {
+    Open,
+    Text(String),
+    Error(APIError),
+}
+
+#[derive(Deserialize)]
+struct EventType {
+    #[serde(rename = "e")]
+    event_type: String,
+}
+
+fn is_listen_key_expired(text: &str) -> bool {
+    matches!(
+        serde_json::from_str::<EventType>(text),
+        Ok(e) if e.event_type == LISTEN_KEY_EXPIRED
+    )
+}
+
+async fn drive<H: ListenKeyClient, W: UserDataConnector>(
+    http: H,
+    ws: W,
+    keepalive_interval: Duration,
+    current_key: Arc<Mutex<Option<String>>>,
+    tx: mpsc::UnboundedSender<BianResult<UserDataEvent<W::Event>>>,
+) {
+    loop {
+        let listen_key = match http.start_user_stream().await {
+            Ok(listen_key) => listen_key,
+            Err(e) => {
+                tx.send(Err(e)).ok();
+                tokio::time::sleep(RETRY_INTERVAL).await;
+                continue;
+            }
+        };
+        *current_key.lock().unwrap() = Some(listen_key.clone());
+
+        let (socket_tx, mut socket_rx) = mpsc::unbounded_channel();
+        let (connector, key) = (ws.clone(), listen_key.clone());
+        // tungstenite 为阻塞 IO, 在单独的线程中读取
+        thread::spawn(move || read_socket(connector, key, socket_tx));
+
+        let mut opened = false;
+        let start = tokio::time::Instant::now() + keepalive_interval;
+        let mut keepalive = tokio::time::interval_at(start, keepalive_interval);
+        let reason = loop {
+            tokio::select! {
+                _ = keepalive.tick() => {
+                    if let Err(e) = http.keepalive_user_stream(&listen_key).await {
+                        tx.send(Err(e)).ok();
+                        break ReconnectReason::KeepaliveFailed;
+                    }
+                }
+                msg = socket_rx.recv() => match msg {
+                    Some(SocketMessage::Open) => {
+                        opened = true;
+                        tx.send(Ok(UserDataEvent::Connected(listen_key.clone()))).ok();
+                    }
+                    Some(SocketMessage::Text(text)) => {
+                        if is_listen_key_expired(&text) {
+                            break ReconnectReason::ListenKeyExpired;
+                        }
+                        let event = serde_json::from_str(&text)
+                            .map(UserDataEvent::Update)
+                            .map_err(|e| APIError::DecodeError(e.to_string()));
+                        tx.send(event).ok();
+                    }
+                    Some(SocketMessage::Error(e)) => {
+                        tx.send(Err(e)).ok();
+                    }
+                    None => break ReconnectReason::Disconnected,
+                },
+            }
+        };
+        tx.send(Ok(UserDataEvent::Reconnecting(reason))).ok();
+        if !opened {
+            tokio::time::sleep(RETRY_INTERVAL).await;
+        }
+    }
+}
+
+/// 读取推送直到连接断开, listenKey 过期或管理任务退出
+fn read_socket<W: UserDataConnector>(
+    ws: W,
+    listen_key: String,
+    tx: mpsc::UnboundedSender<SocketMessage>,
+) {
+    let mut socket = match ws.connect_user_data(&listen_key) {
+        Ok(socket) => socket,
+        Err(e) => {
+            tx.send(SocketMessage::Error(e)).ok();
+            return;
+        }
+    };
+    if let Err(e) = set_read_timeout(&socket, SOCKET_READ_TIMEOUT) {
+        log::warn!("set user data socket read timeout failed: {}", e);
+    }
+    if tx.send(SocketMessage::Open).is_err() {
+        socket.close(None).ok();
+        return;
+    }
+    loop {
+        // 管理任务退出后不再接收消息, 没有推送时也要及时结束线程
+        if tx.is_closed() {
+            socket.close(None).ok();
+            return;
+        }
+        match socket.read_message() {
+            Ok(Message::Text(text)) => {
+                let expired = is_listen_key_expired(&text);
+                if tx.send(SocketMessage::Text(text)).is_err() || expired {
+                    socket.close(None).ok();
+                    return;
+                }
+            }
+            Ok(Message::Ping(payload)) => {
+                socket.write_message(Message::Pong(payload)).ok();
+            }
+            Ok(Message::Close(_)) => return,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(e))
+                if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(e) => {
+                tx.send(SocketMessage::Error(APIError::WSClientError(e.to_string())))
+                    .ok();
+                return;
+            }
+        }
+    }
+}
+
+fn set_read_timeout(
+    socket: &WebSocket<AutoGenericStream>,
+    timeout: Duration,
+) -> std::io::Result<()> {
+    match socket.get_ref() {
+        Stream::Plain(stream) => stream.set_read_timeout(Some(timeout)),
+        Stream::Tls(stream) => stream.get_ref().set_read_timeout(Some(timeout)),
+    }
+}
+
+fn connect(
+    base_url: &url::Url,
+    proxy: Option<std::net::SocketAddr>,
+    listen_key: &str,
+) -> BianResult<WebSocket<AutoGenericStream>> {
+    let url = base_url
+        .join(&format!("ws/{}", listen_key))
+        .map_err(|e| APIError::InvalidUrl(e.to_string()))?;
+    let (socket, _) = connect_with_config(url, None, 3, proxy)
+        .map_err(|e| APIError::WSConnectError(e.to_string()))?;
+    Ok(socket)
+}
+
+#[async_trait]
+impl ListenKeyClient for SpotHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        let param = PListenKey {
+            listen_key: listen_key.to_string(),
+        };
+        self.update_listen_key(param).await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        let param = PListenKey {
+            listen_key: listen_key.to_string(),
+        };
+        self.close_listen_key(param).await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for UFuturesHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.update_listen_key().await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.close_listen_key().await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for DFuturesHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.update_listen_key().await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.close_listen_key().await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for OptionsHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.update_listen_key().await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.close_listen_key().await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for MarginHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        let param = PListenKey {
+            listen_key: listen_key.to_string(),
+        };
+        self.update_listen_key(param).await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        let param = PListenKey {
+            listen_key: listen_key.to_string(),
+        };
+        self.close_listen_key(param).await.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for PortfolioMarginHttpClient {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        Ok(self.create_listen_key().await?.listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.update_listen_key().await.map(|_| ())
+    }
+
+    async fn close_user_stream(&self, _listen_key: &str) -> BianResult<()> {
+        self.close_listen_key().await.map(|_| ())
+    }
+}
+
+/// 逐仓杠杆交易对的 listenKey 接口
+#[derive(Debug, Clone)]
+pub struct IsolatedMarginListenKey {
+    pub client: MarginHttpClient,
+    pub symbol: String,
+}
+
+impl IsolatedMarginListenKey {
+    pub fn new(client: MarginHttpClient, symbol: &str) -> Self {
+        Self {
+            client,
+            symbol: symbol.to_string(),
+        }
+    }
+
+    fn param(&self, listen_key: &str) -> PIsolatedListenKey {
+        PIsolatedListenKey {
+            symbol: self.symbol.clone(),
+            listen_key: listen_key.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl ListenKeyClient for IsolatedMarginListenKey {
+    async fn start_user_stream(&self) -> BianResult<String> {
+        let param = PSymbol {
+            symbol: self.symbol.clone(),
+        };
+        Ok(self
+            .client
+            .create_isolated_listen_key(param)
+            .await?
+            .listen_key)
+    }
+
+    async fn keepalive_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        self.client
+            .update_isolated_listen_key(self.param(listen_key))
+            .await
+            .map(|_| ())
+    }
+
+    async fn close_user_stream(&self, listen_key: &str) -> BianResult<()> {
+        self.client
+            .close_isolated_listen_key(self.param(listen_key))
+            .await
+            .map(|_| ())
+    }
+}
+
+impl UserDataConnector for SpotWSClient {
+    type Event = response::WSSpotUserStream;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>> {
+        connect(&self.base_url, self.proxy, listen_key)
+    }
+}
+
+impl UserDataConnector for UFuturesWSClient {
+    type Event = response::WSUserStream;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>> {
+        connect(&self.base_url, self.proxy, listen_key)
+    }
+}
+
+impl UserDataConnector for DFuturesWSClient {
+    type Event = response::WSUserStream;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>> {
+        connect(&self.base_url, self.proxy, listen_key)
+    }
+}
+
+impl UserDataConnector for OptionsWSClient {
+    type Event = response::WSOptionsUserStream;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>> {
+        connect(&self.base_url, self.proxy, listen_key)
+    }
+}
+
+impl UserDataConnector for PortfolioMarginWSClient {
+    type Event = response::WSPortfolioMarginUserStream;
+
+    fn connect_user_data(&self, listen_key: &str) -> BianResult<WebSocket<AutoGenericStream>> {
+        connect(&self.base_url, self.proxy, listen_key)
+    }
+}
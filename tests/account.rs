@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use bian_rs::account::{AccountSnapshot, AccountState, AssetBalance, PositionState};
+use bian_rs::enums::{MarginType, PositionDirect};
+use bian_rs::response::{WSPrice, WSSpotUserStream, WSUserStream};
+
+const FUTURES_EVENTS: &str = include_str!("../src/mock/fixtures/ufutures/ws_user_data.jsonl");
+const SPOT_EVENTS: &str = include_str!("../src/mock/fixtures/spot/ws_user_data.jsonl");
+
+fn mark_price(symbol: &str, price: f64) -> WSPrice {
+    serde_json::from_value(serde_json::json!({
+        "e": "markPriceUpdate", "E": 1, "s": symbol, "p": price.to_string(),
+        "i": "0", "P": "0", "r": "0", "T": 1
+    }))
+    .unwrap()
+}
+
+fn position(symbol: &str, side: PositionDirect, amount: f64, entry_price: f64) -> PositionState {
+    PositionState {
+        symbol: symbol.to_string(),
+        position_side: side,
+        margin_type: MarginType::Crossed,
+        amount,
+        entry_price,
+        mark_price: 0.0,
+        unrealized_pnl: 0.0,
+        leverage: 0.0,
+        liquidation_price: 0.0,
+    }
+}
+
+fn contract_sizes() -> HashMap<String, f64> {
+    let mut sizes = HashMap::new();
+    sizes.insert("BTCUSD_PERP".to_string(), 100.0);
+    sizes
+}
+
+#[test]
+fn test_futures_account_update() {
+    let mut state = AccountState::new();
+    state.reset(AccountSnapshot {
+        positions: vec![position("BTCUSDT", PositionDirect::Both, 0.5, 6000.0)],
+        ..Default::default()
+    });
+    for line in FUTURES_EVENTS.lines() {
+        let event: WSUserStream = serde_json::from_str(line).unwrap();
+        state.apply(&event);
+    }
+    let usdt = state.balance("USDT").unwrap();
+    assert_eq!(usdt.wallet_balance, 122624.12345678);
+    assert_eq!(usdt.available, 100.12345678);
+    // 数量为 0 的持仓被移除
+    assert!(state.position("BTCUSDT", PositionDirect::Both).is_none());
+    let long = state.position("BTCUSDT", PositionDirect::Long).unwrap();
+    assert_eq!(long.amount, 20.0);
+    assert_eq!(long.margin_type, MarginType::Isolated);
+    assert_eq!(long.unrealized_pnl, 2850.212);
+
+    state.apply(&mark_price("BTCUSDT", 6600.0));
+    let long = state.position("BTCUSDT", PositionDirect::Long).unwrap();
+    assert_eq!(long.mark_price, 6600.0);
+    assert!((long.unrealized_pnl - 20.0 * (6600.0 - 6563.665)).abs() < 1e-6);
+    assert_eq!(state.positions_of("BTCUSDT").count(), 1);
+    assert_eq!(state.positions_of("ETHUSDT").count(), 0);
+}
+
+#[test]
+fn test_coin_futures_pnl() {
+    let mut state = AccountState::new();
+    state.reset(AccountSnapshot {
+        positions: vec![
+            position("BTCUSD_PERP", PositionDirect::Short, -10.0, 50000.0),
+            position("BTCUSD_PERP", PositionDirect::Long, 0.0, 0.0),
+        ],
+        contract_sizes: contract_sizes(),
+        ..Default::default()
+    });
+    assert_eq!(state.positions().count(), 1);
+    state.update_mark_price("BTCUSD_PERP", 40000.0);
+    let short = state
+        .position("BTCUSD_PERP", PositionDirect::Short)
+        .unwrap();
+    let expected = -10.0 * 100.0 * (1.0 / 50000.0 - 1.0 / 40000.0);
+    assert!((short.unrealized_pnl - expected).abs() < 1e-12);
+    assert!(state.unrealized_pnl() > 0.0);
+
+    // 重新同步后保留已知的标记价格
+    state.reset(AccountSnapshot {
+        positions: vec![position(
+            "BTCUSD_PERP",
+            PositionDirect::Short,
+            -5.0,
+            50000.0,
+        )],
+        contract_sizes: contract_sizes(),
+        ..Default::default()
+    });
+    let short = state
+        .position("BTCUSD_PERP", PositionDirect::Short)
+        .unwrap();
+    assert_eq!(short.mark_price, 40000.0);
+    assert!((short.unrealized_pnl - expected / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_spot_balance_events() {
+    let mut state = AccountState::new();
+    state.reset(AccountSnapshot {
+        balances: vec![AssetBalance {
+            asset: "BTC".to_string(),
+            wallet_balance: 1.5,
+            available: 1.0,
+        }],
+        ..Default::default()
+    });
+    for line in SPOT_EVENTS.lines() {
+        let event: WSSpotUserStream = serde_json::from_str(line).unwrap();
+        state.apply(&event);
+    }
+    let usdt = state.balance("USDT").unwrap();
+    assert_eq!(usdt.wallet_balance, 1000.0);
+    assert_eq!(usdt.available, 900.5);
+    let btc = state.balance("BTC").unwrap();
+    assert_eq!((btc.wallet_balance, btc.available), (101.5, 101.0));
+    assert_eq!(state.positions().count(), 0);
+}
@@ -0,0 +1,89 @@
+use bian_rs::enums::{
+    FuturesNewOrderType, FuturesOrderType, NewOrderType, OrderSide, PositionDirect, SpotOrderType,
+    TimeInForce, WorkingType,
+};
+use bian_rs::params::{FuturesOrderBuilder, PFuturesOrderSpec, SpotOrderBuilder};
+
+#[test]
+fn test_futures_builder() {
+    let spec: PFuturesOrderSpec =
+        FuturesOrderBuilder::limit("BTCUSDT", OrderSide::Buy, 0.01, 57000.0, TimeInForce::GTC)
+            .position_side(PositionDirect::Long)
+            .client_order_id("my_order")
+            .resp_type(FuturesNewOrderType::Result)
+            .into();
+    assert_eq!(spec.order_type, FuturesOrderType::Limit);
+    assert_eq!(spec.price, Some(57000.0));
+    assert_eq!(spec.new_client_order_id.as_deref(), Some("my_order"));
+    assert_eq!(spec.new_order_resp_type.as_deref(), Some("RESULT"));
+    let spec = FuturesOrderBuilder::market("BTCUSDT", OrderSide::Sell, 0.01)
+        .reduce_only()
+        .build();
+    assert_eq!(spec.working_type, None);
+    assert_eq!(spec.reduce_only, Some(true));
+
+    let spec = FuturesOrderBuilder::stop_market("BTCUSDT", OrderSide::Sell, 0.01, 55000.0)
+        .working_type(WorkingType::MarkPrice)
+        .price_protect()
+        .reduce_only()
+        .build();
+    assert_eq!(spec.working_type.as_deref(), Some("MARK_PRICE"));
+    assert_eq!(spec.price_protect.as_deref(), Some("TRUE"));
+    assert_eq!(spec.reduce_only, Some(true));
+    assert_eq!(spec.price, None);
+
+    let spec = FuturesOrderBuilder::trailing_stop_market("BTCUSDT", OrderSide::Sell, 0.01, 1.0)
+        .activation_price(60000.0)
+        .build();
+    assert_eq!(spec.callback_rate, Some(1.0));
+    assert_eq!(spec.activation_price, Some(60000.0));
+    assert_eq!(spec.stop_price, None);
+}
+
+#[test]
+fn test_close_position_builder() {
+    let spec =
+        FuturesOrderBuilder::close_position_take_profit_market("BTCUSDT", OrderSide::Sell, 60000.0)
+            .working_type(WorkingType::ContractPrice)
+            .build();
+    assert_eq!(spec.order_type, FuturesOrderType::TakeProfitMarket);
+    assert_eq!(spec.close_position, Some(true));
+    assert_eq!(spec.quantity, None);
+    assert_eq!(spec.reduce_only, None);
+    let query = serde_qs::to_string(&spec).unwrap();
+    assert!(query.contains("closePosition=true"));
+    assert!(query.contains("workingType=CONTRACT_PRICE"));
+    assert!(!query.contains("quantity"));
+    assert!(!query.contains("reduceOnly"));
+}
+
+#[test]
+fn test_spot_builder() {
+    let spec = SpotOrderBuilder::market_quote("BNBUSDT", OrderSide::Buy, 100.0).build();
+    assert_eq!(spec.order_type, SpotOrderType::Market);
+    assert_eq!(spec.quantity, None);
+    assert_eq!(spec.quote_order_qty, Some(100.0));
+    assert_eq!(spec.time_in_force, None);
+
+    let spec = SpotOrderBuilder::stop_loss_limit("BNBUSDT", OrderSide::Sell, 1.0, 390.0, 395.0)
+        .iceberg_qty(0.2)
+        .resp_type(NewOrderType::Full)
+        .build();
+    assert_eq!(spec.order_type, SpotOrderType::StopLossLimit);
+    assert_eq!(spec.stop_price, Some(395.0));
+    assert_eq!(spec.time_in_force, Some(TimeInForce::GTC));
+    assert_eq!(spec.iceberg_qty, Some(0.2));
+    assert_eq!(spec.new_order_resp_type.as_deref(), Some("FULL"));
+
+    let spec = SpotOrderBuilder::limit("BNBUSDT", OrderSide::Sell, 1.0, 400.0)
+        .time_in_force(TimeInForce::IOC)
+        .build();
+    assert_eq!(spec.time_in_force, Some(TimeInForce::IOC));
+    assert_eq!(spec.iceberg_qty, None);
+    let spec = SpotOrderBuilder::limit_maker("BNBUSDT", OrderSide::Buy, 1.0, 380.0)
+        .iceberg_qty(0.5)
+        .build();
+    assert_eq!(spec.order_type, SpotOrderType::LimitMaker);
+    assert_eq!(spec.time_in_force, None);
+    assert_eq!(spec.iceberg_qty, Some(0.5));
+}
@@ -125,6 +125,19 @@ async fn test_index_price_klines() {
     dbg!(client.continuous_klines(param).await.unwrap());
 }
 
+#[tokio::test]
+async fn test_mark_price_klines() {
+    let client = init_client();
+    let param = params::PKline {
+        symbol: "BTCUSD_PERP".to_string(),
+        interval: enums::Interval::Min1,
+        start_time: None,
+        end_time: None,
+        limit: None,
+    };
+    dbg!(client.mark_price_klines(param).await.unwrap());
+}
+
 #[tokio::test]
 async fn test_premium_index() {
     let client = init_client();
@@ -289,6 +302,20 @@ async fn test_taker_buy_sell_vol() {
     dbg!(client.taker_buy_sell_vol(param).await.unwrap());
 }
 
+#[tokio::test]
+async fn test_basis() {
+    let client = init_client();
+    let param = params::PBasis {
+        pair: "BTCUSD".to_string(),
+        contract_type: ContractType::CurrentQuarter,
+        period: Interval::Hour1,
+        limit: Some(30),
+        start_time: None,
+        end_time: None,
+    };
+    dbg!(client.basis(param).await.unwrap());
+}
+
 #[tokio::test]
 async fn test_update_position_side() {
     let client = init_client();
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use bian_rs::dead_man::{DeadManSwitch, TripReason};
+use bian_rs::enums::{OrderSide, OrderStatus};
+use bian_rs::paper::PaperExchange;
+use bian_rs::response;
+use bian_rs::trading::{Order, OrderExecution, OrderRequest};
+
+const EXCHANGE_INFO: &str = include_str!("../src/mock/fixtures/ufutures/exchange_info.json");
+const COUNTDOWN: Duration = Duration::from_millis(1000);
+const INTERVAL: Duration = Duration::from_millis(10);
+
+/// 模拟盘的倒计时使用行情时间, 初始时间为 1
+async fn init_exchange() -> (PaperExchange, Order) {
+    let info: response::UFuturesExchangeInfo = serde_json::from_str(EXCHANGE_INFO).unwrap();
+    let exchange = PaperExchange::new(&info, vec![("USDT".to_string(), 10000.0)]);
+    exchange.advance_clock(1);
+    let req = OrderRequest::limit("BTCUSDT", OrderSide::Buy, 0.1, 57000.0);
+    let order = exchange.submit_order(req).await.unwrap();
+    (exchange, order)
+}
+
+async fn status(exchange: &PaperExchange, order: &Order) -> OrderStatus {
+    exchange
+        .fetch_order("BTCUSDT", order.order_id)
+        .await
+        .unwrap()
+        .status
+}
+
+fn start(exchange: &PaperExchange) -> DeadManSwitch<PaperExchange> {
+    DeadManSwitch::with_interval(
+        exchange.clone(),
+        vec!["BTCUSDT".to_string()],
+        COUNTDOWN,
+        INTERVAL,
+    )
+}
+
+#[tokio::test]
+async fn test_rearm_until_tripped() {
+    let (exchange, order) = init_exchange().await;
+    let switch = start(&exchange);
+    tokio::time::sleep(INTERVAL * 3).await;
+    exchange.advance_clock(900);
+    tokio::time::sleep(INTERVAL * 3).await;
+    // 倒计时已被重置, 超过首次设置的截止时间后挂单仍然有效
+    exchange.advance_clock(1500);
+    assert_eq!(status(&exchange, &order).await, OrderStatus::New);
+
+    switch.trip();
+    assert_eq!(switch.tripped(), Some(TripReason::Manual));
+    tokio::time::sleep(INTERVAL * 3).await;
+    exchange.advance_clock(2500);
+    assert_eq!(status(&exchange, &order).await, OrderStatus::Canceled);
+}
+
+#[tokio::test]
+async fn test_stalled_heartbeat_and_drop() {
+    let (exchange, order) = init_exchange().await;
+    let switch = start(&exchange);
+    let health = switch.health();
+    health.require_heartbeat(INTERVAL * 5);
+    for _ in 0..5 {
+        tokio::time::sleep(INTERVAL).await;
+        health.beat();
+    }
+    assert_eq!(health.tripped(), None);
+    tokio::time::sleep(INTERVAL * 10).await;
+    assert_eq!(health.tripped(), Some(TripReason::Stalled));
+    exchange.advance_clock(1100);
+    assert_eq!(status(&exchange, &order).await, OrderStatus::Canceled);
+
+    let req = OrderRequest::limit("BTCUSDT", OrderSide::Buy, 0.1, 57000.0);
+    let order = exchange.submit_order(req).await.unwrap();
+    let switch = start(&exchange);
+    tokio::time::sleep(INTERVAL * 3).await;
+    drop(switch);
+    exchange.advance_clock(2200);
+    assert_eq!(status(&exchange, &order).await, OrderStatus::Canceled);
+}
+
+#[tokio::test]
+async fn test_disarm_keeps_orders() {
+    let (exchange, order) = init_exchange().await;
+    let switch = start(&exchange);
+    tokio::time::sleep(INTERVAL * 3).await;
+    switch.disarm().await.unwrap();
+    exchange.advance_clock(5000);
+    assert_eq!(status(&exchange, &order).await, OrderStatus::New);
+}
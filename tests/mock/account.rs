@@ -0,0 +1,87 @@
+use super::*;
+
+#[tokio::test]
+async fn test_account_state_sync() {
+    use bian_rs::account::AccountState;
+    use bian_rs::enums::PositionDirect;
+    use bian_rs::user_stream::{ReconnectReason, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let mut state = AccountState::load(&client).await.unwrap();
+    assert_eq!(state.balance("USDT").unwrap().wallet_balance, 23.72469206);
+    assert_eq!(state.positions().count(), 0);
+
+    let line = include_str!("../../src/mock/fixtures/ufutures/ws_user_data.jsonl")
+        .lines()
+        .next()
+        .unwrap();
+    let event: UserDataEvent<response::WSUserStream> =
+        UserDataEvent::Update(serde_json::from_str(line).unwrap());
+    state.handle(&client, &event).await.unwrap();
+    assert!(state.position("BTCUSDT", PositionDirect::Long).is_some());
+    let event =
+        UserDataEvent::<response::WSUserStream>::Reconnecting(ReconnectReason::ListenKeyExpired);
+    state.handle(&client, &event).await.unwrap();
+    assert!(state.position("BTCUSDT", PositionDirect::Long).is_some());
+
+    // 重连后以 http 快照为准
+    let event = UserDataEvent::<response::WSUserStream>::Connected("key".to_string());
+    state.handle(&client, &event).await.unwrap();
+    assert!(state.position("BTCUSDT", PositionDirect::Long).is_none());
+    assert_eq!(state.balance("USDT").unwrap().wallet_balance, 23.72469206);
+    let accounts = server
+        .requests()
+        .into_iter()
+        .filter(|r| r.path == "/fapi/v2/account")
+        .count();
+    assert_eq!(accounts, 2);
+
+    let spot = AccountState::load(&spot_client(&server)).await.unwrap();
+    let usdt = spot.balance("USDT").unwrap();
+    assert_eq!((usdt.wallet_balance, usdt.available), (1012.0, 1000.0));
+}
+
+#[tokio::test]
+async fn test_account_state_seeds_position_risk() {
+    use bian_rs::account::AccountState;
+    use bian_rs::enums::PositionDirect;
+
+    let server = MockServer::start().unwrap();
+    let mut account: serde_json::Value = serde_json::from_str(include_str!(
+        "../../src/mock/fixtures/ufutures/account.json"
+    ))
+    .unwrap();
+    account["assets"][0]["availableBalance"] = "20.5".into();
+    let position = account["positions"]
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .find(|p| p["symbol"] == "BTCUSDT" && p["positionSide"] == "BOTH")
+        .unwrap();
+    position["positionAmt"] = "0.010".into();
+    position["entryPrice"] = "57000.0".into();
+    position["leverage"] = "10".into();
+    server.mock(Method::SGET, "fapi/v2/account", &account.to_string());
+    server.mock(
+        Method::SGET,
+        "fapi/v2/positionRisk",
+        &include_str!("../../src/mock/fixtures/ufutures/position_risk.json")
+            .replace(r#""positionAmt": "0.000""#, r#""positionAmt": "0.010""#)
+            .replace(
+                r#""liquidationPrice": "0""#,
+                r#""liquidationPrice": "51300.5""#,
+            ),
+    );
+
+    let state = AccountState::load(&u_client(&server)).await.unwrap();
+    let usdt = state.balance("USDT").unwrap();
+    assert_eq!((usdt.wallet_balance, usdt.available), (23.72469206, 20.5));
+    let position = state.position("BTCUSDT", PositionDirect::Both).unwrap();
+    assert_eq!(position.mark_price, 58000.5);
+    assert_eq!(position.leverage, 10.0);
+    assert_eq!(position.liquidation_price, 51300.5);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/fapi/v2/positionRisk");
+    assert_eq!(req.param("symbol").as_deref(), Some("BTCUSDT"));
+}
@@ -0,0 +1,137 @@
+use super::*;
+
+#[tokio::test]
+async fn test_algo_orders() {
+    use bian_rs::enums::{AlgoStatus, AlgoType, AlgoUrgency, OrderSide, OrderStatus};
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, AlgoHttpClient::new);
+    let resp = client
+        .twap_order(params::PTwapOrder {
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Buy,
+            position_side: None,
+            quantity: 1.0,
+            duration: Duration::from_secs(60 * 60),
+            client_algo_id: None,
+            reduce_only: None,
+            limit_price: Some(57000.0),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(resp.success);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("duration").as_deref(), Some("3600"));
+    assert_eq!(req.param("limitPrice").as_deref(), Some("57000"));
+    client
+        .vp_order(params::PVpOrder {
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Sell,
+            position_side: None,
+            quantity: 1.0,
+            urgency: AlgoUrgency::Medium,
+            client_algo_id: Some("rebalance_1".to_string()),
+            reduce_only: Some(true),
+            limit_price: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("urgency").as_deref(), Some("MEDIUM"));
+    assert_eq!(req.param("limitPrice"), None);
+
+    let orders = client.open_orders(PTimestamp::now()).await.unwrap();
+    let order = &orders.orders[0];
+    assert_eq!(order.algo_status, AlgoStatus::Working);
+    assert_eq!(order.urgency, Some(AlgoUrgency::Low));
+    let orders = client
+        .historical_orders(params::PAlgoHistory {
+            symbol: None,
+            side: None,
+            start_time: None,
+            end_time: None,
+            page: None,
+            page_size: Some(10),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(orders.orders[0].algo_type, AlgoType::Twap);
+    assert!(orders.orders[0].urgency.is_none());
+    let subs = client
+        .sub_orders(params::PAlgoSubOrders {
+            algo_id: 13723,
+            page: None,
+            page_size: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(subs.sub_orders[0].order_status, OrderStatus::Filled);
+    assert_eq!(subs.sub_orders[0].fee_amt, -1.61471999);
+    let resp = client
+        .cancel_order(params::PAlgoId {
+            algo_id: 14511,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.algo_id, 14511);
+}
+
+#[tokio::test]
+async fn test_algo_hedge_mode_and_empty_history() {
+    use bian_rs::enums::{OrderSide, PositionDirect};
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, AlgoHttpClient::new);
+    client
+        .twap_order(params::PTwapOrder {
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Sell,
+            position_side: Some(PositionDirect::Long),
+            quantity: 0.5,
+            // 不足一秒的部分舍去
+            duration: Duration::from_millis(300_900),
+            client_algo_id: Some("close_long_1".to_string()),
+            reduce_only: None,
+            limit_price: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("duration").as_deref(), Some("300"));
+    assert_eq!(req.param("positionSide").as_deref(), Some("LONG"));
+    assert_eq!(req.param("clientAlgoId").as_deref(), Some("close_long_1"));
+    assert_eq!(req.param("reduceOnly"), None);
+
+    // 没有历史订单时不返回 orders 字段
+    server.mock(
+        Method::SGET,
+        "sapi/v1/algo/futures/historicalOrders",
+        r#"{"total":0}"#,
+    );
+    let orders = client
+        .historical_orders(params::PAlgoHistory {
+            symbol: Some("BTCUSDT".to_string()),
+            side: Some(OrderSide::Buy),
+            start_time: None,
+            end_time: None,
+            page: Some(2),
+            page_size: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(orders.total, 0);
+    assert!(orders.orders.is_empty());
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("side").as_deref(), Some("BUY"));
+    assert_eq!(req.param("page").as_deref(), Some("2"));
+    assert_eq!(req.param("pageSize"), None);
+}
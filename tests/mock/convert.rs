@@ -0,0 +1,97 @@
+use super::*;
+
+#[tokio::test]
+async fn test_convert() {
+    use bian_rs::enums::{ConvertStatus, QuoteValidTime};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server).convert();
+    let pairs = client
+        .exchange_info(params::PConvertPair {
+            from_asset: Some("BTC".to_string()),
+            to_asset: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(pairs[0].from_asset_min_amount, 0.0004);
+
+    let (quote, accepted) = client
+        .convert(params::PConvertQuote {
+            from_asset: "BTC".to_string(),
+            to_asset: "USDT".to_string(),
+            from_amount: Some(0.1),
+            to_amount: None,
+            wallet_type: None,
+            valid_time: Some(QuoteValidTime::ThirtySeconds),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(quote.to_amount, 3816.37);
+    assert_eq!(accepted.order_status, ConvertStatus::Process);
+    let mut requests = server.requests();
+    let req = requests.pop().unwrap();
+    assert_eq!(req.path, "/sapi/v1/convert/acceptQuote");
+    assert_eq!(req.param("quoteId").as_deref(), Some("12415572564"));
+    let req = requests.pop().unwrap();
+    assert_eq!(req.param("validTime").as_deref(), Some("30s"));
+
+    let order = client
+        .order_status(params::PConvertOrderStatus {
+            order_id: Some(accepted.order_id),
+            quote_id: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order.order_status, ConvertStatus::Success);
+    assert!(order.quote_id.is_none());
+    let flow = client
+        .trade_flow(params::PConvertTradeFlow {
+            start_time: 1623824139000,
+            end_time: 1626416139000,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(flow.list[0].order_id, 940708407462087195);
+    assert!(!flow.more_data);
+
+    // 超过 validTimestamp 后报价过期
+    let mut expired = quote.clone();
+    expired.valid_timestamp = PTimestamp::now().timestamp - 1;
+    assert!(expired.is_expired());
+    assert!(!quote.is_expired());
+}
+
+#[tokio::test]
+async fn test_convert_expired_quote() {
+    use bian_rs::enums::ConvertWallet;
+
+    let server = MockServer::start().unwrap();
+    let expired = include_str!("../../src/mock/fixtures/convert/get_quote.json")
+        .replace("4102444800000", "1623381330472");
+    server.mock(Method::SPOST, "sapi/v1/convert/getQuote", &expired);
+    let client = spot_client(&server).convert();
+    let err = client
+        .convert(params::PConvertQuote {
+            from_asset: "USDT".to_string(),
+            to_asset: "BTC".to_string(),
+            from_amount: None,
+            to_amount: Some(0.25),
+            wallet_type: Some(ConvertWallet::SpotFunding),
+            valid_time: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::QuoteExpired(ref id) if id == "12415572564"));
+    // 过期报价不会被接受
+    let reqs = server.requests();
+    assert_eq!(reqs.len(), 1);
+    assert_eq!(reqs[0].param("toAmount").as_deref(), Some("0.25"));
+    assert_eq!(reqs[0].param("fromAmount"), None);
+    assert_eq!(reqs[0].param("walletType").as_deref(), Some("SPOT_FUNDING"));
+    assert_eq!(reqs[0].param("validTime"), None);
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+#[tokio::test]
+async fn test_dead_man_switch_params() {
+    use bian_rs::dead_man::DeadManSwitch;
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+    let switch = DeadManSwitch::start(u_client(&server), symbols, Duration::from_secs(60));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    switch.disarm().await.unwrap();
+    let params: Vec<(String, String)> = server
+        .requests()
+        .iter()
+        .filter(|r| r.path == "/fapi/v1/countdownCancelAll")
+        .map(|r| {
+            (
+                r.param("symbol").unwrap(),
+                r.param("countdownTime").unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        params,
+        vec![
+            ("BTCUSDT".to_string(), "60000".to_string()),
+            ("ETHUSDT".to_string(), "60000".to_string()),
+            ("BTCUSDT".to_string(), "0".to_string()),
+            ("ETHUSDT".to_string(), "0".to_string()),
+        ]
+    );
+}
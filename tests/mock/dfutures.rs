@@ -0,0 +1,153 @@
+use super::*;
+
+#[tokio::test]
+async fn test_d_client() {
+    let server = MockServer::start().unwrap();
+    let client = d_client(&server);
+    client.ping().await.unwrap();
+    client.server_time().await.unwrap();
+    let info = client.exchange_info().await.unwrap();
+    assert_eq!(info.symbols[0].contract_size, 100);
+    client.premium_indexes().await.unwrap();
+    let mut spec = futures_order_spec();
+    spec.symbol = "BTCUSD_PERP".to_string();
+    spec.quantity = Some(1.0);
+    client
+        .order(params::PFuturesOrder {
+            spec,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    client.account_balance(PTimestamp::now()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_d_market_and_account() {
+    use bian_rs::enums::{ContractType, IncomeType, Interval};
+
+    let server = MockServer::start().unwrap();
+    let client = d_client(&server);
+    let klines = client
+        .mark_price_klines(params::PKline {
+            symbol: "BTCUSD_PERP".to_string(),
+            interval: Interval::Min1,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(klines[0].count, 60);
+    let klines = client
+        .premium_index_klines(params::PKline {
+            symbol: "BTCUSD_PERP".to_string(),
+            interval: Interval::Min1,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert!(klines[0].close < 0.0);
+
+    let basis = client
+        .basis(params::PBasis {
+            pair: "BTCUSD".to_string(),
+            contract_type: ContractType::CurrentQuarter,
+            period: Interval::Hour1,
+            limit: None,
+            start_time: None,
+            end_time: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(basis[0].contract_type, ContractType::CurrentQuarter);
+    assert_eq!(basis[1].annualized_basis_rate, 0.0);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("pair").as_deref(), Some("BTCUSD"));
+    assert_eq!(
+        req.param("contractType").as_deref(),
+        Some("CURRENT_QUARTER")
+    );
+
+    let income = client
+        .income(params::PDFuturesIncome {
+            symbol: None,
+            income_type: Some(IncomeType::Commission),
+            start_time: None,
+            end_time: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(income[1].asset, "BTC");
+    let brackets = client
+        .leverage_brackets(params::POptionPairQuery {
+            pair: Some("BTCUSD".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(brackets[0].brackets[1].qty_floor, 50.0);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("pair").as_deref(), Some("BTCUSD"));
+    let rate = client
+        .commission_rate(params::PSymbolWithTs {
+            symbol: "BTCUSD_PERP".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(rate.maker_commission_rate, 0.00015);
+    let adl = client
+        .adl_quantile(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(adl[0].adl_quantile.both, Some(2));
+}
+
+#[test]
+fn test_d_ws_index_and_contract_info() {
+    use bian_rs::enums::{ContractStatus, ContractType, Interval};
+
+    let server = MockServer::start().unwrap();
+    let client = DFuturesWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream = client.index_price("btcusd".to_string(), 1).unwrap();
+    let msg = stream.read_stream_single().unwrap();
+    assert_eq!(msg.pair, "BTCUSD");
+    assert_eq!(msg.price, 9636.5786);
+    stream.close_stream();
+
+    let mut stream = client
+        .index_price_kline("btcusd".to_string(), Interval::Min1)
+        .unwrap();
+    let msg = stream.read_stream_single().unwrap();
+    assert_eq!(msg.pair, "BTCUSD");
+    assert_eq!(msg.data.trade_num, 51);
+    stream.close_stream();
+
+    let mut stream = client
+        .mark_price_kline("btcusd_perp".to_string(), Interval::Min1)
+        .unwrap();
+    let msg = stream.read_stream_single().unwrap();
+    assert_eq!(msg.data.symbol, "BTCUSD_PERP");
+    stream.close_stream();
+
+    let mut stream = client.contract_info().unwrap();
+    let msg = stream.read_stream_single().unwrap();
+    assert_eq!(msg.pair, "IOTAUSD");
+    assert_eq!(msg.brackets[1].max_leverage, 20);
+    let msg = stream.read_stream_single().unwrap();
+    assert_eq!(msg.contract_type, ContractType::CurrentQuarter);
+    assert_eq!(msg.contract_status, ContractStatus::Delivering);
+    assert!(msg.brackets.is_empty());
+    stream.close_stream();
+}
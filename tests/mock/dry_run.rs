@@ -0,0 +1,142 @@
+use super::*;
+
+#[tokio::test]
+async fn test_dry_run() {
+    use bian_rs::enums::OrderSide;
+    use bian_rs::params::SpotOrderBuilder;
+
+    let server = MockServer::start().unwrap();
+    let spot = spot_client(&server);
+    let order = || params::PSpotOrder {
+        spec: SpotOrderBuilder::limit("ADAUSDT", OrderSide::Buy, 10.0, 1.25).build(),
+        ts: PTimestamp::now(),
+    };
+    spot.order_test(order()).await.unwrap();
+    assert_eq!(server.requests().pop().unwrap().path, "/api/v3/order/test");
+
+    // 下单只发送到测试接口
+    let spot = spot.with_dry_run(true);
+    let err = spot.order(order()).await.unwrap_err();
+    assert!(matches!(err, APIError::DryRun(ref req) if req.starts_with("POST ")));
+    let reqs = server.requests();
+    assert_eq!(reqs.len(), 2);
+    assert_eq!(reqs[1].path, "/api/v3/order/test");
+    assert_eq!(reqs[1].param("price").as_deref(), Some("1.25"));
+    assert!(reqs[1].param("signature").is_some());
+
+    // 撤单不发送请求
+    let err = spot
+        .cancel_order(params::PQuerySpotOrder {
+            symbol: "ADAUSDT".to_string(),
+            order_id: Some(1),
+            orig_client_order_id: None,
+            new_client_order_id: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::DryRun(ref req) if req.starts_with("DELETE ")));
+    assert_eq!(server.requests().len(), 2);
+    // 钱包客户端继承现货客户端的 dry run 配置
+    let err = spot
+        .wallet()
+        .transfer(params::PUniversalTransfer {
+            transfer_type: enums::UniversalTransferType::MainUmFuture,
+            asset: "USDT".to_string(),
+            amount: 1.0,
+            from_symbol: None,
+            to_symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::DryRun(_)));
+    assert_eq!(server.requests().len(), 2);
+    // 闪兑仍然询价, 只是不接受报价
+    let err = spot
+        .convert()
+        .convert(params::PConvertQuote {
+            from_asset: "BTC".to_string(),
+            to_asset: "USDT".to_string(),
+            from_amount: Some(0.1),
+            to_amount: None,
+            wallet_type: None,
+            valid_time: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::DryRun(ref req) if req.contains("acceptQuote")));
+    assert_eq!(server.requests().len(), 3);
+    // 查询接口不受影响
+    spot.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(server.requests().len(), 4);
+
+    // 测试接口校验失败时返回交易所错误
+    server.mock_status(
+        Method::SPOST,
+        "fapi/v1/order/test",
+        400,
+        r#"{"code":-1111,"msg":"Precision is over the maximum defined for this asset."}"#,
+    );
+    let futures = u_client(&server).with_dry_run(true);
+    let err = futures
+        .order(params::PFuturesOrder {
+            spec: futures_order_spec(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::ClientSideError(ref msg) if msg.contains("-1111")));
+    let err = futures
+        .cancel_all_orders(params::PSymbolWithTs {
+            symbol: "BTCUSDT".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, APIError::DryRun(_)));
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn test_dry_run_validates_locally() {
+    use bian_rs::rules::{RuleViolation, SymbolRulesCache};
+    use std::sync::Arc;
+
+    let server = MockServer::start().unwrap();
+    let client = d_client(&server);
+    let rules = SymbolRulesCache::from_dfutures(&client.exchange_info().await.unwrap());
+    let client = client
+        .with_dry_run(true)
+        .with_dry_run_rules(Arc::new(rules));
+    let order = |price: f64| params::PFuturesOrder {
+        spec: PFuturesOrderSpec {
+            symbol: "BTCUSD_PERP".to_string(),
+            quantity: Some(1.0),
+            price: Some(price),
+            ..futures_order_spec()
+        },
+        ts: PTimestamp::now(),
+    };
+    let err = client.order(order(58000.05)).await.unwrap_err();
+    assert!(matches!(
+        err,
+        APIError::FilterFailure(RuleViolation::InvalidTickSize { .. })
+    ));
+    let err = client.order(order(58000.1)).await.unwrap_err();
+    assert!(matches!(err, APIError::DryRun(ref req) if req.contains("/dapi/v1/order?")));
+    let err = client
+        .batch_order(params::PBatchOrder {
+            batch_orders: vec![order(58000.1).spec, order(10.0).spec],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        APIError::FilterFailure(RuleViolation::PriceOutOfRange { .. })
+    ));
+    // 只有查询规则的请求发送到了服务器
+    assert_eq!(server.requests().len(), 1);
+}
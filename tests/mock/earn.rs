@@ -0,0 +1,190 @@
+use super::*;
+
+#[tokio::test]
+async fn test_earn_flexible() {
+    use bian_rs::enums::EarnRewardType;
+    use bian_rs::pagination::collect_pages;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, EarnHttpClient::new);
+    let products = collect_pages(10, |page| {
+        client.flexible_products(params::PEarnProductList {
+            asset: Some("USDT".to_string()),
+            page,
+            ts: PTimestamp::now(),
+        })
+    })
+    .await
+    .unwrap();
+    assert_eq!(products.len(), 1);
+    assert_eq!(products[0].min_purchase_amount, 0.1);
+    assert_eq!(products[0].tier_annual_percentage_rate["0-200USDT"], 0.05);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("current").as_deref(), Some("1"));
+    assert_eq!(req.param("size").as_deref(), Some("10"));
+
+    // 数量小于最小申购数量时不申购
+    assert!(client.sweep("USDT", 0.01).await.unwrap().is_none());
+    assert!(client.sweep("BTC", 1.0).await.unwrap().is_none());
+    let resp = client.sweep("USDT", 50.0).await.unwrap().unwrap();
+    assert!(resp.success);
+    assert!(resp.position_id.is_none());
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/sapi/v1/simple-earn/flexible/subscribe");
+    assert_eq!(req.param("productId").as_deref(), Some("USDT001"));
+
+    let positions = client
+        .flexible_positions(params::PFlexiblePosition {
+            asset: None,
+            product_id: None,
+            page: params::PPage::default(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(positions.rows[0].total_amount, 75.46);
+    let rewards = client
+        .flexible_rewards(params::PFlexibleRewards {
+            product_id: None,
+            asset: None,
+            start_time: None,
+            end_time: None,
+            reward_type: EarnRewardType::Realtime,
+            page: params::PPage::default(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(rewards.total, 2);
+    assert_eq!(rewards.rows[1].rewards, 0.00687654);
+    let quota = client
+        .flexible_quota(params::PFlexibleQuota {
+            product_id: "USDT001".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(quota.left_personal_quota, 1000.0);
+    let resp = client
+        .flexible_redeem(params::PFlexibleRedeem {
+            product_id: "USDT001".to_string(),
+            redeem_all: Some(true),
+            amount: None,
+            dest_account: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.redeem_id, 40607);
+}
+
+#[tokio::test]
+async fn test_earn_locked() {
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, EarnHttpClient::new);
+    let products = client
+        .locked_products(params::PEarnProductList {
+            asset: None,
+            page: params::PPage::first(10),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let product = &products.rows[0];
+    assert_eq!(product.detail.duration, 90);
+    assert_eq!(product.detail.extra_reward_apr, 0.23);
+    assert_eq!(product.quota.minimum, 0.001);
+    let resp = client
+        .locked_subscribe(params::PLockedSubscribe {
+            project_id: product.project_id.clone(),
+            amount: 1.0,
+            auto_subscribe: Some(false),
+            source_account: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.position_id.as_deref(), Some("12345"));
+
+    let positions = client
+        .locked_positions(params::PLockedPosition {
+            asset: Some("AXS".to_string()),
+            position_id: None,
+            project_id: None,
+            page: params::PPage::default(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let position = &positions.rows[0];
+    assert_eq!(position.position_id, 123123);
+    assert_eq!(position.purchase_time, 1646182276000);
+    assert_eq!(position.accrual_days, 4);
+    let rewards = client
+        .locked_rewards(params::PLockedRewards {
+            position_id: Some(position.position_id),
+            asset: None,
+            start_time: None,
+            end_time: None,
+            page: params::PPage::default(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(rewards.rows[0].lock_period, 30);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("positionId").as_deref(), Some("123123"));
+    assert_eq!(req.param("current"), None);
+}
+
+#[tokio::test]
+async fn test_earn_pages_and_partial_redeem() {
+    use bian_rs::enums::EarnAccount;
+    use bian_rs::pagination::collect_pages;
+
+    let server = MockServer::start().unwrap();
+    let first = include_str!("../../src/mock/fixtures/earn/flexible_list.json")
+        .replace(r#""total": 1"#, r#""total": 2"#);
+    server.mock(
+        Method::SGET,
+        "sapi/v1/simple-earn/flexible/list?current=1",
+        &first,
+    );
+    server.mock(
+        Method::SGET,
+        "sapi/v1/simple-earn/flexible/list?current=2",
+        &first.replace("USDT001", "USDT002"),
+    );
+    let client = mock_client(&server, EarnHttpClient::new);
+    // 每页一条, 按 total 查询到第二页为止
+    let products = collect_pages(1, |page| {
+        client.flexible_products(params::PEarnProductList {
+            asset: None,
+            page,
+            ts: PTimestamp::now(),
+        })
+    })
+    .await
+    .unwrap();
+    let ids: Vec<_> = products.iter().map(|p| p.product_id.as_str()).collect();
+    assert_eq!(ids, ["USDT001", "USDT002"]);
+    let reqs = server.requests();
+    assert_eq!(reqs.len(), 2);
+    assert_eq!(reqs[1].param("size").as_deref(), Some("1"));
+    assert_eq!(reqs[1].param("asset"), None);
+
+    client
+        .flexible_redeem(params::PFlexibleRedeem {
+            product_id: "USDT001".to_string(),
+            redeem_all: None,
+            amount: Some(12.5),
+            dest_account: Some(EarnAccount::Fund),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("amount").as_deref(), Some("12.5"));
+    assert_eq!(req.param("destAccount").as_deref(), Some("FUND"));
+    assert_eq!(req.param("redeemAll"), None);
+}
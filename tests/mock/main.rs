@@ -0,0 +1,83 @@
+use bian_rs::client::*;
+use bian_rs::enums::{self, Method};
+use bian_rs::error::{APIError, BianResult};
+use bian_rs::mock::{MockServer, MOCK_API_KEY, MOCK_SECRET_KEY};
+use bian_rs::params::{self, PFuturesOrderSpec, PSpotOrderSpec, PTimestamp};
+use bian_rs::response::{self, WebsocketResponse};
+use bian_rs::trading::{self, MarketData, OrderExecution, OrderRequest};
+
+mod account;
+mod algo;
+mod convert;
+mod dead_man;
+mod dfutures;
+mod dry_run;
+mod earn;
+mod margin;
+mod options;
+mod portfolio_margin;
+mod server;
+mod spot;
+mod sub_account;
+mod trading_traits;
+mod ufutures;
+mod user_stream;
+mod wallet;
+
+fn u_client(server: &MockServer) -> UFuturesHttpClient {
+    UFuturesHttpClient::new(
+        MOCK_API_KEY.to_string(),
+        MOCK_SECRET_KEY.to_string(),
+        &server.http_url(),
+    )
+    .unwrap()
+}
+
+fn d_client(server: &MockServer) -> DFuturesHttpClient {
+    DFuturesHttpClient::new(
+        MOCK_API_KEY.to_string(),
+        MOCK_SECRET_KEY.to_string(),
+        &server.http_url(),
+    )
+    .unwrap()
+}
+
+fn spot_client(server: &MockServer) -> SpotHttpClient {
+    SpotHttpClient::new(
+        MOCK_API_KEY.to_string(),
+        MOCK_SECRET_KEY.to_string(),
+        &server.http_url(),
+    )
+    .unwrap()
+}
+
+/// 使用 mock 服务器地址和测试密钥创建客户端, 如 `mock_client(&server, MarginHttpClient::new)`
+fn mock_client<C>(server: &MockServer, new: fn(String, String, &str) -> BianResult<C>) -> C {
+    new(
+        MOCK_API_KEY.to_string(),
+        MOCK_SECRET_KEY.to_string(),
+        &server.http_url(),
+    )
+    .unwrap()
+}
+
+fn futures_order_spec() -> PFuturesOrderSpec {
+    PFuturesOrderSpec {
+        symbol: "BTCUSDT".to_string(),
+        side: enums::OrderSide::Buy,
+        position_side: None,
+        order_type: enums::FuturesOrderType::Limit,
+        reduce_only: None,
+        quantity: Some(0.01),
+        price: Some(57000.0),
+        new_client_order_id: Some("testOrder".to_string()),
+        stop_price: None,
+        close_position: None,
+        activation_price: None,
+        callback_rate: None,
+        time_in_force: Some(enums::TimeInForce::GTC),
+        working_type: None,
+        price_protect: None,
+        new_order_resp_type: None,
+    }
+}
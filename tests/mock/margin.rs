@@ -0,0 +1,281 @@
+use super::*;
+
+#[tokio::test]
+async fn test_margin_borrow_and_account() {
+    use bian_rs::enums::MarginLoanType;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, MarginHttpClient::new);
+    let tran = client
+        .borrow_repay(params::PMarginBorrowRepay {
+            asset: "USDT".to_string(),
+            is_isolated: Some(true),
+            symbol: Some("BTCUSDT".to_string()),
+            amount: 100.0,
+            loan_type: MarginLoanType::Borrow,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(tran.tran_id, 100000001);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("isIsolated").as_deref(), Some("TRUE"));
+    assert_eq!(req.param("type").as_deref(), Some("BORROW"));
+
+    let max = client
+        .max_borrowable(params::PMarginMaxAmount {
+            asset: "BTC".to_string(),
+            isolated_symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(max.borrow_limit, 60.0);
+    let max = client
+        .max_transferable(params::PMarginMaxAmount {
+            asset: "BTC".to_string(),
+            isolated_symbol: Some("BTCUSDT".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(max.amount, 3.59498107);
+    assert_eq!(
+        server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("isolatedSymbol")
+            .as_deref(),
+        Some("BTCUSDT")
+    );
+    let history = client
+        .interest_history(params::PMarginInterestHistory {
+            asset: Some("USDT".to_string()),
+            isolated_symbol: None,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(history.total, 2);
+    assert_eq!(history.rows[0].isolated_symbol.as_deref(), Some("BTCUSDT"));
+    assert_eq!(history.rows[1].isolated_symbol, None);
+
+    let account = client.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(account.margin_level, 11.64405625);
+    assert_eq!(account.user_assets[1].borrowed, 100.0);
+    let isolated = client
+        .isolated_account(params::PIsolatedMarginAccount {
+            symbols: Some("BTCUSDT".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(isolated.assets[0].quote_asset.borrowed, 100.0);
+    assert_eq!(isolated.assets[0].liquidate_price, 12000.0);
+}
+
+#[tokio::test]
+async fn test_margin_trade() {
+    use bian_rs::enums::{OrderSide, OrderStatus, SideEffectType};
+    use bian_rs::params::SpotOrderBuilder;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, MarginHttpClient::new);
+    let order = || params::PMarginOrder {
+        spec: SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.01).build(),
+        is_isolated: Some(true),
+        side_effect_type: Some(SideEffectType::MarginBuy),
+        ts: PTimestamp::now(),
+    };
+    let resp = client.order(order()).await.unwrap();
+    assert!(matches!(resp, response::SpotOrder::Full(ref o) if o.fills.len() == 1));
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("type").as_deref(), Some("MARKET"));
+    assert_eq!(req.param("sideEffectType").as_deref(), Some("MARGIN_BUY"));
+    assert_eq!(req.param("isIsolated").as_deref(), Some("TRUE"));
+
+    let query = || params::PMarginQueryOrder {
+        symbol: "BTCUSDT".to_string(),
+        is_isolated: None,
+        order_id: Some(28),
+        orig_client_order_id: None,
+        new_client_order_id: None,
+        ts: PTimestamp::now(),
+    };
+    let queried = client.query_order(query()).await.unwrap();
+    assert_eq!(queried.status, OrderStatus::New);
+    assert!(!queried.is_isolated);
+    assert_eq!(server.requests().pop().unwrap().param("isIsolated"), None);
+    let canceled = client.cancel_order(query()).await.unwrap();
+    assert_eq!(canceled.status, OrderStatus::Canceled);
+    assert_eq!(canceled.orig_client_order_id, "msXkySR3u5uYwpvRMFsi3u");
+    let open = client
+        .open_orders(params::PMarginOpenOrders {
+            symbol: None,
+            is_isolated: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+    let all = client
+        .all_orders(params::PMarginAllOrders {
+            symbol: "BTCUSDT".to_string(),
+            is_isolated: Some(false),
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(all[0].status, OrderStatus::Filled);
+    assert_eq!(
+        server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("isIsolated")
+            .as_deref(),
+        Some("FALSE")
+    );
+}
+
+#[tokio::test]
+async fn test_margin_order_assigns_client_order_id() {
+    use bian_rs::enums::OrderSide;
+    use bian_rs::params::SpotOrderBuilder;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, MarginHttpClient::new).with_client_order_prefix("margin-");
+    let order = |spec| params::PMarginOrder {
+        spec,
+        is_isolated: None,
+        side_effect_type: None,
+        ts: PTimestamp::now(),
+    };
+    client
+        .order(order(
+            SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.01).build(),
+        ))
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert!(req
+        .param("newClientOrderId")
+        .unwrap()
+        .starts_with("margin-"));
+    client
+        .order(order(
+            SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.01)
+                .client_order_id("keep")
+                .build(),
+        ))
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("newClientOrderId").as_deref(), Some("keep"));
+}
+
+#[tokio::test]
+async fn test_margin_cross_params() {
+    use bian_rs::enums::{MarginLoanType, NewOrderType, OrderSide, SideEffectType};
+    use bian_rs::params::SpotOrderBuilder;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, MarginHttpClient::new);
+    // 全仓还款不发送 isIsolated 和 symbol
+    client
+        .borrow_repay(params::PMarginBorrowRepay {
+            asset: "USDT".to_string(),
+            is_isolated: None,
+            symbol: None,
+            amount: 100.5,
+            loan_type: MarginLoanType::Repay,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("type").as_deref(), Some("REPAY"));
+    assert_eq!(req.param("amount").as_deref(), Some("100.5"));
+    assert_eq!(req.param("isIsolated"), None);
+    assert_eq!(req.param("symbol"), None);
+
+    // 杠杆 ACK 响应没有 orderListId
+    server.mock(
+        Method::SPOST,
+        "sapi/v1/margin/order?newOrderRespType=ACK",
+        r#"{"symbol":"BTCUSDT","orderId":28,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","isIsolated":false,"transactTime":1507725176595}"#,
+    );
+    let resp = client
+        .order(params::PMarginOrder {
+            spec: SpotOrderBuilder::market_quote("BTCUSDT", OrderSide::Sell, 100.0)
+                .resp_type(NewOrderType::Ack)
+                .build(),
+            is_isolated: None,
+            side_effect_type: Some(SideEffectType::AutoRepay),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(resp, response::SpotOrder::Ack(ref o) if o.order_list_id == -1));
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("quoteOrderQty").as_deref(), Some("100"));
+    assert_eq!(req.param("quantity"), None);
+    assert_eq!(req.param("sideEffectType").as_deref(), Some("AUTO_REPAY"));
+}
+
+#[tokio::test]
+async fn test_margin_user_stream() {
+    use bian_rs::user_stream::{
+        IsolatedMarginListenKey, IsolatedMarginUserDataStream, MarginUserDataStream, UserDataEvent,
+    };
+
+    let server = MockServer::start().unwrap();
+    let ws = SpotWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream =
+        MarginUserDataStream::start(mock_client(&server, MarginHttpClient::new), ws.clone());
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(ref key) if key.ends_with("a4")
+    ));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(response::WSSpotUserStream::AccountPosition(_))
+    ));
+    stream.close().await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.path.as_str()),
+        ("DELETE", "/sapi/v1/userDataStream")
+    );
+
+    let listen_key =
+        IsolatedMarginListenKey::new(mock_client(&server, MarginHttpClient::new), "BTCUSDT");
+    let mut stream = IsolatedMarginUserDataStream::start(listen_key, ws);
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(ref key) if key.ends_with("a5")
+    ));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(response::WSSpotUserStream::AccountPosition(_))
+    ));
+    stream.close().await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.path.as_str()),
+        ("DELETE", "/sapi/v1/userDataStream/isolated")
+    );
+    assert_eq!(req.param("symbol").as_deref(), Some("BTCUSDT"));
+}
@@ -0,0 +1,242 @@
+use super::*;
+
+#[tokio::test]
+async fn test_options_market() {
+    use bian_rs::enums::{Interval, OptionSide};
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, OptionsHttpClient::new);
+    client.ping().await.unwrap();
+    client.server_time().await.unwrap();
+    let info = client.exchange_info().await.unwrap();
+    let symbol = &info.option_symbols[0];
+    assert_eq!(symbol.side, OptionSide::Call);
+    assert_eq!(symbol.strike_price, 60000.0);
+    assert_eq!(info.option_contracts[0].underlying, "BTCUSDT");
+    let depth = client
+        .depth(params::PDepth {
+            symbol: "BTC-240628-60000-C".to_string(),
+            limit: 10,
+        })
+        .await
+        .unwrap();
+    assert_eq!(depth.bids[0].0, 2905.0);
+    let klines = client
+        .klines(params::PKline {
+            symbol: "BTC-240628-60000-C".to_string(),
+            interval: Interval::Min5,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(klines[0].interval, Interval::Min5);
+    assert_eq!(klines[0].trade_count, 7);
+    let marks = client
+        .mark(params::POptionalSymbol { symbol: None })
+        .await
+        .unwrap();
+    assert_eq!(marks[0].mark_iv, 0.544);
+    assert_eq!(marks[0].delta, 0.6915);
+    let index = client
+        .index(params::PUnderlying {
+            underlying: "BTCUSDT".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(index.index_price, 61803.25);
+    assert_eq!(
+        server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("underlying")
+            .as_deref(),
+        Some("BTCUSDT")
+    );
+}
+
+#[tokio::test]
+async fn test_options_trade() {
+    use bian_rs::enums::{OptionSide, OptionsOrderStatus, OrderSide, PositionDirect};
+    use params::POptionsOrderSpec;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, OptionsHttpClient::new);
+    let account = client.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(account.assets[0].locked, 290.0);
+    assert_eq!(account.greeks[0].underlying, "BTCUSDT");
+    let positions = client
+        .position(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(positions[0].side, PositionDirect::Short);
+    assert_eq!(positions[0].option_side, OptionSide::Call);
+
+    let mut spec = POptionsOrderSpec::limit("BTC-240628-60000-C", OrderSide::Buy, 0.1, 2900.0);
+    spec.post_only = Some(true);
+    let order = client
+        .order(params::POptionsOrder {
+            spec: spec.clone(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(order, response::NewOptionsOrder::Ack(ref o) if o.price == 2900.0));
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("type").as_deref(), Some("LIMIT"));
+    assert_eq!(req.param("postOnly").as_deref(), Some("true"));
+    assert_eq!(req.param("reduceOnly"), None);
+
+    let resp = client
+        .batch_order(params::POptionsBatchOrder {
+            orders: vec![spec.clone(), spec],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(
+        matches!(resp[0], response::OrderResponse::Order(ref o) if o.status == OptionsOrderStatus::Accepted)
+    );
+    assert!(matches!(resp[1], response::OrderResponse::Code(ref c) if c.code == -2027));
+    let orders: serde_json::Value =
+        serde_json::from_str(&server.requests().pop().unwrap().param("orders").unwrap()).unwrap();
+    assert_eq!(orders[1]["price"], "2900");
+    assert_eq!(orders[1]["quantity"], "0.1");
+
+    let query = || params::POptionsQueryOrder {
+        symbol: "BTC-240628-60000-C".to_string(),
+        order_id: None,
+        client_order_id: Some("bian_opt_1".to_string()),
+        ts: PTimestamp::now(),
+    };
+    let order = client.query_order(query()).await.unwrap();
+    assert_eq!(order.client_order_id, "bian_opt_1");
+    let order = client.cancel_order(query()).await.unwrap();
+    assert_eq!(order.status, OptionsOrderStatus::Cancelled);
+    let open = client
+        .open_orders(params::POptionsOpenOrders {
+            symbol: None,
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+    client
+        .cancel_all_orders(params::PSymbolWithTs {
+            symbol: "BTC-240628-60000-C".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        server.requests().pop().unwrap().path,
+        "/eapi/v1/allOpenOrders"
+    );
+}
+
+#[tokio::test]
+async fn test_options_order_result() {
+    use bian_rs::enums::{OptionsOrderStatus, OrderSide, TimeInForce};
+
+    let server = MockServer::start().unwrap();
+    // 只有 newOrderRespType=RESULT 的请求返回完整订单
+    server.mock(
+        Method::SPOST,
+        "eapi/v1/order?newOrderRespType=RESULT",
+        include_str!("../../src/mock/fixtures/options/query_order.json"),
+    );
+    let client = mock_client(&server, OptionsHttpClient::new);
+    let mut spec =
+        params::POptionsOrderSpec::limit("BTC-240628-60000-C", OrderSide::Buy, 0.1, 2900.0);
+    spec.time_in_force = Some(TimeInForce::IOC);
+    spec.new_order_resp_type = Some("RESULT".to_string());
+    spec.is_mmp = Some(true);
+    let order = client
+        .order(params::POptionsOrder {
+            spec,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    match order {
+        response::NewOptionsOrder::Result(order) => {
+            assert_eq!(order.status, OptionsOrderStatus::Accepted);
+            assert_eq!(order.client_order_id, "bian_opt_1");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("timeInForce").as_deref(), Some("IOC"));
+    assert_eq!(req.param("isMmp").as_deref(), Some("true"));
+    // 未设置的可选参数不发送
+    assert_eq!(req.param("postOnly"), None);
+    assert_eq!(req.param("clientOrderId"), None);
+}
+
+#[tokio::test]
+async fn test_options_ws() {
+    use bian_rs::enums::{OptionSide, OptionsOrderStatus};
+    use bian_rs::user_stream::{OptionsUserDataStream, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    let client = OptionsWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream = client.ticker("BTC-240628-60000-C").unwrap();
+    let ticker = stream.read_stream_single().unwrap();
+    assert_eq!(ticker.symbol.strike, 60000.0);
+    assert_eq!(ticker.symbol.side, OptionSide::Call);
+    assert_eq!(ticker.implied_volatility, 0.544);
+    stream.close_stream();
+    let mut stream = client.mark_price("BTC").unwrap();
+    let marks = stream.read_stream_single().unwrap();
+    assert_eq!(marks[1].symbol.side, OptionSide::Put);
+    assert_eq!(marks[1].mark_price, 412.5);
+    stream.close_stream();
+    let mut stream = client.limit_depth("BTC-240628-60000-C", 10, 100).unwrap();
+    let depth = stream.read_stream_single().unwrap();
+    assert_eq!(depth.sell[0].0, 2950.0);
+    stream.close_stream();
+
+    let mut stream =
+        OptionsUserDataStream::start(mock_client(&server, OptionsHttpClient::new), client);
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(_)
+    ));
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(response::WSOptionsUserStream::AccountUpdate(update)) => {
+            assert_eq!(update.positions[0].quantity, -0.1);
+            assert_eq!(update.greeks[0].delta, -0.0691);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(response::WSOptionsUserStream::OrderUpdate(update)) => {
+            let order = &update.orders[0];
+            assert_eq!(order.order_id, 4611875134427365377);
+            assert_eq!(order.status, OptionsOrderStatus::Filled);
+            assert_eq!(order.fills[0].fee, 0.058);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(response::WSOptionsUserStream::RiskLevelChange(_))
+    ));
+    stream.close().await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.path.as_str()),
+        ("DELETE", "/eapi/v1/listenKey")
+    );
+}
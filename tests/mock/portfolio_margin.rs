@@ -0,0 +1,310 @@
+use super::*;
+
+#[tokio::test]
+async fn test_portfolio_margin_trade() {
+    use bian_rs::enums::{OrderSide, SideEffectType, TimeInForce};
+    use bian_rs::params::{FuturesOrderBuilder, SpotOrderBuilder};
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, PortfolioMarginHttpClient::new);
+    let order = client
+        .um_order(params::PFuturesOrder {
+            spec: FuturesOrderBuilder::market("BTCUSDT", OrderSide::Buy, 10.0).build(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order.order_id, 22542179);
+    assert_eq!(order.time_in_force, TimeInForce::GTD);
+    assert_eq!(server.requests().pop().unwrap().path, "/papi/v1/um/order");
+    let order = client
+        .cm_order(params::PFuturesOrder {
+            spec: FuturesOrderBuilder::limit(
+                "BTCUSD_200925",
+                OrderSide::Buy,
+                10.0,
+                57000.0,
+                TimeInForce::GTC,
+            )
+            .build(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order.cum_base, 0.0);
+    let query = || params::PQueryFuturesOrder {
+        symbol: "BTCUSD_200925".to_string(),
+        order_id: Some(22542179),
+        orig_client_order_id: None,
+        ts: PTimestamp::now(),
+    };
+    client.cm_query_order(query()).await.unwrap();
+    client.cm_cancel_order(query()).await.unwrap();
+    let orders = client
+        .um_open_orders(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(orders[0].order_id, 1917641);
+    let orders = client
+        .cm_all_orders(params::PHistOrder {
+            symbol: "BTCUSD_200925".to_string(),
+            order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(orders[0].symbol, "BTCUSD_200925");
+
+    let resp = client
+        .margin_order(params::PMarginOrder {
+            spec: SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.01).build(),
+            is_isolated: None,
+            side_effect_type: Some(SideEffectType::MarginBuy),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(resp, response::SpotOrder::Full(_)));
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/papi/v1/margin/order");
+    assert_eq!(req.param("isIsolated"), None);
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_reduce_only_and_liquidation() {
+    use bian_rs::enums::{OrderSide, PortfolioMarginStatus};
+    use bian_rs::params::FuturesOrderBuilder;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, PortfolioMarginHttpClient::new);
+    client
+        .um_order(params::PFuturesOrder {
+            spec: FuturesOrderBuilder::market("BTCUSDT", OrderSide::Sell, 0.5)
+                .reduce_only()
+                .build(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("reduceOnly").as_deref(), Some("true"));
+    assert_eq!(req.param("quantity").as_deref(), Some("0.5"));
+    // 市价单不发送价格和有效方式
+    assert_eq!(req.param("price"), None);
+    assert_eq!(req.param("timeInForce"), None);
+
+    // 强平中的账户会返回可用余额, 缺少的字段按 0 处理
+    server.mock(
+        Method::SGET,
+        "papi/v1/account",
+        r#"{"uniMMR":"1.01","accountEquity":"100.5","actualEquity":"99","accountInitialMargin":"90","accountMaintMargin":"99.5","accountStatus":"ACTIVE_LIQUIDATION","virtualMaxWithdrawAmount":"0","totalAvailableBalance":"10.5","updateTime":1657707212154}"#,
+    );
+    let account = client.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(
+        account.account_status,
+        PortfolioMarginStatus::ActiveLiquidation
+    );
+    assert_eq!(account.total_available_balance, 10.5);
+    assert_eq!(account.total_margin_open_loss, 0.0);
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_dry_run_rules_per_product() {
+    use bian_rs::enums::OrderSide;
+    use bian_rs::params::{FuturesOrderBuilder, SpotOrderBuilder};
+    use bian_rs::rules::{ProductType, RuleViolation, SymbolRules, SymbolRulesCache};
+    use std::sync::Arc;
+
+    let server = MockServer::start().unwrap();
+    let um_rules =
+        SymbolRulesCache::from_ufutures(&u_client(&server).exchange_info().await.unwrap());
+    let client = mock_client(&server, PortfolioMarginHttpClient::new)
+        .with_dry_run(true)
+        .with_um_dry_run_rules(Arc::new(um_rules));
+    let margin_order = |quantity: f64| params::PMarginOrder {
+        spec: SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, quantity).build(),
+        is_isolated: None,
+        side_effect_type: None,
+        ts: PTimestamp::now(),
+    };
+    // U本位合约的最小数量为 0.001, 不能用于校验同名的杠杆交易对
+    let err = client.margin_order(margin_order(0.0001)).await.unwrap_err();
+    assert!(matches!(err, APIError::DryRun(ref req) if req.contains("/papi/v1/margin/order?")));
+    let err = client
+        .um_order(params::PFuturesOrder {
+            spec: FuturesOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.0001).build(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        APIError::FilterFailure(RuleViolation::QtyOutOfRange { .. })
+    ));
+
+    let mut margin_rules = SymbolRulesCache::default();
+    margin_rules.insert(SymbolRules {
+        symbol: "BTCUSDT".to_string(),
+        product: ProductType::Spot,
+        min_qty: 0.00001,
+        max_qty: 9000.0,
+        step_size: 0.00001,
+        market_min_qty: 0.00001,
+        market_max_qty: 100.0,
+        market_step_size: 0.00001,
+        ..SymbolRules::default()
+    });
+    let client = client.with_margin_dry_run_rules(Arc::new(margin_rules));
+    let err = client.margin_order(margin_order(0.0001)).await.unwrap_err();
+    assert!(matches!(err, APIError::DryRun(_)));
+    let err = client.margin_order(margin_order(200.0)).await.unwrap_err();
+    assert!(matches!(
+        err,
+        APIError::FilterFailure(RuleViolation::QtyOutOfRange { .. })
+    ));
+    // 只有查询规则的请求发送到了服务器
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_assigns_client_order_id() {
+    use bian_rs::enums::OrderSide;
+    use bian_rs::params::{FuturesOrderBuilder, SpotOrderBuilder};
+
+    let server = MockServer::start().unwrap();
+    let client =
+        mock_client(&server, PortfolioMarginHttpClient::new).with_client_order_prefix("pm-");
+    let futures_order = |symbol: &str| params::PFuturesOrder {
+        spec: FuturesOrderBuilder::market(symbol, OrderSide::Buy, 1.0).build(),
+        ts: PTimestamp::now(),
+    };
+    client.um_order(futures_order("BTCUSDT")).await.unwrap();
+    client.cm_order(futures_order("BTCUSD_PERP")).await.unwrap();
+    client
+        .margin_order(params::PMarginOrder {
+            spec: SpotOrderBuilder::market("BTCUSDT", OrderSide::Buy, 0.01).build(),
+            is_isolated: None,
+            side_effect_type: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let ids: Vec<String> = server
+        .requests()
+        .iter()
+        .filter_map(|r| r.param("newClientOrderId"))
+        .collect();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.iter().all(|id| id.starts_with("pm-")));
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_account() {
+    use bian_rs::enums::PortfolioMarginStatus;
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, PortfolioMarginHttpClient::new);
+    let balances = client
+        .balance(params::PPortfolioMarginBalance {
+            asset: Some("USDT".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(balances[0].um_unrealized_pnl, 23.72469206);
+    assert_eq!(balances[0].cm_unrealized_pnl, 0.0);
+    let account = client.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(account.account_status, PortfolioMarginStatus::Normal);
+    assert_eq!(account.uni_mmr, 5167.92171923);
+
+    let query = || params::POptionSymbolQuery {
+        symbol: None,
+        ts: PTimestamp::now(),
+    };
+    let positions = client.um_positions(query()).await.unwrap();
+    assert_eq!(positions[0].leverage, 10);
+    assert_eq!(positions[0].notional, 20.03852013);
+    let positions = client.cm_positions(query()).await.unwrap();
+    assert_eq!(positions[0].max_qty, 50.0);
+    assert_eq!(positions[0].notional_value, 0.00163839);
+
+    let resp = client.auto_collection(PTimestamp::now()).await.unwrap();
+    assert_eq!(resp.msg, "success");
+    client
+        .asset_collection(params::PAssetCollection {
+            asset: "USDT".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/papi/v1/asset-collection");
+    assert_eq!(req.param("asset").as_deref(), Some("USDT"));
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_user_stream() {
+    use bian_rs::enums::PortfolioMarginStatus;
+    use bian_rs::response::WSPortfolioMarginUserStream as Event;
+    use bian_rs::user_stream::{PortfolioMarginUserDataStream, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    let ws = PortfolioMarginWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream = PortfolioMarginUserDataStream::start(
+        mock_client(&server, PortfolioMarginHttpClient::new),
+        ws,
+    );
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(ref key) if key.ends_with("a6")
+    ));
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(Event::UsdFutures(response::WSUserStream::OrderUpdate(update))) => {
+            assert_eq!(update.event.i, 8886774);
+            assert!(update.event.ot.is_none());
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(Event::CoinFutures(response::WSUserStream::AccountUpdate(
+            update,
+        ))) => {
+            assert_eq!(
+                update.event.positions[0].margin_type,
+                enums::MarginType::Crossed
+            );
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(Event::Margin(response::WSSpotUserStream::AccountPosition(
+            _
+        )))
+    ));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(Event::RiskLevelChange(ref e))
+            if e.status == PortfolioMarginStatus::MarginCall
+    ));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Update(Event::LiabilityChange(ref e)) if e.total_liability == 1.03476851
+    ));
+    stream.close().await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.path.as_str()),
+        ("DELETE", "/papi/v1/listenKey")
+    );
+}
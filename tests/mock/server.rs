@@ -0,0 +1,87 @@
+use super::*;
+
+#[tokio::test]
+async fn test_signature_is_verified() {
+    let server = MockServer::start().unwrap();
+    let client = UFuturesHttpClient::new(
+        MOCK_API_KEY.to_string(),
+        "wrong secret".to_string(),
+        &server.http_url(),
+    )
+    .unwrap();
+    // 不需要签名的接口不受影响
+    client.ping().await.unwrap();
+    match client.account_v2(PTimestamp::now()).await {
+        Err(APIError::ClientSideError(msg)) => assert!(msg.contains("-1022")),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let client = UFuturesHttpClient::new(
+        "wrong key".to_string(),
+        MOCK_SECRET_KEY.to_string(),
+        &server.http_url(),
+    )
+    .unwrap();
+    match client.account_v2(PTimestamp::now()).await {
+        Err(APIError::ClientSideError(msg)) => assert!(msg.contains("-2015")),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_custom_fixture() {
+    let server = MockServer::empty().unwrap();
+    let client = u_client(&server);
+    assert!(matches!(
+        client.ping().await,
+        Err(APIError::ClientSideError(_))
+    ));
+    server.mock(Method::GET, "fapi/v1/ping", "{}");
+    client.ping().await.unwrap();
+    server.mock_status(Method::GET, "fapi/v1/ping", 429, "{}");
+    assert!(matches!(client.ping().await, Err(APIError::FreqWarning)));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].path, "/fapi/v1/ping");
+    assert_eq!(requests[0].api_key.as_deref(), Some(MOCK_API_KEY));
+}
+
+#[tokio::test]
+async fn test_signed_request_params() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    client.account_v2(PTimestamp::now()).await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert!(req.param("timestamp").is_some());
+    assert!(req.param("signature").is_some());
+}
+
+#[test]
+fn test_ws_frames() {
+    let server = MockServer::start().unwrap();
+    let client = UFuturesWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream = client.agg_trade("btcusdt".to_string()).unwrap();
+    for _ in 0..2 {
+        let msg: response::WSAggTrade = stream.read_stream_single().unwrap();
+        assert_eq!(msg.symbol, "BTCUSDT");
+    }
+    stream.close_stream();
+
+    server.push_frames(
+        "stream?streams=btcusdt@aggTrade/ethusdt@aggTrade",
+        vec![
+            r#"{"stream":"ethusdt@aggTrade","data":{"e":"aggTrade","E":1,"s":"ETHUSDT","a":1,"p":"2000.0","q":"1.0","f":1,"l":1,"T":1,"m":true}}"#
+                .to_string(),
+        ],
+    );
+    let mut stream = client
+        .agg_trade_multi(vec!["btcusdt".to_string(), "ethusdt".to_string()])
+        .unwrap();
+    let msg: response::WSAggTrade = stream.read_stream_multi().unwrap();
+    assert_eq!(msg.symbol, "ETHUSDT");
+    stream.close_stream();
+}
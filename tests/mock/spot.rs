@@ -0,0 +1,189 @@
+use super::*;
+
+#[tokio::test]
+async fn test_spot_client() {
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server);
+    client.ping().await.unwrap();
+    client.exchange_info().await.unwrap();
+    client
+        .avg_price(params::PSymbol {
+            symbol: "ADAUSDT".to_string(),
+        })
+        .await
+        .unwrap();
+    let order = client
+        .order(params::PSpotOrder {
+            spec: PSpotOrderSpec {
+                symbol: "ADAUSDT".to_string(),
+                side: enums::OrderSide::Buy,
+                order_type: enums::SpotOrderType::Market,
+                time_in_force: None,
+                quantity: Some(10.0),
+                quote_order_qty: None,
+                price: None,
+                new_client_order_id: None,
+                stop_price: None,
+                iceberg_qty: None,
+                new_order_resp_type: None,
+            },
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    match order {
+        response::SpotOrder::Full(full) => assert_eq!(full.fills.len(), 1),
+        other => panic!("expect full response, got {:?}", other),
+    }
+    let account = client.account(PTimestamp::now()).await.unwrap();
+    assert_eq!(account.balances.len(), 2);
+}
+
+#[tokio::test]
+async fn test_spot_cancel_replace() {
+    use bian_rs::enums::{CancelReplaceMode, CancelReplaceResult, OrderSide};
+    use bian_rs::params::SpotOrderBuilder;
+    use response::{OrderResponse, SpotCancelReplace};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server);
+    let param = || params::PCancelReplace {
+        spec: SpotOrderBuilder::limit("ADAUSDT", OrderSide::Sell, 10.0, 1.25).build(),
+        cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+        cancel_order_id: Some(9),
+        cancel_orig_client_order_id: None,
+        cancel_new_client_order_id: None,
+        ts: PTimestamp::now(),
+    };
+    let resp = client.cancel_replace(param()).await.unwrap();
+    assert_eq!(resp.cancel_result, CancelReplaceResult::Success);
+    assert!(matches!(resp.cancel_response, OrderResponse::Order(ref o) if o.order_id == 9));
+    assert!(matches!(
+        resp.new_order_response,
+        Some(OrderResponse::Order(response::SpotOrder::Ack(ref o))) if o.order_id == 10
+    ));
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        req.param("cancelReplaceMode").as_deref(),
+        Some("STOP_ON_FAILURE")
+    );
+    assert_eq!(req.param("cancelOrderId").as_deref(), Some("9"));
+    assert_eq!(req.param("type").as_deref(), Some("LIMIT"));
+
+    // 撤单失败时不会下单, 结果在错误信息中
+    server.mock_status(
+        Method::SPOST,
+        "api/v3/order/cancelReplace",
+        400,
+        r#"{"code":-2022,"msg":"Order cancel-replace failed.","data":{"cancelResult":"FAILURE","newOrderResult":"NOT_ATTEMPTED","cancelResponse":{"code":-2011,"msg":"Unknown order sent."},"newOrderResponse":null}}"#,
+    );
+    let err = client.cancel_replace(param()).await.unwrap_err();
+    let resp = SpotCancelReplace::from_error(&err).unwrap();
+    assert_eq!(resp.cancel_result, CancelReplaceResult::Failure);
+    assert_eq!(resp.new_order_result, CancelReplaceResult::NotAttempted);
+    assert!(matches!(resp.cancel_response, OrderResponse::Code(ref c) if c.code == -2011));
+    assert!(resp.new_order_response.is_none());
+    assert!(SpotCancelReplace::from_error(&APIError::FreqWarning).is_none());
+}
+
+#[tokio::test]
+async fn test_spot_order_list_and_sor() {
+    use bian_rs::enums::{OrderSide, OrderStatus, SpotOrderType};
+    use bian_rs::params::{OrderLegBuilder, SpotOrderBuilder};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server);
+    let oco = client
+        .order_list_oco(params::POrderListOco {
+            symbol: "ADAUSDT".to_string(),
+            list_client_order_id: None,
+            side: OrderSide::Sell,
+            quantity: 10.0,
+            above: OrderLegBuilder::limit_maker(1.5).build(),
+            below: OrderLegBuilder::stop_loss_limit(1.0, 1.0)
+                .client_order_id("below")
+                .build(),
+            new_order_resp_type: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(oco.contingency_type, "OCO");
+    assert_eq!(oco.order_reports[1].stop_price, 0.0);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("aboveType").as_deref(), Some("LIMIT_MAKER"));
+    assert_eq!(req.param("abovePrice").as_deref(), Some("1.5"));
+    assert_eq!(req.param("aboveTimeInForce"), None);
+    assert_eq!(req.param("belowStopPrice").as_deref(), Some("1"));
+    assert_eq!(req.param("belowTimeInForce").as_deref(), Some("GTC"));
+    assert_eq!(req.param("belowClientOrderId").as_deref(), Some("below"));
+
+    let oto = client
+        .order_list_oto(params::POrderListOto {
+            symbol: "ADAUSDT".to_string(),
+            list_client_order_id: None,
+            working_side: OrderSide::Buy,
+            working_quantity: 10.0,
+            working: OrderLegBuilder::limit(1.2).build(),
+            pending_side: OrderSide::Sell,
+            pending_quantity: 10.0,
+            pending: OrderLegBuilder::market().build(),
+            new_order_resp_type: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(oto.order_reports[1].status, OrderStatus::PendingNew);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("workingType").as_deref(), Some("LIMIT"));
+    assert_eq!(req.param("pendingType").as_deref(), Some("MARKET"));
+    assert_eq!(req.param("pendingSide").as_deref(), Some("SELL"));
+
+    let otoco = client
+        .order_list_otoco(params::POrderListOtoco {
+            symbol: "ADAUSDT".to_string(),
+            list_client_order_id: None,
+            working_side: OrderSide::Buy,
+            working_quantity: 10.0,
+            working: OrderLegBuilder::limit(1.2).build(),
+            pending_side: OrderSide::Sell,
+            pending_quantity: 10.0,
+            pending_above: OrderLegBuilder::limit_maker(1.5).build(),
+            pending_below: OrderLegBuilder::stop_loss_limit(1.0, 1.05)
+                .trailing_delta(100)
+                .build(),
+            new_order_resp_type: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(otoco.orders.len(), 3);
+    assert_eq!(
+        otoco.order_reports[1].order_type,
+        SpotOrderType::StopLossLimit
+    );
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        req.param("pendingAboveType").as_deref(),
+        Some("LIMIT_MAKER")
+    );
+    assert_eq!(req.param("pendingBelowStopPrice").as_deref(), Some("1.05"));
+    assert_eq!(
+        req.param("pendingBelowTrailingDelta").as_deref(),
+        Some("100")
+    );
+    assert_eq!(req.param("pendingBelowIcebergQty"), None);
+
+    let order = || params::PSpotOrder {
+        spec: SpotOrderBuilder::limit("ADAUSDT", OrderSide::Buy, 10.0, 1.25).build(),
+        ts: PTimestamp::now(),
+    };
+    let sor = client.sor_order(order()).await.unwrap();
+    assert!(sor.used_sor);
+    assert_eq!(sor.order.fills[0].price, 1.24);
+    client.sor_order_test(order()).await.unwrap();
+    assert_eq!(
+        server.requests().pop().unwrap().path,
+        "/api/v3/sor/order/test"
+    );
+}
@@ -0,0 +1,192 @@
+use super::*;
+
+#[tokio::test]
+async fn test_sub_account_management() {
+    use bian_rs::enums::{SubAccountType, SubFuturesType};
+
+    let server = MockServer::start().unwrap();
+    let client = mock_client(&server, SubAccountHttpClient::new);
+    let list = client
+        .list(params::PSubAccountList {
+            email: None,
+            is_freeze: Some(true),
+            page: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(list.sub_accounts[1].is_freeze);
+    assert_eq!(
+        server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("isFreeze")
+            .as_deref(),
+        Some("true")
+    );
+    let sub = client
+        .create_virtual(params::PVirtualSubAccount {
+            sub_account_string: "strategy_1".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(sub.email, "strategy_1@xxx_virtual.com");
+
+    let summary = client
+        .spot_summary(params::PSubSpotSummary {
+            email: None,
+            page: None,
+            size: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(summary.sub_accounts[0].total_asset, 9999.0);
+    let summary = client
+        .futures_summary(params::PSubFuturesSummary {
+            futures_type: SubFuturesType::UsdM,
+            page: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(summary.coin_futures.is_none());
+    let usd = summary.usd_futures.unwrap();
+    assert_eq!(usd.sub_account_list[0].total_wallet_balance, 22.12659734);
+    assert_eq!(
+        server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("futuresType")
+            .as_deref(),
+        Some("1")
+    );
+
+    let history = client
+        .transfer_history(params::PSubTransferHistory {
+            from_email: None,
+            to_email: None,
+            client_tran_id: None,
+            start_time: None,
+            end_time: None,
+            page: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        history.result[0].from_account_type,
+        SubAccountType::UsdtFuture
+    );
+}
+
+#[tokio::test]
+async fn test_sub_account_on_behalf() {
+    use bian_rs::enums::{IpRestriction, SubAccountType};
+
+    let server = MockServer::start().unwrap();
+    let sub = mock_client(&server, SubAccountHttpClient::new).sub_account("testsub@gmail.com");
+    let assets = sub.assets().await.unwrap();
+    assert_eq!(assets.balances[1].free, 1500.5);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/sapi/v4/sub-account/assets");
+    assert_eq!(req.param("email").as_deref(), Some("testsub@gmail.com"));
+    assert!(sub.enable_futures().await.unwrap().enabled);
+    assert!(sub.enable_margin().await.unwrap().enabled);
+
+    let resp = sub
+        .transfer_in(
+            SubAccountType::Spot,
+            SubAccountType::UsdtFuture,
+            "USDT",
+            100.0,
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.tran_id, 11945860693);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("fromEmail"), None);
+    assert_eq!(req.param("toEmail").as_deref(), Some("testsub@gmail.com"));
+    assert_eq!(req.param("toAccountType").as_deref(), Some("USDT_FUTURE"));
+    sub.transfer_out(SubAccountType::Spot, SubAccountType::Spot, "USDT", 1.0)
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("fromEmail").as_deref(), Some("testsub@gmail.com"));
+    assert_eq!(req.param("toEmail"), None);
+
+    let restriction = sub
+        .restrict_api_ips("sub_api_key", &["69.210.67.14", "8.34.21.10"])
+        .await
+        .unwrap();
+    assert_eq!(restriction.status, IpRestriction::Restricted);
+    assert_eq!(restriction.ip_list.len(), 2);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("status").as_deref(), Some("2"));
+    assert_eq!(
+        req.param("ipAddress").as_deref(),
+        Some("69.210.67.14,8.34.21.10")
+    );
+    sub.api_ip_restriction("sub_api_key").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_sub_account_coin_futures_and_isolated_transfer() {
+    use bian_rs::enums::{SubAccountType, SubFuturesType};
+
+    let server = MockServer::start().unwrap();
+    server.mock(
+        Method::SGET,
+        "sapi/v2/sub-account/futures/accountSummary?futuresType=2",
+        r#"{"deliveryAccountSummaryResp":{"totalMarginBalanceOfBTC":"25.03221121","totalUnrealizedProfitOfBTC":"0.12000000","totalWalletBalanceOfBTC":"22.15879444","asset":"BTC","subAccountList":[{"email":"123@test.com","totalMarginBalance":"22.12659734","totalUnrealizedProfit":"0","totalWalletBalance":"22.12659734","asset":"BTC"}]}}"#,
+    );
+    let client = mock_client(&server, SubAccountHttpClient::new);
+    let summary = client
+        .futures_summary(params::PSubFuturesSummary {
+            futures_type: SubFuturesType::CoinM,
+            page: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(summary.usd_futures.is_none());
+    let coin = summary.coin_futures.unwrap();
+    assert_eq!(coin.total_unrealized_profit_of_btc, 0.12);
+    assert_eq!(coin.sub_account_list[0].total_unrealized_profit, 0.0);
+
+    // 未设置 clientTranId 时响应中没有该字段
+    server.mock(
+        Method::SPOST,
+        "sapi/v1/sub-account/universalTransfer",
+        r#"{"tranId":11945860694}"#,
+    );
+    let resp = client
+        .universal_transfer(params::PSubUniversalTransfer {
+            from_email: Some("testsub@gmail.com".to_string()),
+            to_email: None,
+            from_account_type: SubAccountType::IsolatedMargin,
+            to_account_type: SubAccountType::Spot,
+            client_tran_id: None,
+            symbol: Some("BTCUSDT".to_string()),
+            asset: "USDT".to_string(),
+            amount: 0.5,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.client_tran_id, "");
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        req.param("fromAccountType").as_deref(),
+        Some("ISOLATED_MARGIN")
+    );
+    assert_eq!(req.param("symbol").as_deref(), Some("BTCUSDT"));
+    assert_eq!(req.param("clientTranId"), None);
+}
@@ -0,0 +1,175 @@
+use super::*;
+
+/// 对交易场所泛型的策略代码
+async fn run_venue<C: MarketData + OrderExecution>(client: &C, symbol: &str) {
+    let price = client.last_price(symbol).await.unwrap();
+    assert!(price > 0.0);
+    let quote = client.best_quote(symbol).await.unwrap();
+    assert!(quote.bid_price <= quote.ask_price);
+    let book = client.order_book(symbol, 5).await.unwrap();
+    assert!(!book.bids.is_empty());
+    let order = client
+        .submit_order(OrderRequest::limit(
+            symbol,
+            enums::OrderSide::Buy,
+            1.0,
+            quote.bid_price,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(order.symbol, symbol);
+    client.fetch_order(symbol, order.order_id).await.unwrap();
+    client.fetch_open_orders(Some(symbol)).await.unwrap();
+    let canceled = client.cancel(symbol, order.order_id).await.unwrap();
+    assert_eq!(canceled.status, enums::OrderStatus::Canceled);
+    let fills = client.fetch_fills(symbol).await.unwrap();
+    assert_eq!(fills[0].symbol, symbol);
+    assert!(!client.fetch_balances().await.unwrap().is_empty());
+    client.fetch_positions(symbol).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_trading_traits() {
+    let server = MockServer::start().unwrap();
+    run_venue(&u_client(&server), "BTCUSDT").await;
+    run_venue(&d_client(&server), "BTCUSD_PERP").await;
+    run_venue(&spot_client(&server), "ADAUSDT").await;
+
+    let positions = d_client(&server)
+        .fetch_positions("BTCUSD_PERP")
+        .await
+        .unwrap();
+    assert_eq!(positions[0].amount, 2.0);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/dapi/v2/positionRisk");
+
+    // 没有行情不是解码错误
+    server.mock(Method::GET, "dapi/v1/ticker/price", "[]");
+    server.mock(Method::GET, "dapi/v1/ticker/bookTicker", "[]");
+    let client = d_client(&server);
+    let err = client.last_price("BTCUSD_PERP").await.unwrap_err();
+    assert!(matches!(err, APIError::NoMarketData(ref s) if s == "BTCUSD_PERP"));
+    let err = client.best_quote("BTCUSD_PERP").await.unwrap_err();
+    assert!(matches!(err, APIError::NoMarketData(_)));
+}
+
+#[tokio::test]
+async fn test_submit_assigns_client_order_id() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server).with_client_order_prefix("bot1-");
+    let req = OrderRequest::limit("BTCUSDT", enums::OrderSide::Buy, 0.01, 57000.0);
+    client.submit_order(req.clone()).await.unwrap();
+    client.submit_order(req).await.unwrap();
+    let ids: Vec<String> = server
+        .requests()
+        .iter()
+        .filter_map(|r| r.param("newClientOrderId"))
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert!(ids
+        .iter()
+        .all(|id| id.starts_with("bot1-") && id.len() <= 36));
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[tokio::test]
+async fn test_raw_orders_assign_client_order_id() {
+    use bian_rs::params::{FuturesOrderBuilder, SpotOrderBuilder};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server).with_client_order_prefix("raw-");
+    client
+        .order(params::PSpotOrder {
+            spec: SpotOrderBuilder::market("BTCUSDT", enums::OrderSide::Buy, 0.01).build(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert!(req.param("newClientOrderId").unwrap().starts_with("raw-"));
+
+    let client = u_client(&server).with_client_order_prefix("batch-");
+    server.mock(
+        Method::SPOST,
+        "fapi/v1/batchOrders",
+        r#"[{"code":-2019,"msg":"Margin is insufficient."},{"code":-2019,"msg":"Margin is insufficient."}]"#,
+    );
+    client
+        .batch_order(params::PBatchOrder {
+            batch_orders: vec![
+                FuturesOrderBuilder::market("BTCUSDT", enums::OrderSide::Buy, 0.01)
+                    .client_order_id("keep")
+                    .build(),
+                FuturesOrderBuilder::market("BTCUSDT", enums::OrderSide::Buy, 0.01).build(),
+            ],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    let orders: serde_json::Value =
+        serde_json::from_str(&req.param("batchOrders").unwrap()).unwrap();
+    assert_eq!(orders[0]["newClientOrderId"], "keep");
+    assert!(orders[1]["newClientOrderId"]
+        .as_str()
+        .unwrap()
+        .starts_with("batch-"));
+    assert!(orders[1].get("positionSide").is_none());
+    assert!(orders[1].get("price").is_none());
+    assert_eq!(orders[1]["quantity"], "0.01");
+}
+
+#[tokio::test]
+async fn test_submit_queries_after_ambiguous_failure() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let mut req = OrderRequest::limit("BTCUSDT", enums::OrderSide::Buy, 0.01, 57000.0);
+    req.client_order_id = Some("testOrder".to_string());
+    // 下单超时或服务端错误时无法确定订单是否已提交
+    server.mock_status(Method::SPOST, "fapi/v1/order", 503, "Service Unavailable");
+    let start = std::time::Instant::now();
+    let order = client.submit_order(req.clone()).await.unwrap();
+    assert!(start.elapsed() >= trading::SUBMIT_QUERY_DELAY);
+    assert_eq!(order.client_order_id, "testOrder");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "GET");
+    assert_eq!(
+        requests[1].param("origClientOrderId").as_deref(),
+        Some("testOrder")
+    );
+
+    // 订单不存在时使用同一订单号重新下单
+    server.mock_status(
+        Method::SGET,
+        "fapi/v1/order",
+        400,
+        r#"{"code":-2013,"msg":"Order does not exist."}"#,
+    );
+    let err = client.submit_order(req.clone()).await.unwrap_err();
+    assert!(matches!(err, APIError::ServerSideError(_)));
+    let posts: Vec<_> = server
+        .requests()
+        .into_iter()
+        .skip(2)
+        .filter(|r| r.method == "POST")
+        .collect();
+    assert_eq!(posts.len(), 1 + trading::SUBMIT_RETRIES);
+    assert!(posts
+        .iter()
+        .all(|r| r.param("newClientOrderId").as_deref() == Some("testOrder")));
+
+    // 明确被拒绝的订单不会查询
+    server.mock_status(
+        Method::SPOST,
+        "fapi/v1/order",
+        400,
+        r#"{"code":-1111,"msg":"Precision is over the maximum defined for this asset."}"#,
+    );
+    let before = server.requests().len();
+    assert!(matches!(
+        client.submit_order(req).await,
+        Err(APIError::ClientSideError(_))
+    ));
+    assert_eq!(server.requests().len(), before + 1);
+}
@@ -0,0 +1,347 @@
+use super::*;
+
+#[tokio::test]
+async fn test_u_market() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    client.ping().await.unwrap();
+    client.server_time().await.unwrap();
+    let info = client.exchange_info().await.unwrap();
+    assert_eq!(info.symbols[0].symbol, "BTCUSDT");
+    let depth = client
+        .depth(params::PDepth {
+            symbol: "BTCUSDT".to_string(),
+            limit: 5,
+        })
+        .await
+        .unwrap();
+    assert_eq!(depth.bids.len(), 2);
+    client
+        .trades(params::PTrade {
+            symbol: "BTCUSDT".to_string(),
+            limit: 10,
+        })
+        .await
+        .unwrap();
+    let klines = client
+        .klines(params::PKline {
+            symbol: "BTCUSDT".to_string(),
+            interval: enums::Interval::Min1,
+            start_time: None,
+            end_time: None,
+            limit: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(klines[0].count, 308);
+}
+
+#[tokio::test]
+async fn test_u_single_and_multi_symbol() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let symbol = || params::PSymbol {
+        symbol: "BTCUSDT".to_string(),
+    };
+    assert_eq!(client.price(symbol()).await.unwrap().price, 58000.1);
+    assert_eq!(client.prices().await.unwrap().len(), 1);
+    client.premium_index(symbol()).await.unwrap();
+    client.premium_indexes().await.unwrap();
+    client.book_ticker(symbol()).await.unwrap();
+    client.book_tickers().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_u_trade() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let order = client
+        .order(params::PFuturesOrder {
+            spec: futures_order_spec(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order.client_order_id, "testOrder");
+    let query = || params::PQueryFuturesOrder {
+        symbol: "BTCUSDT".to_string(),
+        order_id: Some(order.order_id),
+        orig_client_order_id: None,
+        ts: PTimestamp::now(),
+    };
+    client.query_order(query()).await.unwrap();
+    let canceled = client.cancel_order(query()).await.unwrap();
+    assert!(matches!(canceled.status, enums::OrderStatus::Canceled));
+    client
+        .open_orders(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    client
+        .countdown_cancel_all(params::PCountdownCancel {
+            symbol: "BTCUSDT".to_string(),
+            countdown_time: 100000,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_u_account() {
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    client.account_balance_v2(PTimestamp::now()).await.unwrap();
+    client.account_v2(PTimestamp::now()).await.unwrap();
+    client
+        .position_risk(params::PSymbolWithTs {
+            symbol: "BTCUSDT".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let key = client.create_listen_key().await.unwrap();
+    assert!(!key.listen_key.is_empty());
+    client.update_listen_key().await.unwrap();
+    client.close_listen_key().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_u_income_and_account_status() {
+    use bian_rs::enums::{AutoCloseType, IncomeType};
+    use bian_rs::pagination::collect_page_numbers;
+
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let price = client
+        .price_v2(params::PSymbol {
+            symbol: "BTCUSDT".to_string(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(price.price, 57012.4);
+    let prices = client.prices_v2().await.unwrap();
+    assert_eq!(prices.len(), 2);
+
+    // 第二页为空时结束翻页
+    server.mock(Method::SGET, "fapi/v1/income?page=2", "[]");
+    let income = collect_page_numbers(3, |page| {
+        client.income(params::PIncome {
+            symbol: None,
+            income_type: None,
+            start_time: None,
+            end_time: None,
+            page: Some(page),
+            limit: Some(3),
+            ts: PTimestamp::now(),
+        })
+    })
+    .await
+    .unwrap();
+    assert_eq!(income.len(), 3);
+    assert_eq!(income[1].income_type, IncomeType::Commission);
+    assert_eq!(income[1].trade_id, "2059192");
+    assert_eq!(income[2].income, 0.001248);
+    let pages = server
+        .requests()
+        .iter()
+        .filter(|r| r.path == "/fapi/v1/income")
+        .filter_map(|r| r.param("page"))
+        .collect::<Vec<_>>();
+    assert_eq!(pages, vec!["1", "2"]);
+
+    client
+        .income(params::PIncome {
+            symbol: Some("BTCUSDT".to_string()),
+            income_type: Some(IncomeType::FundingFee),
+            start_time: None,
+            end_time: None,
+            page: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("incomeType").as_deref(), Some("FUNDING_FEE"));
+
+    let rate = client
+        .commission_rate(params::PSymbolWithTs {
+            symbol: "BTCUSDT".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(rate.taker_commission_rate, 0.0004);
+
+    let adl = client
+        .adl_quantile(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(adl[0].adl_quantile.hedge, Some(0));
+    assert_eq!(adl[1].adl_quantile.both, Some(0));
+
+    let bracket = client
+        .leverage_bracket(params::PSymbolWithTs {
+            symbol: "BTCUSDT".to_string(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(bracket.brackets[0].initial_leverage, 125);
+    assert!(bracket.notional_coef.is_none());
+    let brackets = client.leverage_brackets(PTimestamp::now()).await.unwrap();
+    assert_eq!(brackets[0].brackets[1].maint_margin_ratio, 0.01);
+
+    let orders = client
+        .force_orders(params::PUserForceOrders {
+            symbol: None,
+            auto_close_type: Some(AutoCloseType::Liquidation),
+            start_time: None,
+            end_time: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(orders[0].client_order_id, "autoclose-1596107620040000020");
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("autoCloseType").as_deref(), Some("LIQUIDATION"));
+
+    let mode = client.multi_assets_margin(PTimestamp::now()).await.unwrap();
+    assert!(mode.multi_assets_margin);
+    client
+        .set_multi_assets_margin(params::PMultiAssetsMargin {
+            multi_assets_margin: false,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("multiAssetsMargin").as_deref(), Some("false"));
+
+    let status = client
+        .api_trading_status(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(status.indicators["BTCUSDT"][0].is_locked);
+
+    let id = client
+        .order_download_id(params::PAsyncDownload {
+            start_time: 1640995200000,
+            end_time: 1643673600000,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let link = client
+        .order_download_link(params::PDownloadId {
+            download_id: id.download_id,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(link.status, "completed");
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/fapi/v1/order/asyn/id");
+    assert_eq!(
+        req.param("downloadId").as_deref(),
+        Some("546975389218332672")
+    );
+}
+
+#[tokio::test]
+async fn test_futures_modify_order() {
+    use bian_rs::enums::OrderSide;
+
+    let server = MockServer::start().unwrap();
+    let client = u_client(&server);
+    let spec = params::PModifyOrderSpec {
+        symbol: "BTCUSDT".to_string(),
+        order_id: Some(22542179),
+        orig_client_order_id: None,
+        side: OrderSide::Buy,
+        quantity: Some(0.02),
+        price: Some(56500.0),
+    };
+    let order = client
+        .modify_order(params::PModifyOrder {
+            spec: spec.clone(),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(order.price, 56500.0);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.method, "PUT");
+    assert_eq!(req.param("price").as_deref(), Some("56500"));
+    assert_eq!(req.param("origClientOrderId"), None);
+
+    let mut by_client_id = spec.clone();
+    by_client_id.order_id = None;
+    by_client_id.orig_client_order_id = Some("missing".to_string());
+    let resp = client
+        .batch_modify_orders(params::PBatchModifyOrder {
+            batch_orders: vec![spec, by_client_id],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(matches!(resp[0], response::BatchOrderResponse::Order(_)));
+    assert!(matches!(resp[1], response::BatchOrderResponse::Code(ref c) if c.code == -2013));
+    // 批量参数以 JSON 字符串发送
+    let batch: serde_json::Value = serde_json::from_str(
+        &server
+            .requests()
+            .pop()
+            .unwrap()
+            .param("batchOrders")
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(batch[0]["orderId"], 22542179);
+    assert_eq!(batch[0]["quantity"], "0.02");
+    assert_eq!(batch[1]["origClientOrderId"], "missing");
+    assert!(batch[1].get("orderId").is_none());
+
+    let amendments = client
+        .order_amendment(params::POrderAmendment {
+            symbol: "BTCUSDT".to_string(),
+            order_id: Some(22542179),
+            orig_client_order_id: None,
+            start_time: None,
+            end_time: None,
+            limit: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(amendments[0].amendment.price.before, 57000.0);
+    assert_eq!(amendments[0].amendment.orig_qty.after, 0.02);
+
+    d_client(&server)
+        .modify_order(params::PModifyOrder {
+            spec: params::PModifyOrderSpec {
+                symbol: "BTCUSD_PERP".to_string(),
+                order_id: Some(1),
+                orig_client_order_id: None,
+                side: OrderSide::Buy,
+                quantity: None,
+                price: Some(40000.0),
+            },
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/dapi/v1/order");
+    assert_eq!(req.param("quantity"), None);
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+fn u_ws_client(server: &MockServer) -> UFuturesWSClient {
+    UFuturesWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    }
+}
+
+const LISTEN_KEY: &str = "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1";
+
+#[tokio::test]
+async fn test_user_data_stream_rotates_expired_key() {
+    use bian_rs::user_stream::{ReconnectReason, UFuturesUserDataStream, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    server.push_frames(
+        &format!("ws/{}", LISTEN_KEY),
+        vec![r#"{"e":"listenKeyExpired","E":1576653824250}"#.to_string()],
+    );
+    let mut stream = UFuturesUserDataStream::start(u_client(&server), u_ws_client(&server));
+    for _ in 0..2 {
+        match stream.next().await.unwrap().unwrap() {
+            UserDataEvent::Connected(key) => assert_eq!(key, LISTEN_KEY),
+            other => panic!("unexpected {:?}", other),
+        }
+        match stream.next().await.unwrap().unwrap() {
+            UserDataEvent::Reconnecting(reason) => {
+                assert_eq!(reason, ReconnectReason::ListenKeyExpired)
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+    let created = server
+        .requests()
+        .iter()
+        .filter(|r| r.method == "POST" && r.path == "/fapi/v1/listenKey")
+        .count();
+    assert!(created >= 2);
+}
+
+#[tokio::test]
+async fn test_user_data_stream_keepalive_and_close() {
+    use bian_rs::user_stream::{UFuturesUserDataStream, UserDataEvent};
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    let mut stream = UFuturesUserDataStream::with_keepalive_interval(
+        u_client(&server),
+        u_ws_client(&server),
+        Duration::from_millis(100),
+    );
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(_)
+    ));
+    assert_eq!(stream.listen_key().as_deref(), Some(LISTEN_KEY));
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    let count = |method: &str| {
+        server
+            .requests()
+            .iter()
+            .filter(|r| r.method == method && r.path == "/fapi/v1/listenKey")
+            .count()
+    };
+    assert!(count("PUT") >= 2);
+    assert_eq!(count("DELETE"), 0);
+
+    // drop 时在后台关闭 listenKey
+    drop(stream);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(count("DELETE"), 1);
+}
+
+#[tokio::test]
+async fn test_user_data_stream_drop_closes_socket() {
+    use bian_rs::user_stream::{UFuturesUserDataStream, UserDataEvent};
+    use std::time::Duration;
+
+    let server = MockServer::start().unwrap();
+    server.push_frames(&format!("ws/{}", LISTEN_KEY), vec![]);
+    let mut stream = UFuturesUserDataStream::start(u_client(&server), u_ws_client(&server));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(_)
+    ));
+    assert_eq!(server.ws_connections(), 1);
+    // 等待读取线程阻塞在读取上, 没有推送时也会在读取超时后退出
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    drop(stream);
+    for _ in 0..30 {
+        if server.ws_connections() == 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(server.ws_connections(), 0);
+}
+
+#[tokio::test]
+async fn test_user_data_stream_events() {
+    use bian_rs::user_stream::{UFuturesUserDataStream, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    let mut stream = UFuturesUserDataStream::start(u_client(&server), u_ws_client(&server));
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(_)
+    ));
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(response::WSUserStream::AccountUpdate(update)) => {
+            assert_eq!(update.event.balances.len(), 2)
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(response::WSUserStream::OrderUpdate(update)) => {
+            assert_eq!(update.event.c, "TEST")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    stream.close().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_spot_user_data_stream() {
+    use bian_rs::user_stream::{SpotUserDataStream, UserDataEvent};
+
+    let server = MockServer::start().unwrap();
+    let ws = SpotWSClient {
+        proxy: None,
+        base_url: url::Url::parse(&server.ws_url()).unwrap(),
+    };
+    let mut stream = SpotUserDataStream::start(spot_client(&server), ws);
+    assert!(matches!(
+        stream.next().await.unwrap().unwrap(),
+        UserDataEvent::Connected(_)
+    ));
+    for _ in 0..2 {
+        stream.next().await.unwrap().unwrap();
+    }
+    match stream.next().await.unwrap().unwrap() {
+        UserDataEvent::Update(response::WSSpotUserStream::ExecutionReport(report)) => {
+            assert_eq!(report.order_id, 4293153)
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    let listen_key = stream.listen_key().unwrap();
+    stream.close().await.unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.path.as_str()),
+        ("DELETE", "/api/v3/userDataStream")
+    );
+    assert_eq!(req.param("listenKey"), Some(listen_key));
+}
@@ -0,0 +1,206 @@
+use super::*;
+
+#[tokio::test]
+async fn test_wallet_deposit_and_withdraw() {
+    use bian_rs::enums::{DepositStatus, WithdrawStatus};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server).wallet();
+    let coins = client.coins(PTimestamp::now()).await.unwrap();
+    let btc = &coins[0].network_list[1];
+    assert!(btc.is_default && btc.busy);
+    assert_eq!(btc.withdraw_fee, 0.0005);
+    let address = client
+        .deposit_address(params::PDepositAddress {
+            coin: "BTC".to_string(),
+            network: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(address.address, "1HPn8Rx2y6nNSfagQBKy27GB99Vbzg89wv");
+    let deposits = client
+        .deposit_history(params::PDepositHistory {
+            coin: None,
+            status: Some(DepositStatus::Credited),
+            start_time: None,
+            end_time: None,
+            offset: None,
+            limit: None,
+            tx_id: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(deposits[0].status, DepositStatus::Pending);
+    assert_eq!(deposits[1].status, DepositStatus::Credited);
+    assert_eq!(
+        server.requests().pop().unwrap().param("status").as_deref(),
+        Some("6")
+    );
+
+    let withdraw = || params::PWithdraw {
+        coin: "USDT".to_string(),
+        withdraw_order_id: Some("bian_w_1".to_string()),
+        network: Some("ETH".to_string()),
+        address: "0x94df8b352de7f46f64b01d3666bf6e936e44ce60".to_string(),
+        address_tag: None,
+        amount: 10.0,
+        transaction_fee_flag: None,
+        name: None,
+        ts: PTimestamp::now(),
+    };
+    // 未开启提币时不发送请求
+    let count = server.requests().len();
+    let err = client.withdraw(withdraw()).await.unwrap_err();
+    assert!(matches!(err, APIError::WithdrawDisabled));
+    assert_eq!(server.requests().len(), count);
+    let client = client.with_withdraw(true);
+    let resp = client.withdraw(withdraw()).await.unwrap();
+    assert_eq!(resp.id, "7213fea8e94b4a5593d507237e5a555b");
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.path, "/sapi/v1/capital/withdraw/apply");
+    assert_eq!(req.param("withdrawOrderId").as_deref(), Some("bian_w_1"));
+
+    let history = client
+        .withdraw_history(params::PWithdrawHistory {
+            coin: None,
+            withdraw_order_id: None,
+            status: None,
+            offset: None,
+            limit: None,
+            start_time: None,
+            end_time: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(history[0].status, WithdrawStatus::Completed);
+    assert_eq!(history[1].status, WithdrawStatus::Rejected);
+    assert_eq!(history[1].tx_id, "");
+    assert_eq!(history[1].withdraw_order_id, None);
+}
+
+#[tokio::test]
+async fn test_wallet_optional_params() {
+    use bian_rs::enums::UniversalTransferType;
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server).wallet().with_withdraw(true);
+    client
+        .withdraw(params::PWithdraw {
+            coin: "XRP".to_string(),
+            withdraw_order_id: None,
+            network: None,
+            address: "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh".to_string(),
+            address_tag: Some("101907".to_string()),
+            amount: 25.5,
+            transaction_fee_flag: Some(true),
+            name: Some("cold wallet".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(req.param("addressTag").as_deref(), Some("101907"));
+    assert_eq!(req.param("transactionFeeFlag").as_deref(), Some("true"));
+    assert_eq!(req.param("name").as_deref(), Some("cold wallet"));
+    assert_eq!(req.param("amount").as_deref(), Some("25.5"));
+    // 不传网络时使用默认网络
+    assert_eq!(req.param("network"), None);
+    assert_eq!(req.param("withdrawOrderId"), None);
+
+    // 逐仓之间划转需要同时指定转出和转入交易对
+    client
+        .transfer(params::PUniversalTransfer {
+            transfer_type: UniversalTransferType::IsolatedMarginIsolatedMargin,
+            asset: "USDT".to_string(),
+            amount: 10.0,
+            from_symbol: Some("BTCUSDT".to_string()),
+            to_symbol: Some("ETHUSDT".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        req.param("type").as_deref(),
+        Some("ISOLATEDMARGIN_ISOLATEDMARGIN")
+    );
+    assert_eq!(req.param("fromSymbol").as_deref(), Some("BTCUSDT"));
+    assert_eq!(req.param("toSymbol").as_deref(), Some("ETHUSDT"));
+}
+
+#[tokio::test]
+async fn test_wallet_assets() {
+    use bian_rs::enums::{TransferStatus, UniversalTransferType};
+
+    let server = MockServer::start().unwrap();
+    let client = spot_client(&server).wallet();
+    let resp = client
+        .transfer(params::PUniversalTransfer {
+            transfer_type: UniversalTransferType::MainUmFuture,
+            asset: "USDT".to_string(),
+            amount: 100.0,
+            from_symbol: None,
+            to_symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.tran_id, 13526853623);
+    let req = server.requests().pop().unwrap();
+    assert_eq!(
+        (req.method.as_str(), req.param("type").as_deref()),
+        ("POST", Some("MAIN_UMFUTURE"))
+    );
+    let history = client
+        .transfer_history(params::PTransferHistory {
+            transfer_type: UniversalTransferType::MainUmFuture,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            from_symbol: None,
+            to_symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(history.total, 2);
+    assert_eq!(history.rows[0].status, TransferStatus::Confirmed);
+
+    let details = client
+        .asset_detail(params::PAssetDetail {
+            asset: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert!(!details["CTR"].deposit_status);
+    assert_eq!(details["SKY"].withdraw_fee, 0.01);
+    assert_eq!(details["SKY"].deposit_tip, None);
+    let fees = client
+        .trade_fee(params::POptionSymbolQuery {
+            symbol: None,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(fees[1].taker_commission, 0.001);
+
+    let dust = client.dust_assets(PTimestamp::now()).await.unwrap();
+    assert_eq!(dust.details[0].to_bnb_off_exchange, 0.01741756);
+    let resp = client
+        .dust(params::PDust {
+            assets: vec!["ETH".to_string(), "LTC".to_string()],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.total_transferred, 1.05127099);
+    assert_eq!(resp.transfer_result[1].from_asset, "LTC");
+    // 每个资产一个 asset 参数
+    let req = server.requests().pop().unwrap();
+    assert!(req.query.starts_with("asset=ETH&asset=LTC&"));
+}
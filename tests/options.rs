@@ -0,0 +1,27 @@
+use bian_rs::enums::OptionSide;
+use bian_rs::response::OptionSymbol;
+
+#[test]
+fn test_option_symbol() {
+    let symbol: OptionSymbol = "BTC-240628-60000-C".parse().unwrap();
+    assert_eq!(symbol.base_asset, "BTC");
+    assert_eq!(symbol.expiry.to_string(), "2024-06-28");
+    assert_eq!(symbol.strike, 60000.0);
+    assert_eq!(symbol.side, OptionSide::Call);
+    assert_eq!(symbol.expiry_time(), 1719561600000);
+    assert_eq!(symbol.to_string(), "BTC-240628-60000-C");
+
+    let symbol: OptionSymbol = "DOGE-240628-0.125-P".parse().unwrap();
+    assert_eq!(symbol.strike, 0.125);
+    assert_eq!(symbol.side, OptionSide::Put);
+    assert_eq!(symbol.to_string(), "DOGE-240628-0.125-P");
+
+    for invalid in [
+        "BTCUSDT",
+        "BTC-240631-60000-C",
+        "BTC-240628-x-C",
+        "BTC-240628-60000-X",
+    ] {
+        assert!(invalid.parse::<OptionSymbol>().is_err(), "{}", invalid);
+    }
+}
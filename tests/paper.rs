@@ -0,0 +1,397 @@
+use bian_rs::enums::{self, FuturesOrderType, OrderSide, OrderStatus, PositionDirect};
+use bian_rs::error::APIError;
+use bian_rs::paper::PaperExchange;
+use bian_rs::params::{self, PFuturesOrderSpec, PTimestamp};
+use bian_rs::response::{self, WSUserStream};
+
+const EXCHANGE_INFO: &str = include_str!("../src/mock/fixtures/ufutures/exchange_info.json");
+
+fn init_exchange() -> PaperExchange {
+    let info: response::UFuturesExchangeInfo = serde_json::from_str(EXCHANGE_INFO).unwrap();
+    let exchange =
+        PaperExchange::new(&info, vec![("USDT".to_string(), 10000.0)]).with_fee(0.0, 0.0);
+    exchange.on_book_ticker(&book_ticker(1, 58000.0, 58000.1));
+    exchange
+}
+
+fn book_ticker(time: i64, bid: f64, ask: f64) -> response::WSFuturesBookTicker {
+    serde_json::from_value(serde_json::json!({
+        "e": "bookTicker",
+        "u": time,
+        "E": time,
+        "T": time,
+        "s": "BTCUSDT",
+        "b": bid.to_string(),
+        "B": "1",
+        "a": ask.to_string(),
+        "A": "1"
+    }))
+    .unwrap()
+}
+
+fn agg_trade(time: i64, price: f64) -> response::WSAggTrade {
+    serde_json::from_value(serde_json::json!({
+        "e": "aggTrade",
+        "E": time,
+        "s": "BTCUSDT",
+        "a": time,
+        "p": price.to_string(),
+        "q": "0.1",
+        "f": time,
+        "l": time,
+        "T": time,
+        "m": true
+    }))
+    .unwrap()
+}
+
+fn spec(side: OrderSide, order_type: FuturesOrderType, quantity: f64) -> PFuturesOrderSpec {
+    PFuturesOrderSpec {
+        symbol: "BTCUSDT".to_string(),
+        side,
+        position_side: None,
+        order_type,
+        reduce_only: None,
+        quantity: Some(quantity),
+        price: None,
+        new_client_order_id: None,
+        stop_price: None,
+        close_position: None,
+        activation_price: None,
+        callback_rate: None,
+        time_in_force: None,
+        working_type: None,
+        price_protect: None,
+        new_order_resp_type: None,
+    }
+}
+
+fn limit(side: OrderSide, quantity: f64, price: f64) -> PFuturesOrderSpec {
+    let mut spec = spec(side, FuturesOrderType::Limit, quantity);
+    spec.price = Some(price);
+    spec.time_in_force = Some(enums::TimeInForce::GTC);
+    spec
+}
+
+fn order(spec: PFuturesOrderSpec) -> params::PFuturesOrder {
+    params::PFuturesOrder {
+        spec,
+        ts: PTimestamp::now(),
+    }
+}
+
+fn query(order_id: usize) -> params::PQueryFuturesOrder {
+    params::PQueryFuturesOrder {
+        symbol: "BTCUSDT".to_string(),
+        order_id: Some(order_id),
+        orig_client_order_id: None,
+        ts: PTimestamp::now(),
+    }
+}
+
+fn error_code(err: APIError) -> i64 {
+    match err {
+        APIError::ClientSideError(body) => {
+            serde_json::from_str::<response::CodeResponse>(&body)
+                .unwrap()
+                .code
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_market_order() {
+    let exchange = init_exchange().with_fee(0.0002, 0.0004);
+    let resp = exchange
+        .order(order(spec(OrderSide::Buy, FuturesOrderType::Market, 0.1)))
+        .await
+        .unwrap();
+    assert_eq!(resp.status, OrderStatus::Filled);
+    assert_eq!(resp.avg_price, 58000.1);
+    let (amount, entry) = exchange.position("BTCUSDT", PositionDirect::Both);
+    assert_eq!(amount, 0.1);
+    assert_eq!(entry, 58000.1);
+    let fee = 58000.1 * 0.1 * 0.0004;
+    assert!((exchange.balance("USDT") - (10000.0 - fee)).abs() < 1e-8);
+
+    let events = exchange.drain_events();
+    assert_eq!(events.len(), 3);
+    match &events[1] {
+        WSUserStream::OrderUpdate(update) => {
+            assert_eq!(update.event.x, "TRADE");
+            assert_eq!(update.event.X, OrderStatus::Filled);
+            assert!(!update.event.m);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(events[2], WSUserStream::AccountUpdate(_)));
+    assert!(exchange.drain_events().is_empty());
+}
+
+#[tokio::test]
+async fn test_limit_order_rests_and_fills_as_maker() {
+    let exchange = init_exchange();
+    let resp = exchange
+        .order(order(limit(OrderSide::Buy, 0.1, 57000.0)))
+        .await
+        .unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+    let open = exchange
+        .open_orders(params::POptionSymbolQuery {
+            symbol: Some("BTCUSDT".to_string()),
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(open.len(), 1);
+
+    exchange.on_agg_trade(&agg_trade(2, 57500.0));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+
+    exchange.on_agg_trade(&agg_trade(3, 56999.0));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Filled);
+    assert_eq!(resp.avg_price, 57000.0);
+    assert_eq!(resp.update_time, 3);
+
+    // 平仓, 实现盈亏 0.1 * (58000 - 57000)
+    exchange
+        .order(order(spec(OrderSide::Sell, FuturesOrderType::Market, 0.1)))
+        .await
+        .unwrap();
+    assert_eq!(
+        exchange.position("BTCUSDT", PositionDirect::Both),
+        (0.0, 0.0)
+    );
+    assert!((exchange.balance("USDT") - 10100.0).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_marketable_limit_order() {
+    let exchange = init_exchange();
+    let resp = exchange
+        .order(order(limit(OrderSide::Buy, 0.1, 58100.0)))
+        .await
+        .unwrap();
+    assert_eq!(resp.status, OrderStatus::Filled);
+    assert_eq!(resp.avg_price, 58000.1);
+
+    let mut post_only = limit(OrderSide::Sell, 0.1, 57000.0);
+    post_only.time_in_force = Some(enums::TimeInForce::GTX);
+    let resp = exchange.order(order(post_only)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Expired);
+
+    let mut ioc = limit(OrderSide::Buy, 0.1, 57000.0);
+    ioc.time_in_force = Some(enums::TimeInForce::IOC);
+    let resp = exchange.order(order(ioc)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Expired);
+}
+
+#[tokio::test]
+async fn test_stop_market_order() {
+    let exchange = init_exchange();
+    let mut stop = spec(OrderSide::Sell, FuturesOrderType::StopMarket, 0.1);
+    stop.stop_price = Some(57000.0);
+    let resp = exchange.order(order(stop)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+
+    exchange.on_agg_trade(&agg_trade(2, 57500.0));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+
+    exchange.on_book_ticker(&book_ticker(3, 56900.0, 56900.1));
+    exchange.on_agg_trade(&agg_trade(4, 56950.0));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Filled);
+    assert_eq!(resp.avg_price, 56900.0);
+    assert_eq!(exchange.position("BTCUSDT", PositionDirect::Both).0, -0.1);
+}
+
+#[tokio::test]
+async fn test_stop_triggers_on_book_ticker_only() {
+    let exchange = init_exchange();
+    let mut stop = spec(OrderSide::Sell, FuturesOrderType::StopMarket, 0.1);
+    stop.stop_price = Some(57000.0);
+    let resp = exchange.order(order(stop)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+
+    // 没有成交推送时按中间价触发
+    exchange.on_book_ticker(&book_ticker(2, 57000.0, 57000.2));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::New);
+    exchange.on_book_ticker(&book_ticker(3, 56900.0, 56900.1));
+    let resp = exchange.query_order(query(resp.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Filled);
+    assert_eq!(resp.avg_price, 56900.0);
+}
+
+#[tokio::test]
+async fn test_filters() {
+    let exchange = init_exchange();
+    // 数量不满足 stepSize
+    let err = exchange
+        .order(order(limit(OrderSide::Buy, 0.0015, 57000.0)))
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(err), -1111);
+    // 价格不满足 tickSize
+    let err = exchange
+        .order(order(limit(OrderSide::Buy, 0.1, 57000.005)))
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(err), -4014);
+    // 价格低于 minPrice
+    let err = exchange
+        .order(order(limit(OrderSide::Buy, 0.1, 100.0)))
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(err), -1013);
+    // 名义价值低于 5 USDT
+    let err = exchange
+        .order(order(limit(OrderSide::Buy, 0.001, 1000.0)))
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(err), -4164);
+    // 无持仓时只减仓单被拒绝
+    let mut reduce = spec(OrderSide::Sell, FuturesOrderType::Market, 0.1);
+    reduce.reduce_only = Some(true);
+    let err = exchange.order(order(reduce)).await.unwrap_err();
+    assert_eq!(error_code(err), -2022);
+    // 只减仓单的数量也必须大于 0
+    let mut reduce = limit(OrderSide::Sell, 0.0, 58100.0);
+    reduce.reduce_only = Some(true);
+    let err = exchange.order(order(reduce)).await.unwrap_err();
+    assert_eq!(error_code(err), -4003);
+}
+
+#[tokio::test]
+async fn test_oversized_reduce_only_order() {
+    let exchange = init_exchange();
+    exchange
+        .order(order(spec(OrderSide::Buy, FuturesOrderType::Market, 0.1)))
+        .await
+        .unwrap();
+    // 超过持仓的只减仓单只成交持仓数量, 不会反向开仓
+    let mut reduce = spec(OrderSide::Sell, FuturesOrderType::Market, 0.3);
+    reduce.reduce_only = Some(true);
+    let resp = exchange.order(order(reduce)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Expired);
+    assert_eq!(resp.executed_qty, 0.1);
+    assert_eq!(exchange.position("BTCUSDT", PositionDirect::Both).0, 0.0);
+
+    // 挂单成交前持仓已平, 只减仓单过期且不成交
+    exchange
+        .order(order(spec(OrderSide::Buy, FuturesOrderType::Market, 0.1)))
+        .await
+        .unwrap();
+    let mut resting = limit(OrderSide::Sell, 0.1, 58100.0);
+    resting.reduce_only = Some(true);
+    let resting = exchange.order(order(resting)).await.unwrap();
+    exchange
+        .order(order(spec(OrderSide::Sell, FuturesOrderType::Market, 0.1)))
+        .await
+        .unwrap();
+    exchange.on_agg_trade(&agg_trade(2, 58100.0));
+    let resp = exchange.query_order(query(resting.order_id)).await.unwrap();
+    assert_eq!(resp.status, OrderStatus::Expired);
+    assert_eq!(resp.executed_qty, 0.0);
+    assert_eq!(exchange.position("BTCUSDT", PositionDirect::Both).0, 0.0);
+}
+
+#[tokio::test]
+async fn test_cancel_and_batch() {
+    let exchange = init_exchange();
+    let resp = exchange
+        .batch_order(params::PBatchOrder {
+            batch_orders: vec![
+                limit(OrderSide::Buy, 0.1, 57000.0),
+                limit(OrderSide::Buy, 0.1, 100.0),
+            ],
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    let order_id = match &resp[0] {
+        response::BatchOrderResponse::Order(order) => order.order_id,
+        other => panic!("unexpected {:?}", other),
+    };
+    match &resp[1] {
+        response::BatchOrderResponse::Code(code) => assert_eq!(code.code, -1013),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let canceled = exchange.cancel_order(query(order_id)).await.unwrap();
+    assert_eq!(canceled.status, OrderStatus::Canceled);
+    let err = exchange.cancel_order(query(order_id)).await.unwrap_err();
+    assert_eq!(error_code(err), -2011);
+    let err = exchange.query_order(query(100)).await.unwrap_err();
+    assert_eq!(error_code(err), -2013);
+}
+
+#[tokio::test]
+async fn test_countdown_cancel_all() {
+    let exchange = init_exchange();
+    let resp = exchange
+        .order(order(limit(OrderSide::Buy, 0.1, 57000.0)))
+        .await
+        .unwrap();
+    exchange
+        .countdown_cancel_all(params::PCountdownCancel {
+            symbol: "BTCUSDT".to_string(),
+            countdown_time: 1000,
+            ts: PTimestamp::now(),
+        })
+        .await
+        .unwrap();
+    exchange.advance_clock(500);
+    let status = exchange
+        .query_order(query(resp.order_id))
+        .await
+        .unwrap()
+        .status;
+    assert_eq!(status, OrderStatus::New);
+    exchange.advance_clock(1001);
+    let status = exchange
+        .query_order(query(resp.order_id))
+        .await
+        .unwrap()
+        .status;
+    assert_eq!(status, OrderStatus::Canceled);
+}
+
+#[tokio::test]
+async fn test_trading_traits() {
+    use bian_rs::trading::{MarketData, OrderExecution, OrderRequest};
+
+    let exchange = init_exchange();
+    let quote = exchange.best_quote("BTCUSDT").await.unwrap();
+    assert_eq!(quote.ask_price, 58000.1);
+    assert!(exchange.last_price("BTCUSDT").await.is_err());
+
+    let order = exchange
+        .submit_order(OrderRequest::market("BTCUSDT", OrderSide::Buy, 0.1))
+        .await
+        .unwrap();
+    assert_eq!(order.status, OrderStatus::Filled);
+    let resting = exchange
+        .submit_order(OrderRequest::post_only(
+            "BTCUSDT",
+            OrderSide::Buy,
+            0.1,
+            57000.0,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(exchange.fetch_open_orders(None).await.unwrap().len(), 1);
+    exchange.cancel("BTCUSDT", resting.order_id).await.unwrap();
+
+    let fills = exchange.fetch_fills("BTCUSDT").await.unwrap();
+    assert_eq!(fills.len(), 1);
+    assert_eq!(fills[0].order_id, order.order_id);
+    let positions = exchange.fetch_positions("BTCUSDT").await.unwrap();
+    assert_eq!(positions[0].amount, 0.1);
+    let balances = exchange.fetch_balances().await.unwrap();
+    assert_eq!(balances[0].asset, "USDT");
+}
@@ -0,0 +1,274 @@
+use bian_rs::enums::{FuturesOrderType, OrderSide, SpotOrderType, TimeInForce};
+use bian_rs::error::APIError;
+use bian_rs::params::{PFuturesOrderSpec, PSpotOrderSpec};
+use bian_rs::response;
+use bian_rs::rules::{MarketContext, ProductType, RuleViolation, SymbolRules, SymbolRulesCache};
+
+fn spot_rules() -> SymbolRulesCache {
+    let info: response::SpotExchangeInfo =
+        serde_json::from_str(include_str!("../src/mock/fixtures/spot/exchange_info.json")).unwrap();
+    SymbolRulesCache::from_spot(&info)
+}
+
+fn ufutures_rules() -> SymbolRulesCache {
+    let info: response::UFuturesExchangeInfo = serde_json::from_str(include_str!(
+        "../src/mock/fixtures/ufutures/exchange_info.json"
+    ))
+    .unwrap();
+    SymbolRulesCache::from_ufutures(&info)
+}
+
+fn dfutures_rules() -> SymbolRulesCache {
+    let info: response::DFuturesExchangeInfo = serde_json::from_str(include_str!(
+        "../src/mock/fixtures/dfutures/exchange_info.json"
+    ))
+    .unwrap();
+    SymbolRulesCache::from_dfutures(&info)
+}
+
+fn spot_spec(order_type: SpotOrderType, quantity: f64, price: Option<f64>) -> PSpotOrderSpec {
+    PSpotOrderSpec {
+        symbol: "ADAUSDT".to_string(),
+        side: OrderSide::Buy,
+        order_type,
+        time_in_force: Some(TimeInForce::GTC),
+        quantity: Some(quantity),
+        quote_order_qty: None,
+        price,
+        new_client_order_id: None,
+        stop_price: None,
+        iceberg_qty: None,
+        new_order_resp_type: None,
+    }
+}
+
+fn futures_spec(symbol: &str, quantity: f64, price: f64) -> PFuturesOrderSpec {
+    PFuturesOrderSpec {
+        symbol: symbol.to_string(),
+        side: OrderSide::Buy,
+        position_side: None,
+        order_type: FuturesOrderType::Limit,
+        reduce_only: None,
+        quantity: Some(quantity),
+        price: Some(price),
+        new_client_order_id: None,
+        stop_price: None,
+        close_position: None,
+        activation_price: None,
+        callback_rate: None,
+        time_in_force: Some(TimeInForce::GTC),
+        working_type: None,
+        price_protect: None,
+        new_order_resp_type: None,
+    }
+}
+
+#[test]
+fn test_normalize_spot() {
+    let rules = spot_rules();
+    let ctx = MarketContext::default();
+    let mut spec = spot_spec(SpotOrderType::Limit, 12.345, Some(1.234567));
+    rules.normalize_spot(&mut spec, &ctx).unwrap();
+    assert_eq!(spec.quantity, Some(12.3));
+    assert_eq!(spec.price, Some(1.2346));
+
+    // 未修正时直接校验失败
+    let spec = spot_spec(SpotOrderType::Limit, 12.345, Some(1.2346));
+    assert!(matches!(
+        rules.validate_spot(&spec, &ctx),
+        Err(RuleViolation::InvalidStepSize { .. })
+    ));
+}
+
+#[test]
+fn test_normalize_spot_stop_uses_market_lot_size() {
+    let mut rules = SymbolRulesCache::default();
+    rules.insert(SymbolRules {
+        symbol: "ADAUSDT".to_string(),
+        product: ProductType::Spot,
+        tick_size: 0.0001,
+        min_qty: 0.1,
+        max_qty: 90000.0,
+        step_size: 0.1,
+        market_min_qty: 1.0,
+        market_max_qty: 10000.0,
+        market_step_size: 1.0,
+        ..SymbolRules::default()
+    });
+    let ctx = MarketContext::default();
+    // 止损止盈单没有价格, 数量按 MARKET_LOT_SIZE 修正
+    for order_type in [SpotOrderType::StopLoss, SpotOrderType::TakeProfit] {
+        let mut spec = spot_spec(order_type, 12.34, None);
+        spec.stop_price = Some(1.2);
+        rules.normalize_spot(&mut spec, &ctx).unwrap();
+        assert_eq!(spec.quantity, Some(12.0));
+    }
+    let mut spec = spot_spec(SpotOrderType::StopLossLimit, 12.34, Some(1.2));
+    spec.stop_price = Some(1.2);
+    rules.normalize_spot(&mut spec, &ctx).unwrap();
+    assert_eq!(spec.quantity, Some(12.3));
+}
+
+#[test]
+fn test_spot_violations() {
+    let rules = spot_rules();
+    let ctx = MarketContext {
+        reference_price: Some(1.2),
+        open_orders: 0,
+    };
+    // 名义价值 5 * 1.2 < 10
+    let spec = spot_spec(SpotOrderType::Limit, 5.0, Some(1.2));
+    assert!(matches!(
+        rules.validate_spot(&spec, &ctx),
+        Err(RuleViolation::MinNotional { .. })
+    ));
+    // 市价单使用参考价格计算名义价值
+    let spec = spot_spec(SpotOrderType::Market, 5.0, None);
+    let err = rules.validate_spot(&spec, &ctx).unwrap_err();
+    assert!(matches!(err, RuleViolation::MinNotional { .. }));
+    // 现货过滤器失败统一为 -1013
+    let product = rules.get("ADAUSDT").unwrap().product;
+    assert_eq!(product, ProductType::Spot);
+    assert_eq!(err.code(product), -1013);
+    // 超出平均价格的 5 倍
+    let spec = spot_spec(SpotOrderType::Limit, 100.0, Some(7.0));
+    assert!(matches!(
+        rules.validate_spot(&spec, &ctx),
+        Err(RuleViolation::PercentPrice { .. })
+    ));
+    let spec = spot_spec(SpotOrderType::Limit, 100.0, None);
+    assert_eq!(
+        rules.validate_spot(&spec, &ctx),
+        Err(RuleViolation::MissingParam("price"))
+    );
+    let ctx = MarketContext {
+        reference_price: None,
+        open_orders: 200,
+    };
+    let spec = spot_spec(SpotOrderType::Limit, 100.0, Some(1.2));
+    assert_eq!(
+        rules.validate_spot(&spec, &ctx),
+        Err(RuleViolation::MaxNumOrders(200))
+    );
+    let mut spec = spot_spec(SpotOrderType::Limit, 100.0, Some(1.2));
+    spec.symbol = "BTCUSDT".to_string();
+    assert_eq!(
+        rules.validate_spot(&spec, &MarketContext::default()),
+        Err(RuleViolation::UnknownSymbol("BTCUSDT".to_string()))
+    );
+}
+
+#[test]
+fn test_futures_rules() {
+    let rules = ufutures_rules();
+    let ctx = MarketContext::default();
+    let mut spec = futures_spec("BTCUSDT", 0.0105, 57000.123);
+    rules.normalize_futures(&mut spec, &ctx).unwrap();
+    assert_eq!(spec.quantity, Some(0.01));
+    assert_eq!(spec.price, Some(57000.12));
+
+    let mut spec = futures_spec("BTCUSDT", 0.0009, 57000.0);
+    let err = rules.normalize_futures(&mut spec, &ctx).unwrap_err();
+    assert!(matches!(err, RuleViolation::QtyOutOfRange { .. }));
+    let spec = futures_spec("BTCUSDT", 0.001, 600.0);
+    let err = rules.validate_futures(&spec, &ctx).unwrap_err();
+    assert_eq!(err.code(ProductType::Futures), -4164);
+    assert_eq!(err.code(ProductType::Spot), -1013);
+    // 只减仓单不检查名义价值
+    let mut spec = futures_spec("BTCUSDT", 0.001, 600.0);
+    spec.reduce_only = Some(true);
+    rules.validate_futures(&spec, &ctx).unwrap();
+
+    let mut spec = futures_spec("BTCUSDT", 0.001, 600.0);
+    spec.order_type = FuturesOrderType::StopMarket;
+    spec.price = None;
+    assert_eq!(
+        rules.validate_futures(&spec, &ctx),
+        Err(RuleViolation::MissingParam("stopPrice"))
+    );
+
+    // 本地校验错误可以直接通过 ? 转换为 APIError
+    let err: APIError = rules.get("ETHUSDT").unwrap_err().into();
+    assert!(matches!(err, APIError::FilterFailure(_)));
+}
+
+#[test]
+fn test_dfutures_rules() {
+    let rules = dfutures_rules();
+    let ctx = MarketContext {
+        reference_price: Some(58000.0),
+        open_orders: 0,
+    };
+    let mut spec = futures_spec("BTCUSD_PERP", 2.7, 58000.06);
+    rules.normalize_futures(&mut spec, &ctx).unwrap();
+    assert_eq!(spec.quantity, Some(2.0));
+    assert_eq!(spec.price, Some(58000.1));
+    let spec = futures_spec("BTCUSD_PERP", 1.0, 62000.0);
+    assert!(matches!(
+        rules.validate_futures(&spec, &ctx),
+        Err(RuleViolation::PercentPrice { .. })
+    ));
+}
+
+#[test]
+fn test_validate_order() {
+    use bian_rs::params::{
+        OrderLegBuilder, PModifyOrder, PModifyOrderSpec, POrderListOco, PTimestamp,
+    };
+    use bian_rs::rules::ValidateOrder;
+
+    let oco = |quantity: f64, stop_price: f64| POrderListOco {
+        symbol: "ADAUSDT".to_string(),
+        list_client_order_id: None,
+        side: OrderSide::Sell,
+        quantity,
+        above: OrderLegBuilder::limit_maker(1.5).build(),
+        below: OrderLegBuilder::stop_loss_limit(1.0, stop_price).build(),
+        new_order_resp_type: None,
+        ts: PTimestamp::now(),
+    };
+    let rules = spot_rules();
+    oco(10.0, 1.05).validate(Some(&rules)).unwrap();
+    assert!(matches!(
+        oco(10.0, 1.00005).validate(Some(&rules)),
+        Err(RuleViolation::InvalidTickSize { price, .. }) if price == 1.00005
+    ));
+    // 下方订单名义价值不足
+    assert!(matches!(
+        oco(8.0, 1.05).validate(Some(&rules)),
+        Err(RuleViolation::MinNotional { .. })
+    ));
+    // 没有规则时只检查必填参数和数量
+    oco(8.0, 1.00005).validate(None).unwrap();
+    assert!(matches!(
+        oco(0.0, 1.05).validate(None),
+        Err(RuleViolation::QtyOutOfRange { .. })
+    ));
+
+    let modify = |quantity: Option<f64>, price: Option<f64>| PModifyOrder {
+        spec: PModifyOrderSpec {
+            symbol: "BTCUSD_PERP".to_string(),
+            order_id: Some(1),
+            orig_client_order_id: None,
+            side: OrderSide::Buy,
+            quantity,
+            price,
+        },
+        ts: PTimestamp::now(),
+    };
+    assert_eq!(
+        modify(None, None).validate(None),
+        Err(RuleViolation::MissingParam("quantity"))
+    );
+    let rules = dfutures_rules();
+    modify(None, Some(58000.1)).validate(Some(&rules)).unwrap();
+    // 只改数量时按限价单的 LOT_SIZE 校验
+    assert!(matches!(
+        modify(Some(1.5), None).validate(Some(&rules)),
+        Err(RuleViolation::InvalidStepSize { .. })
+    ));
+    assert!(matches!(
+        modify(Some(1.0), None).validate(Some(&spot_rules())),
+        Err(RuleViolation::UnknownSymbol(_))
+    ));
+}
@@ -0,0 +1,151 @@
+use bian_rs::enums::{OrderSide, OrderStatus};
+use bian_rs::paper::PaperExchange;
+use bian_rs::response::{self, WSUserStream};
+use bian_rs::tracker::OrderTracker;
+use bian_rs::trading::{OrderExecution, OrderRequest};
+
+const EXCHANGE_INFO: &str = include_str!("../src/mock/fixtures/ufutures/exchange_info.json");
+
+fn init_exchange() -> PaperExchange {
+    let info: response::UFuturesExchangeInfo = serde_json::from_str(EXCHANGE_INFO).unwrap();
+    let exchange =
+        PaperExchange::new(&info, vec![("USDT".to_string(), 10000.0)]).with_fee(0.0002, 0.0004);
+    exchange.on_book_ticker(&book_ticker(1, 58000.0, 58000.1));
+    exchange
+}
+
+fn book_ticker(time: i64, bid: f64, ask: f64) -> response::WSFuturesBookTicker {
+    serde_json::from_value(serde_json::json!({
+        "e": "bookTicker", "u": time, "E": time, "T": time, "s": "BTCUSDT",
+        "b": bid.to_string(), "B": "1", "a": ask.to_string(), "A": "1"
+    }))
+    .unwrap()
+}
+
+fn agg_trade(time: i64, price: f64) -> response::WSAggTrade {
+    serde_json::from_value(serde_json::json!({
+        "e": "aggTrade", "E": time, "s": "BTCUSDT", "a": time, "p": price.to_string(),
+        "q": "0.1", "f": time, "l": time, "T": time, "m": true
+    }))
+    .unwrap()
+}
+
+fn limit(client_order_id: &str, price: f64) -> OrderRequest {
+    let mut req = OrderRequest::limit("BTCUSDT", OrderSide::Buy, 0.1, price);
+    req.client_order_id = Some(client_order_id.to_string());
+    req
+}
+
+/// 将模拟盘推送的订单更新应用到 tracker, 返回状态变化
+fn apply_events(exchange: &PaperExchange, tracker: &mut OrderTracker) -> Vec<(OrderStatus, f64)> {
+    exchange
+        .drain_events()
+        .iter()
+        .filter_map(|event| match event {
+            WSUserStream::OrderUpdate(update) => tracker.apply_order_update(update),
+            _ => None,
+        })
+        .map(|t| (t.status, t.filled_qty))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_track_from_user_stream() {
+    let exchange = init_exchange();
+    let mut tracker = OrderTracker::new();
+    let order = exchange.submit_order(limit("a", 57000.0)).await.unwrap();
+    let transition = tracker.apply_order(&order).unwrap();
+    assert_eq!(transition.previous, None);
+    assert_eq!(transition.status, OrderStatus::New);
+    // NEW 推送与下单返回值一致, 不产生状态变化
+    assert!(apply_events(&exchange, &mut tracker).is_empty());
+
+    exchange.on_agg_trade(&agg_trade(2, 56999.0));
+    assert_eq!(
+        apply_events(&exchange, &mut tracker),
+        vec![(OrderStatus::Filled, 0.1)]
+    );
+    let tracked = tracker.get("a").unwrap();
+    assert!(tracked.is_finished());
+    assert_eq!(tracked.avg_price, 57000.0);
+    assert!((tracked.fee("USDT") - 57000.0 * 0.1 * 0.0002).abs() < 1e-9);
+    assert_eq!(tracked.fees.len(), 1);
+
+    // 已结束的订单不会被过期数据覆盖
+    assert!(tracker.apply_order(&order).is_none());
+    assert_eq!(tracker.get("a").unwrap().status, OrderStatus::Filled);
+    assert_eq!(tracker.open_orders().count(), 0);
+    assert_eq!(tracker.remove_finished().len(), 1);
+    assert!(tracker.get("a").is_none());
+}
+
+#[tokio::test]
+async fn test_reconcile_after_missed_events() {
+    let exchange = init_exchange();
+    let mut tracker = OrderTracker::new();
+    for (id, price) in [("a", 57000.0), ("b", 56000.0), ("c", 55000.0)] {
+        let order = exchange.submit_order(limit(id, price)).await.unwrap();
+        tracker.apply_order(&order);
+    }
+    let b = tracker.get("b").unwrap().order_id;
+    exchange.drain_events();
+
+    // 断线期间 a 成交, b 被撤销, 推送丢失
+    exchange.on_agg_trade(&agg_trade(2, 56999.0));
+    exchange.cancel("BTCUSDT", b).await.unwrap();
+    exchange.drain_events();
+    assert_eq!(tracker.open_orders().count(), 3);
+
+    let mut transitions = tracker.reconcile(&exchange, None).await.unwrap();
+    transitions.sort_by(|x, y| x.client_order_id.cmp(&y.client_order_id));
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[0].client_order_id, "a");
+    assert_eq!(transitions[0].previous, Some(OrderStatus::New));
+    assert_eq!(transitions[0].status, OrderStatus::Filled);
+    assert_eq!(transitions[0].filled_qty, 0.1);
+    assert_eq!(transitions[1].status, OrderStatus::Canceled);
+
+    // 手续费从成交记录中补齐
+    let a = tracker.get("a").unwrap();
+    assert!((a.fee("USDT") - 57000.0 * 0.1 * 0.0002).abs() < 1e-9);
+    let open: Vec<_> = tracker
+        .open_orders()
+        .map(|o| o.client_order_id.as_str())
+        .collect();
+    assert_eq!(open, vec!["c"]);
+    assert!(tracker.reconcile(&exchange, None).await.unwrap().is_empty());
+}
+
+fn order_update(x: &str, status: &str, z: f64, l: f64, fee_asset: &str, fee: f64) -> WSUserStream {
+    serde_json::from_value(serde_json::json!({
+        "e": "ORDER_TRADE_UPDATE", "E": 1, "T": 1,
+        "o": {
+            "s": "BTCUSDT", "c": "mixed", "S": "BUY", "o": "LIMIT", "f": "GTC", "q": "0.2",
+            "p": "57000", "ap": "57000", "sp": "0", "x": x, "X": status, "i": 1,
+            "l": l.to_string(), "z": z.to_string(), "L": "57000", "N": fee_asset,
+            "n": fee.to_string(), "T": 1, "t": 1, "b": "0", "a": "0", "m": true, "R": false,
+            "wt": "CONTRACT_PRICE", "ot": "LIMIT", "ps": "BOTH", "rp": "0"
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_fees_kept_per_asset() {
+    let mut tracker = OrderTracker::new();
+    let events = [
+        order_update("NEW", "NEW", 0.0, 0.0, "", 0.0),
+        order_update("TRADE", "PARTIALLY_FILLED", 0.1, 0.1, "BNB", 0.003),
+        order_update("TRADE", "FILLED", 0.2, 0.1, "USDT", 1.14),
+    ];
+    for event in events.iter() {
+        if let WSUserStream::OrderUpdate(update) = event {
+            tracker.apply_order_update(update);
+        }
+    }
+    let order = tracker.get("mixed").unwrap();
+    assert_eq!(order.status, OrderStatus::Filled);
+    assert_eq!(order.fees.len(), 2);
+    assert_eq!(order.fee("BNB"), 0.003);
+    assert_eq!(order.fee("USDT"), 1.14);
+}
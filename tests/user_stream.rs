@@ -0,0 +1,187 @@
+use bian_rs::enums::{
+    FuturesOrderType, MarginType, OcoOrderStatus, OcoStatus, OrderSide, OrderStatus,
+    PositionDirect, SpotOrderType,
+};
+use bian_rs::response::{WSSpotUserStream, WSUserStream};
+
+const FUTURES_EVENTS: &str = include_str!("../src/mock/fixtures/ufutures/ws_user_data.jsonl");
+const SPOT_EVENTS: &str = include_str!("../src/mock/fixtures/spot/ws_user_data.jsonl");
+
+fn futures_events() -> Vec<WSUserStream> {
+    FUTURES_EVENTS
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_futures_account_and_order_update() {
+    let events = futures_events();
+    match &events[0] {
+        WSUserStream::AccountUpdate(update) => {
+            assert_eq!(update.event.update_type, "ORDER");
+            assert_eq!(update.event.balances[0].asset, "USDT");
+            assert_eq!(update.event.balances[1].bc, -49.12345678);
+            assert_eq!(
+                update.event.positions[1].position_side,
+                PositionDirect::Long
+            );
+            assert_eq!(update.event.positions[1].margin_type, MarginType::Isolated);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[1] {
+        WSUserStream::OrderUpdate(update) => {
+            let order = &update.event;
+            assert_eq!(order.S, OrderSide::Sell);
+            assert_eq!(order.o, FuturesOrderType::TrailingStopMarket);
+            assert_eq!(order.X, OrderStatus::New);
+            assert_eq!(order.AP, 7476.89);
+            assert_eq!(order.cr, 5.0);
+            assert_eq!(order.a, 9.91);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    // 非条件单不推送 cp, AP, cr
+    match &events[2] {
+        WSUserStream::OrderUpdate(update) => {
+            let order = &update.event;
+            assert_eq!(order.x, "TRADE");
+            assert_eq!(order.X, OrderStatus::Filled);
+            assert_eq!(order.t, 109100866);
+            assert_eq!(order.n, 0.114);
+            assert!(order.m);
+            assert!(!order.cp);
+            assert_eq!(order.AP, 0.0);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_futures_other_events() {
+    let events = futures_events();
+    match &events[3] {
+        WSUserStream::MarginCall(call) => {
+            assert_eq!(call.cw, 3.16812045);
+            assert_eq!(call.position[0].symbol, "ETHUSDT");
+            assert_eq!(call.position[0].margin_type, MarginType::Crossed);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[4] {
+        WSUserStream::AccountConfigUpdate(update) => {
+            let leverage = update.leverage.as_ref().unwrap();
+            assert_eq!(
+                (leverage.symbol.as_str(), leverage.leverage),
+                ("BTCUSDT", 25)
+            );
+            assert!(update.multi_assets.is_none());
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[5] {
+        WSUserStream::AccountConfigUpdate(update) => {
+            assert!(update.leverage.is_none());
+            assert!(update.multi_assets.as_ref().unwrap().multi_assets_margin);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[6] {
+        WSUserStream::TradeLite(trade) => {
+            assert_eq!(trade.side, OrderSide::Buy);
+            assert_eq!(trade.last_price, 64089.2);
+            assert_eq!(trade.order_id, 8886774);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[7] {
+        WSUserStream::ConditionalOrderReject(reject) => {
+            assert_eq!(reject.event.order_id, 155618472834)
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[8] {
+        WSUserStream::StrategyUpdate(update) => assert_eq!(update.event.strategy_status, "NEW"),
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[9] {
+        WSUserStream::GridUpdate(update) => {
+            assert_eq!(update.event.realized_pnl, -0.00300716);
+            assert_eq!(update.event.unmatched_qty, -0.001);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_futures_listen_key_expired_and_unknown() {
+    let event: WSUserStream =
+        serde_json::from_str(r#"{"e":"listenKeyExpired","E":1576653824250}"#).unwrap();
+    match event {
+        WSUserStream::ListenKeyExpired(expired) => assert_eq!(expired.event_time, 1576653824250),
+        other => panic!("unexpected {:?}", other),
+    }
+    // 未知事件不会导致解析失败
+    let event: WSUserStream =
+        serde_json::from_str(r#"{"e":"NEW_EVENT","E":1,"x":{"y":1}}"#).unwrap();
+    match event {
+        WSUserStream::Unknown(value) => assert_eq!(value["x"]["y"], 1),
+        other => panic!("unexpected {:?}", other),
+    }
+    // 已知事件字段错误时仍然返回错误
+    assert!(serde_json::from_str::<WSUserStream>(r#"{"e":"ORDER_TRADE_UPDATE","E":1}"#).is_err());
+}
+
+#[test]
+fn test_spot_events() {
+    let events: Vec<WSSpotUserStream> = SPOT_EVENTS
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    match &events[0] {
+        WSSpotUserStream::AccountPosition(position) => {
+            assert_eq!(position.balances[1].asset, "USDT");
+            assert_eq!(position.balances[1].locked, 99.5);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[1] {
+        WSSpotUserStream::BalanceUpdate(update) => assert_eq!(update.delta, 100.0),
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[2] {
+        WSSpotUserStream::ExecutionReport(report) => {
+            assert_eq!(report.order_type, SpotOrderType::Limit);
+            assert_eq!(report.status, OrderStatus::New);
+            assert_eq!(report.order_list_id, -1);
+            assert_eq!(report.trade_id, -1);
+            assert_eq!(report.commission_asset, None);
+            assert!(report.is_working);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[3] {
+        WSSpotUserStream::ExecutionReport(report) => {
+            assert_eq!(report.execution_type, "TRADE");
+            assert_eq!(report.status, OrderStatus::PartiallyFilled);
+            assert_eq!(report.last_qty, 0.4);
+            assert_eq!(report.commission_asset.as_deref(), Some("ETH"));
+            assert!(report.is_maker);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    match &events[4] {
+        WSSpotUserStream::ListStatus(status) => {
+            assert_eq!(status.list_status_type, OcoStatus::ExecStarted);
+            assert_eq!(status.list_order_status, OcoOrderStatus::Executing);
+            assert_eq!(status.orders.len(), 2);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    let event: WSSpotUserStream =
+        serde_json::from_str(r#"{"e":"externalLockUpdate","E":1,"a":"BTC"}"#).unwrap();
+    assert!(matches!(event, WSSpotUserStream::Unknown(_)));
+    let status: OcoOrderStatus = serde_json::from_str(r#""REJECT""#).unwrap();
+    assert_eq!(status, OcoOrderStatus::REJECT);
+}